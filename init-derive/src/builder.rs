@@ -0,0 +1,187 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, DeriveInput, Error, Fields, GenericParam, Generics, Ident, Lifetime, Result,
+};
+
+use crate::util;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = util::struct_data(&input, "Builder")?;
+    util::reject_packed(&input, "Builder")?;
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &data.fields,
+            "`Builder` can only be derived for structs with named fields",
+        ));
+    };
+    let fields = &fields.named;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let builder = format_ident!("{}Builder", name);
+
+    let lt = Lifetime::new("'__init", Span::call_site());
+    let states = (0..fields.len())
+        .map(|i| format_ident!("__State{}", i))
+        .collect::<Vec<_>>();
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // the original generics, with the builder's lifetime and the given state parameters
+    let generics_with = |states: &[&Ident]| -> Generics {
+        let mut generics = input.generics.clone();
+        generics.params.insert(0, parse_quote!(#lt));
+        generics.params.extend(
+            states
+                .iter()
+                .map(|state| -> GenericParam { parse_quote!(#state: ::init::builder::FieldState) }),
+        );
+        generics
+    };
+
+    let original_args = input
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lt = &param.lifetime;
+                quote!(#lt)
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the builder type with the given field states
+    let builder_with =
+        |states: &[TokenStream]| quote!(#builder<#lt, #(#original_args,)* #(#states,)*>);
+
+    let all_states = states.iter().collect::<Vec<_>>();
+    let builder_generics = generics_with(&all_states);
+    let (builder_impl_generics, builder_ty_generics, _) = builder_generics.split_for_impl();
+
+    let base_generics = generics_with(&[]);
+    let (base_impl_generics, _, _) = base_generics.split_for_impl();
+
+    let unset_builder = builder_with(&vec![quote!(::init::builder::Unset); fields.len()]);
+    let set_builder = builder_with(&vec![quote!(::init::builder::Set); fields.len()]);
+
+    let drops = fields.iter().zip(&states).map(|(field, state)| {
+        let ident = &field.ident;
+        quote! {
+            if <#state as ::init::builder::FieldState>::IS_SET {
+                // SAFETY: the field is initialized, and no one else has access to it
+                unsafe { (&raw mut (*ptr).#ident).drop_in_place() }
+            }
+        }
+    });
+
+    let setters = fields.iter().enumerate().map(|(i, field)| {
+        let field_vis = &field.vis;
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let doc = format!("Initialize the `{ident}` field");
+
+        let other_states = all_states
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, &state)| state)
+            .collect::<Vec<_>>();
+        let generics = generics_with(&other_states);
+        let (impl_generics, _, _) = generics.split_for_impl();
+
+        let mut before = states.iter().map(|state| quote!(#state)).collect::<Vec<_>>();
+        let mut after = before.clone();
+        before[i] = quote!(::init::builder::Unset);
+        after[i] = quote!(::init::builder::Set);
+        let before = builder_with(&before);
+        let after = builder_with(&after);
+
+        quote! {
+            impl #impl_generics #before #where_clause {
+                #[doc = #doc]
+                #field_vis fn #ident<__Args>(
+                    mut self,
+                    args: __Args,
+                ) -> ::init::__private_macros::core::result::Result<#after, <#ty as ::init::Ctor<__Args>>::Error>
+                where
+                    #ty: ::init::Ctor<__Args>,
+                {
+                    let ptr = self.uninit.as_mut_ptr();
+                    // SAFETY: the field isn't initialized yet, so there are no other pointers to it
+                    let field = unsafe { ::init::Uninit::from_raw(&raw mut (*ptr).#ident) };
+                    // if this fails, then dropping self will drop all of the initialized fields
+                    field.try_init(args)?.take_ownership();
+
+                    let this = ::init::__private_macros::core::mem::ManuallyDrop::new(self);
+                    ::init::__private_macros::core::result::Result::Ok(#builder {
+                        // SAFETY: this is never dropped, so only the new builder has access to the pointer
+                        uninit: unsafe { ::init::__private_macros::core::ptr::read(&this.uninit) },
+                        state: ::init::__private_macros::core::marker::PhantomData,
+                    })
+                }
+            }
+        }
+    });
+
+    let doc = format!(
+        "A typestate builder which initializes an `Uninit<{name}>` one field at a time\n\n\
+         It can only be finished once all fields are initialized"
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #builder #builder_generics #where_clause {
+            uninit: ::init::Uninit<#lt, #name #ty_generics>,
+            state: ::init::__private_macros::core::marker::PhantomData<fn() -> (#(#states,)*)>,
+        }
+
+        impl #builder_impl_generics ::init::__private_macros::core::ops::Drop for #builder #builder_ty_generics #where_clause {
+            fn drop(&mut self) {
+                let ptr = self.uninit.as_mut_ptr();
+                #(#drops)*
+            }
+        }
+
+        impl #base_impl_generics #unset_builder #where_clause {
+            /// Create a new builder with no fields initialized
+            #vis fn new(uninit: ::init::Uninit<#lt, #name #ty_generics>) -> Self {
+                #builder {
+                    uninit,
+                    state: ::init::__private_macros::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #base_impl_generics #set_builder #where_clause {
+            /// Finish the builder, all fields are initialized
+            #vis fn finish(self) -> ::init::Init<#lt, #name #ty_generics> {
+                let this = ::init::__private_macros::core::mem::ManuallyDrop::new(self);
+                // SAFETY: this is never dropped, so only the returned pointer has access to the value
+                let uninit = unsafe { ::init::__private_macros::core::ptr::read(&this.uninit) };
+                // SAFETY: all fields are initialized
+                unsafe { uninit.assume_init() }
+            }
+        }
+
+        #(#setters)*
+
+        impl #impl_generics ::init::builder::Build for #name #ty_generics #where_clause {
+            type Builder<#lt> = #unset_builder;
+
+            fn builder<#lt>(uninit: ::init::Uninit<#lt, Self>) -> Self::Builder<#lt> {
+                #builder::new(uninit)
+            }
+        }
+    })
+}
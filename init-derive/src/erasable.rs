@@ -0,0 +1,89 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, DeriveInput, Error, Member, Result};
+
+use crate::util;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = util::struct_data(&input, "Erasable")?;
+    util::reject_packed(&input, "Erasable")?;
+    util::repr_c_align(&input, "Erasable")?;
+
+    let members = data.fields.members().zip(&data.fields).collect::<Vec<_>>();
+    let [(len, len_field), .., (tail, tail_field)] = &members[..] else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Erasable` requires a struct whose first field is the length of its last field",
+        ));
+    };
+
+    for (field, which) in [(len_field, "length"), (tail_field, "tail")] {
+        if let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("cfg")) {
+            return Err(Error::new_spanned(
+                attr,
+                format!(
+                    "the {which} field of an `Erasable` struct can't be conditionally compiled"
+                ),
+            ));
+        }
+    }
+
+    let name = &input.ident;
+    let tail_ty = &tail_field.ty;
+    let message = format!(
+        "the `{}` field of `{name}` must be the length of the `{}` field to erase pointers to it",
+        member_name(len),
+        member_name(tail),
+    );
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // spanned to the fields, so type errors point at the field which is wrong
+    let len_ty = &len_field.ty;
+    let read_len = quote_spanned! {len_ty.span()=>
+        let len: usize = this.#len;
+    };
+    let tail_len = quote_spanned! {tail_ty.span()=>
+        <#tail_ty as ::init::__private_macros::Tail>::len(tail)
+    };
+
+    Ok(quote! {
+        // `#[repr(C)]` places the length field at the start of the struct, and `check` ensures
+        // that it's the length of the tail, which is the only pointer metadata of the struct
+        unsafe impl #impl_generics ::init::thin::Erasable for #name #ty_generics #where_clause {
+            // the struct contains a usize, so it's at least as aligned as a usize
+            const ALIGN: usize = ::init::__private_macros::core::mem::align_of::<usize>();
+
+            fn check(this: &Self) {
+                #read_len
+                let tail = ::init::__private_macros::core::ptr::NonNull::from(&this.#tail);
+                ::init::__private_macros::core::assert!(len == #tail_len, #message);
+            }
+
+            unsafe fn unerase(
+                ptr: ::init::__private_macros::core::ptr::NonNull<::init::thin::Erased>,
+            ) -> ::init::__private_macros::core::ptr::NonNull<Self> {
+                // the caller ensures that the value was checked, so the length field is initialized
+                let len = unsafe { ptr.cast::<usize>().read() };
+                let ptr = ::init::__private_macros::core::ptr::NonNull::slice_from_raw_parts(
+                    ptr.cast::<()>(),
+                    len,
+                );
+                // the pointer is non-null
+                unsafe {
+                    ::init::__private_macros::core::ptr::NonNull::new_unchecked(
+                        ptr.as_ptr() as *mut Self,
+                    )
+                }
+            }
+        }
+    })
+}
+
+/// The name of a field in error messages
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
@@ -0,0 +1,140 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Error, Result, Type};
+
+use crate::util;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = util::struct_data(&input, "DefaultLayoutProvider")?;
+    util::reject_packed(&input, "DefaultLayoutProvider")?;
+    let align = util::repr_c_align(&input, "DefaultLayoutProvider")?;
+
+    let Some(tail) = data.fields.iter().next_back() else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`DefaultLayoutProvider` requires a struct whose last field is a slice or `str`",
+        ));
+    };
+
+    let tail_ty = &tail.ty;
+    let (elem, default_provider) = match tail_ty {
+        Type::Slice(slice) => {
+            let elem = &slice.elem;
+            (quote!(#elem), quote!(::init::slice::SliceLayoutProvider))
+        }
+        Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => {
+            (quote!(u8), quote!(::init::str::StrLayoutProvider))
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                tail_ty,
+                "the last field must be a slice or `str` to derive `DefaultLayoutProvider`",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let provider = format_ident!("{}LayoutProvider", name);
+
+    let fields = data.fields.iter().take(data.fields.len() - 1).map(|field| {
+        let cfgs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"));
+        let ty = &field.ty;
+        quote!(#(#cfgs)* ::init::__private_macros::core::alloc::Layout::new::<#ty>())
+    });
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut provider_generics = input.generics.clone();
+    provider_generics.params.push(syn::parse_quote!(__HI));
+    provider_generics.params.push(syn::parse_quote!(__TI));
+    let mut default_generics = provider_generics.clone();
+    provider_generics.params.push(syn::parse_quote!(__L));
+    provider_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(__L: ::init::layout_provider::LayoutProvider<#tail_ty, __TI>));
+    default_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(__TI: ::init::layout_provider::DefaultLayoutProviderFor<#tail_ty>));
+    let (provider_impl_generics, _, provider_where_clause) = provider_generics.split_for_impl();
+    let (default_impl_generics, _, default_where_clause) = default_generics.split_for_impl();
+
+    let doc =
+        format!("A layout provider for [`{name}`], which uses `L` to get the length of the tail");
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #provider<L = #default_provider>(
+            ::init::__private_macros::core::marker::PhantomData<L>,
+        );
+
+        // the layout is computed with the same algorithm as `#[repr(C)]`, and cast returns
+        // a pointer with the same length as the tail, is_zeroed returns false
+        unsafe impl #provider_impl_generics ::init::layout_provider::LayoutProvider<
+            #name #ty_generics,
+            ::init::tail::WithHeader<__HI, __TI>,
+        > for #provider<__L> #provider_where_clause
+        {
+            fn layout(
+                args: &::init::tail::WithHeader<__HI, __TI>,
+            ) -> ::init::__private_macros::core::option::Option<
+                ::init::__private_macros::core::alloc::Layout,
+            > {
+                <Self as ::init::layout_provider::LayoutProvider<#name #ty_generics, _>>::try_layout(
+                    args,
+                )
+                .ok()
+            }
+
+            fn try_layout(
+                args: &::init::tail::WithHeader<__HI, __TI>,
+            ) -> ::init::__private_macros::core::result::Result<
+                ::init::__private_macros::core::alloc::Layout,
+                ::init::layout_provider::LayoutProviderError,
+            > {
+                __L::try_layout(args.tail())?;
+                let len = ::init::__private_macros::tail_len::<#tail_ty, __TI, __L>(args.tail());
+                ::init::__private_macros::repr_c_layout(
+                    &[#(#fields,)*],
+                    ::init::__private_macros::core::alloc::Layout::array::<#elem>(len),
+                    #align,
+                )
+            }
+
+            fn cast(
+                ptr: ::init::__private_macros::core::ptr::NonNull<()>,
+                args: &::init::tail::WithHeader<__HI, __TI>,
+            ) -> ::init::__private_macros::core::ptr::NonNull<#name #ty_generics> {
+                let len = ::init::__private_macros::tail_len::<#tail_ty, __TI, __L>(args.tail());
+                let ptr = ::init::__private_macros::core::ptr::NonNull::slice_from_raw_parts(
+                    ptr.cast::<#elem>(),
+                    len,
+                );
+                // the pointer is non-null
+                unsafe {
+                    ::init::__private_macros::core::ptr::NonNull::new_unchecked(
+                        ptr.as_ptr() as *mut #name #ty_generics,
+                    )
+                }
+            }
+
+            fn is_zeroed(_args: &::init::tail::WithHeader<__HI, __TI>) -> bool {
+                false
+            }
+        }
+
+        impl #default_impl_generics ::init::layout_provider::DefaultLayoutProviderFor<
+            #name #ty_generics,
+        > for ::init::tail::WithHeader<__HI, __TI> #default_where_clause
+        {
+            type LayoutProvider = #provider<
+                <__TI as ::init::layout_provider::DefaultLayoutProviderFor<#tail_ty>>::LayoutProvider,
+            >;
+        }
+    })
+}
@@ -0,0 +1,77 @@
+//! Derive macros for the `init` crate
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod builder;
+mod erasable;
+mod layout_provider;
+mod project;
+mod util;
+mod zeroable;
+
+/// Derive `init::Project`, which allows projecting an `Uninit<Self>` into
+/// an `Uninit` for each field of `Self`
+///
+/// This generates a `{Name}Projection` struct with the same fields (and field visibility)
+/// as `Self`, but where each field is an `Uninit` pointer to the corresponding field
+#[proc_macro_derive(Project)]
+pub fn derive_project(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    project::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `init::builder::Build`, which allows initializing `Self` one field at a time
+///
+/// This generates a `{Name}Builder` typestate builder, with a method for each field that
+/// initializes that field, and a `finish` method that can only be called once all fields
+/// are initialized. Each method has the same visibility as its field.
+#[proc_macro_derive(Builder)]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    builder::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `init::layout_provider::DefaultLayoutProviderFor<Self>` for `init::tail::WithHeader`,
+/// for a `#[repr(C)]` struct whose last field is a slice or `str`
+///
+/// This generates a `{Name}LayoutProvider<L>` layout provider, which uses `L` to get the
+/// length of the tail from the tail's initializer, and lays out the fields like `#[repr(C)]`
+#[proc_macro_derive(DefaultLayoutProvider)]
+pub fn derive_default_layout_provider(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    layout_provider::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `init::thin::Erasable` for a `#[repr(C)]` struct whose first field is a `usize`
+/// with the length of its last field, which is a slice or `str`
+///
+/// Erasing a pointer to the struct panics if the first field isn't the length of the last field,
+/// since the length is read back from the first field when the pointer is restored
+///
+/// The struct may have lifetimes, generics and `where` clauses, and fields between the first
+/// and last may be conditionally compiled with `#[cfg]`
+#[proc_macro_derive(Erasable)]
+pub fn derive_erasable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    erasable::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `init::zeroed::Zeroable`, which allows initializing `Self` by zeroing out its memory
+///
+/// This requires every field to be `Zeroable`, which is checked by the generated impl
+#[proc_macro_derive(Zeroable)]
+pub fn derive_zeroable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    zeroable::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
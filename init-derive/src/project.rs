@@ -0,0 +1,84 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Fields, GenericParam, Index, Lifetime, LifetimeParam, Result};
+
+use crate::util;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = util::struct_data(&input, "Project")?;
+    util::reject_packed(&input, "Project")?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let projection = format_ident!("{}Projection", name);
+
+    let lt = Lifetime::new("'__init", Span::call_site());
+    let mut projection_generics = input.generics.clone();
+    projection_generics
+        .params
+        .insert(0, GenericParam::Lifetime(LifetimeParam::new(lt.clone())));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (_, projection_ty_generics, _) = projection_generics.split_for_impl();
+
+    let mut fields = Vec::new();
+    let mut projections = Vec::new();
+
+    for (i, field) in data.fields.iter().enumerate() {
+        let cfgs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .collect::<Vec<_>>();
+        let vis = &field.vis;
+        let ty = &field.ty;
+
+        let member = match &field.ident {
+            Some(ident) => {
+                fields.push(quote!(#(#cfgs)* #vis #ident: ::init::Uninit<#lt, #ty>));
+                quote!(#ident)
+            }
+            None => {
+                fields.push(quote!(#(#cfgs)* #vis ::init::Uninit<#lt, #ty>));
+                let index = Index::from(i);
+                quote!(#index)
+            }
+        };
+
+        // each field is disjoint, and is only projected once
+        projections
+            .push(quote!(#(#cfgs)* #member: unsafe { brand.field(&raw mut (*ptr).#member) }));
+    }
+
+    // the projection lifetime must be used, even if there are no fields
+    if data.fields.is_empty() {
+        fields.push(quote!(::init::__private_macros::core::marker::PhantomData<&#lt mut ()>));
+        projections.push(quote!(0: ::init::__private_macros::core::marker::PhantomData));
+    }
+
+    let definition = match &data.fields {
+        Fields::Named(_) if !data.fields.is_empty() => {
+            quote!(#vis struct #projection #projection_generics #where_clause { #(#fields,)* })
+        }
+        _ => quote!(#vis struct #projection #projection_generics (#(#fields,)*) #where_clause;),
+    };
+
+    let doc =
+        format!("A projection of an `Uninit<{name}>` into an `Uninit` for each of its fields");
+
+    Ok(quote! {
+        #[doc = #doc]
+        #definition
+
+        impl #impl_generics ::init::project::Project for #name #ty_generics #where_clause {
+            type Projection<#lt> = #projection #projection_ty_generics
+            where
+                Self: #lt;
+
+            fn project<#lt>(uninit: &#lt mut ::init::Uninit<'_, Self>) -> Self::Projection<#lt> {
+                let (brand, ptr) = ::init::__private_macros::brand(uninit);
+                #projection { #(#projections,)* }
+            }
+        }
+    })
+}
@@ -0,0 +1,78 @@
+use syn::{Data, DataStruct, DeriveInput, Error, LitInt, Result};
+
+/// Get the struct data, or error if the input isn't a struct
+pub fn struct_data<'a>(input: &'a DeriveInput, derive: &str) -> Result<&'a DataStruct> {
+    match &input.data {
+        Data::Struct(data) => Ok(data),
+        Data::Enum(data) => Err(Error::new(
+            data.enum_token.span,
+            format!("`{derive}` can only be derived for structs"),
+        )),
+        Data::Union(data) => Err(Error::new(
+            data.union_token.span,
+            format!("`{derive}` can only be derived for structs"),
+        )),
+    }
+}
+
+/// Error if the input is `#[repr(packed)]`, since fields of packed structs may be unaligned
+pub fn reject_packed(input: &DeriveInput, derive: &str) -> Result<()> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        let mut packed = false;
+        attr.parse_nested_meta(|meta| {
+            packed |= meta.path.is_ident("packed");
+            // skip any arguments, like `align(N)` or `packed(N)`
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+
+        if packed {
+            return Err(Error::new_spanned(
+                attr,
+                format!("`{derive}` can't be derived for packed structs"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Error if the input isn't `#[repr(C)]`, and get the alignment from `#[repr(align(N))]`
+pub fn repr_c_align(input: &DeriveInput, derive: &str) -> Result<LitInt> {
+    let mut repr_c = false;
+    let mut align = LitInt::new("1", proc_macro2::Span::call_site());
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+            } else if meta.path.is_ident("align") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                align = content.parse()?;
+            }
+            Ok(())
+        })?;
+    }
+
+    if !repr_c {
+        return Err(Error::new_spanned(
+            &input.ident,
+            format!("`{derive}` can only be derived for `#[repr(C)]` structs"),
+        ));
+    }
+
+    Ok(align)
+}
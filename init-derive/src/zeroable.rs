@@ -0,0 +1,26 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::util;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = util::struct_data(&input, "Zeroable")?;
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in &data.fields {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ty: ::init::zeroed::Zeroable));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        // every field is `Zeroable`, so all zero bytes is a valid value of the struct
+        unsafe impl #impl_generics ::init::zeroed::Zeroable for #name #ty_generics #where_clause {}
+    })
+}
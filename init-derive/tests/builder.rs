@@ -0,0 +1,69 @@
+use core::cell::Cell;
+
+use init::{builder::Build, Builder, Init, Initializer, Uninit};
+
+#[derive(Builder)]
+struct Point<T> {
+    x: T,
+    y: u32,
+}
+
+#[test]
+fn build() {
+    let mut value = core::mem::MaybeUninit::<Point<u64>>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+
+    let point = init::try_from_fn(|uninit| {
+        Ok::<_, core::convert::Infallible>(Point::builder(uninit).y(2)?.x(1)?.finish())
+    });
+    let Ok(point) = uninit.try_init(point);
+
+    assert_eq!(point.as_ref().x, 1);
+    assert_eq!(point.as_ref().y, 2);
+}
+
+struct Counted<'a>(&'a Cell<usize>);
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+struct Fail;
+
+impl<'a> Initializer<Counted<'a>> for Fail {
+    type Error = ();
+
+    fn try_init_into<'b>(self, _: Uninit<'b, Counted<'a>>) -> Result<Init<'b, Counted<'a>>, ()> {
+        Err(())
+    }
+}
+
+impl<'a> Initializer<Counted<'a>> for &'a Cell<usize> {
+    type Error = ();
+
+    fn try_init_into<'b>(self, ptr: Uninit<'b, Counted<'a>>) -> Result<Init<'b, Counted<'a>>, ()> {
+        Ok(ptr.write(Counted(self)))
+    }
+}
+
+#[derive(Builder)]
+struct Pair<'a> {
+    a: Counted<'a>,
+    b: Counted<'a>,
+}
+
+#[test]
+fn drops_initialized_fields_on_error() {
+    let drops = Cell::new(0);
+
+    let mut value = core::mem::MaybeUninit::<Pair>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+
+    let builder = PairBuilder::new(uninit).a(&drops).unwrap();
+    assert!(builder.b(Fail).is_err());
+    assert_eq!(drops.get(), 1);
+}
@@ -0,0 +1,128 @@
+use std::rc::Rc;
+
+use init::{
+    slice::copy_from_slice,
+    tail::{with_header, WithHeader},
+    thin::{ErasablePtr, TaggedThin},
+    Ctor, DefaultLayoutProvider, Erasable, Init, Initializer, Project, Uninit,
+};
+
+#[repr(C)]
+#[derive(Erasable, Project, DefaultLayoutProvider)]
+struct Packet {
+    len: usize,
+    id: u32,
+    data: [u16],
+}
+
+impl<TI> Initializer<Packet> for WithHeader<u32, TI>
+where
+    [u16]: Ctor<TI>,
+{
+    type Error = <[u16] as Ctor<TI>>::Error;
+
+    fn try_init_into(self, mut ptr: Uninit<Packet>) -> Result<Init<Packet>, Self::Error> {
+        let (id, data) = self.into_parts();
+        let PacketProjection {
+            len: len_ptr,
+            id: id_ptr,
+            data: data_ptr,
+        } = ptr.project();
+        len_ptr.write(data_ptr.len()).take_ownership();
+        id_ptr.write(id).take_ownership();
+        data_ptr.try_init(data)?.take_ownership();
+        // SAFETY: all fields were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+fn packet() -> Box<Packet> {
+    init::boxed::try_boxed(with_header(7, copy_from_slice(&[1u16, 2, 3])))
+        .ok()
+        .unwrap()
+}
+
+#[test]
+fn tagged_box() {
+    let thin = TaggedThin::<Box<Packet>, 3>::new(packet(), 5);
+    assert_eq!(thin.tag(), 5);
+    thin.with(|packet| {
+        assert_eq!(packet.id, 7);
+        assert_eq!(packet.data, [1, 2, 3]);
+    });
+
+    let packet = thin.into_inner();
+    assert_eq!(packet.len, 3);
+    assert_eq!(packet.data, [1, 2, 3]);
+}
+
+#[test]
+fn erase_rc() {
+    let packet = Rc::<Packet>::from(packet());
+    let other = packet.clone();
+
+    let erased = ErasablePtr::erase(packet);
+    // SAFETY: erased came from erasing an Rc<Packet>
+    let packet = unsafe { <Rc<Packet> as ErasablePtr>::unerase(erased) };
+    assert!(Rc::ptr_eq(&packet, &other));
+    assert_eq!(packet.data, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic = "the `len` field of `Packet` must be the length of the `data` field"]
+fn wrong_len() {
+    let mut packet = packet();
+    packet.len = 2;
+    let _ = TaggedThin::<Box<Packet>, 0>::new(packet, 0);
+}
+
+#[repr(C)]
+#[derive(Erasable, Project, DefaultLayoutProvider)]
+struct Labeled<'a, T>
+where
+    T: Copy,
+{
+    /// the length of `data`
+    len: usize,
+    #[cfg(any())]
+    missing: u8,
+    label: &'a str,
+    data: [T],
+}
+
+impl<'a, T, TI> Initializer<Labeled<'a, T>> for WithHeader<&'a str, TI>
+where
+    T: Copy,
+    [T]: Ctor<TI>,
+{
+    type Error = <[T] as Ctor<TI>>::Error;
+
+    fn try_init_into<'b>(
+        self,
+        mut ptr: Uninit<'b, Labeled<'a, T>>,
+    ) -> Result<Init<'b, Labeled<'a, T>>, Self::Error> {
+        let (label, data) = self.into_parts();
+        let LabeledProjection {
+            len: len_ptr,
+            label: label_ptr,
+            data: data_ptr,
+        } = ptr.project();
+        len_ptr.write(data_ptr.len()).take_ownership();
+        label_ptr.write(label).take_ownership();
+        data_ptr.try_init(data)?.take_ownership();
+        // SAFETY: all fields were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+#[test]
+fn generic_struct() {
+    let label = String::from("label");
+    let labeled =
+        init::boxed::try_boxed::<Labeled<u8>, _>(with_header(&*label, copy_from_slice(b"abcd")));
+    let thin = TaggedThin::<Box<Labeled<u8>>, 3>::new(labeled.ok().unwrap(), 1);
+
+    let labeled = thin.into_inner();
+    assert_eq!(labeled.label, "label");
+    assert_eq!(labeled.data, *b"abcd");
+}
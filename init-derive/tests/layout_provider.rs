@@ -0,0 +1,77 @@
+use core::alloc::Layout;
+
+use init::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider},
+    slice::copy_from_slice,
+    str::copy_from_str,
+    tail::{with_header, WithHeader},
+    Ctor, DefaultLayoutProvider, Init, Initializer, Project, Uninit,
+};
+
+#[repr(C)]
+#[derive(Project, DefaultLayoutProvider)]
+struct Packet<T> {
+    id: u8,
+    flags: u32,
+    data: [T],
+}
+
+impl<T, TI> Initializer<Packet<T>> for WithHeader<(u8, u32), TI>
+where
+    [T]: Ctor<TI>,
+{
+    type Error = <[T] as Ctor<TI>>::Error;
+
+    fn try_init_into(self, mut ptr: Uninit<Packet<T>>) -> Result<Init<Packet<T>>, Self::Error> {
+        let ((id, flags), data) = self.into_parts();
+        let PacketProjection {
+            id: id_ptr,
+            flags: flags_ptr,
+            data: data_ptr,
+        } = ptr.project();
+        id_ptr.write(id).take_ownership();
+        flags_ptr.write(flags).take_ownership();
+        data_ptr.try_init(data)?.take_ownership();
+        // SAFETY: all fields were initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+#[repr(C, align(16))]
+#[derive(DefaultLayoutProvider)]
+struct Name {
+    len: u16,
+    name: str,
+}
+
+fn layout_of<T: ?Sized, I: DefaultLayoutProviderFor<T>>(args: &I) -> Option<Layout> {
+    I::LayoutProvider::layout(args)
+}
+
+#[test]
+fn slice_tail() {
+    let data: &[u16] = &[1, 2, 3];
+    let args = with_header((7, 42), copy_from_slice(data));
+
+    // 1 byte id, 3 bytes padding, 4 bytes flags, 6 bytes data, 2 bytes padding
+    let layout = layout_of::<Packet<u16>, _>(&args).unwrap();
+    assert_eq!(layout, Layout::from_size_align(16, 4).unwrap());
+
+    let packet = init::boxed::try_boxed::<Packet<u16>, _>(args).ok().unwrap();
+    assert_eq!(packet.id, 7);
+    assert_eq!(packet.flags, 42);
+    assert_eq!(&packet.data, data);
+    assert_eq!(core::mem::size_of_val(&*packet), 16);
+}
+
+#[test]
+fn str_tail() {
+    let args = with_header(3, copy_from_str("abc"));
+    let layout = layout_of::<Name, _>(&args).unwrap();
+    assert_eq!(layout, Layout::from_size_align(16, 16).unwrap());
+
+    let name = "a".repeat(20);
+    let args = with_header(3, copy_from_str(&name));
+    let layout = layout_of::<Name, _>(&args).unwrap();
+    assert_eq!(layout, Layout::from_size_align(32, 16).unwrap());
+}
@@ -0,0 +1,55 @@
+use init::{Init, Project, Uninit};
+
+#[derive(Project)]
+struct Named<T> {
+    a: T,
+    b: u32,
+}
+
+#[derive(Project)]
+struct Tuple(u8, u16);
+
+#[derive(Project)]
+struct Unit;
+
+fn init_named(mut uninit: Uninit<Named<u64>>) -> Init<Named<u64>> {
+    let NamedProjection { a, b } = uninit.project();
+    a.write(10).take_ownership();
+    b.write(20).take_ownership();
+    // SAFETY: all fields were initialized
+    unsafe { uninit.assume_init() }
+}
+
+#[test]
+fn named() {
+    let mut value = core::mem::MaybeUninit::<Named<u64>>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let init = init_named(unsafe { Uninit::from_raw(value.as_mut_ptr()) });
+
+    assert_eq!(init.as_ref().a, 10);
+    assert_eq!(init.as_ref().b, 20);
+}
+
+#[test]
+fn tuple() {
+    let mut value = core::mem::MaybeUninit::<Tuple>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let mut uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+
+    let TupleProjection(a, b) = uninit.project();
+    a.write(1).take_ownership();
+    b.write(2).take_ownership();
+    // SAFETY: all fields were initialized
+    let init = unsafe { uninit.assume_init() };
+
+    assert_eq!(init.as_ref().0, 1);
+    assert_eq!(init.as_ref().1, 2);
+}
+
+#[test]
+fn unit() {
+    let mut value = core::mem::MaybeUninit::<Unit>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let mut uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+    let UnitProjection(_) = uninit.project();
+}
@@ -0,0 +1,30 @@
+use core::num::NonZero;
+
+use init::{zeroed, Zeroable};
+
+#[derive(Debug, PartialEq, Zeroable)]
+struct Config<T> {
+    id: u32,
+    next: Option<NonZero<u64>>,
+    values: [T; 4],
+}
+
+#[derive(Zeroable)]
+struct Tuple(u8, Config<f32>);
+
+#[test]
+fn zeroed_struct() {
+    let config = init::boxed::boxed::<Config<i16>, _>(zeroed());
+    assert_eq!(
+        *config,
+        Config {
+            id: 0,
+            next: None,
+            values: [0; 4]
+        }
+    );
+
+    let tuple = init::boxed::boxed::<Tuple, _>(zeroed());
+    assert_eq!(tuple.0, 0);
+    assert_eq!(tuple.1.values, [0.0; 4]);
+}
@@ -0,0 +1,282 @@
+//! initialize data directly inside an [`Arc`] allocation
+//!
+//! [`Arc`] doesn't have a fallible way to allocate, so running out of memory aborts the process
+//! (see [`handle_alloc_error`](alloc::alloc::handle_alloc_error)) instead of returning [`EmplaceError::Alloc`]
+
+use crate::{
+    layout_provider::{DefaultLayoutProvider, LayoutProvider},
+    shared::{self, SharedPtr},
+    slice::{WithLength, WithLengthLayoutProvider},
+    Ctor, Emplace, EmplaceError,
+};
+
+use alloc::sync::Arc;
+use core::mem::MaybeUninit;
+
+enum ArcPtr {}
+
+impl SharedPtr for ArcPtr {
+    type Ptr<T: ?Sized> = Arc<T>;
+
+    fn new_uninit_slice<A>(len: usize) -> Arc<[MaybeUninit<A>]> {
+        Arc::new_uninit_slice(len)
+    }
+
+    fn into_raw<T: ?Sized>(ptr: Arc<T>) -> *const T {
+        Arc::into_raw(ptr)
+    }
+
+    unsafe fn from_raw<T: ?Sized>(ptr: *const T) -> Arc<T> {
+        // SAFETY: the caller ensures that the pointer came from into_raw,
+        // and the allocation has the right layout for T
+        unsafe { Arc::from_raw(ptr) }
+    }
+
+    fn is_unique<T: ?Sized>(ptr: &mut Arc<T>) -> bool {
+        Arc::get_mut(ptr).is_some()
+    }
+}
+
+/// initialize a value directly inside an [`Arc`] allocation
+///
+/// # Panics
+///
+/// If the layout provider's layout doesn't match the layout of the initialized value
+///
+/// # Aborts
+///
+/// If the allocation fails, see the [module docs](self)
+pub fn try_arc_with<T, I, L>(init: I) -> Result<Arc<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    shared::try_shared_with::<ArcPtr, T, I, L, _>(init, |ptr, init| {
+        ptr.try_init(init)?.take_ownership();
+        Ok(())
+    })
+}
+
+/// initialize a value directly inside an [`Arc`] allocation
+///
+/// # Panics
+///
+/// If the layout provider's layout doesn't match the layout of the initialized value
+pub fn arc_with<T, I, L>(init: I) -> Arc<T>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible>,
+    L: LayoutProvider<T, I>,
+{
+    match try_arc_with::<T, I, L>(init) {
+        Ok(arc) => arc,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a value directly inside an [`Arc`] allocation
+pub fn try_arc<T, I>(init: I) -> Result<Arc<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I> + DefaultLayoutProvider<I>,
+{
+    try_arc_with::<T, I, T::LayoutProvider>(init)
+}
+
+/// initialize a value directly inside an [`Arc`] allocation
+pub fn arc<T, I>(init: I) -> Arc<T>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+{
+    match try_arc_with::<T, I, T::LayoutProvider>(init) {
+        Ok(arc) => arc,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a slice with `len` elements directly inside an [`Arc`] allocation
+pub fn try_arc_slice<T, I, E>(len: usize, init: I) -> Result<Arc<[T]>, EmplaceError<E>>
+where
+    [T]: Ctor<I, Error = E>,
+{
+    try_arc_with::<[T], WithLength<I>, WithLengthLayoutProvider>(WithLength::from_init(len, init))
+}
+
+/// initialize a slice with `len` elements directly inside an [`Arc`] allocation
+pub fn arc_slice<T, I>(len: usize, init: I) -> Arc<[T]>
+where
+    [T]: Ctor<I, Error = core::convert::Infallible>,
+{
+    match try_arc_slice(len, init) {
+        Ok(arc) => arc,
+        Err(err) => err.handle(),
+    }
+}
+
+/// An [`Arc`] which is known to be unique, so it can be mutated freely
+///
+/// Once it's ready to be shared, call [`UniqueArc::freeze`] to get back an [`Arc`]
+pub struct UniqueArc<T: ?Sized> {
+    arc: Arc<T>,
+}
+
+impl<T: ?Sized> UniqueArc<T> {
+    /// initialize a value directly inside a [`UniqueArc`] allocation
+    ///
+    /// # Panics
+    ///
+    /// If the layout provider's layout doesn't match the layout of the initialized value
+    pub fn try_new_with<I, L>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        try_arc_with::<T, I, L>(init).map(|arc| Self { arc })
+    }
+
+    /// initialize a value directly inside a [`UniqueArc`] allocation
+    ///
+    /// # Panics
+    ///
+    /// If the layout provider's layout doesn't match the layout of the initialized value
+    pub fn new_with<I, L>(init: I) -> Self
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+        L: LayoutProvider<T, I>,
+    {
+        match Self::try_new_with::<I, L>(init) {
+            Ok(arc) => arc,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// initialize a value directly inside a [`UniqueArc`] allocation
+    pub fn try_new<I>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I> + DefaultLayoutProvider<I>,
+    {
+        Self::try_new_with::<I, T::LayoutProvider>(init)
+    }
+
+    /// initialize a value directly inside a [`UniqueArc`] allocation
+    pub fn new<I>(init: I) -> Self
+    where
+        T: Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+    {
+        match Self::try_new_with::<I, T::LayoutProvider>(init) {
+            Ok(arc) => arc,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// Convert into a shared [`Arc`]
+    pub fn freeze(this: Self) -> Arc<T> {
+        this.arc
+    }
+}
+
+impl<T: ?Sized> core::ops::Deref for UniqueArc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.arc
+    }
+}
+
+impl<T: ?Sized> core::ops::DerefMut for UniqueArc<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let ptr = Arc::as_ptr(&self.arc).cast_mut();
+        // SAFETY: UniqueArc never hands out clones or weak pointers of the Arc,
+        // so this is the only pointer to the allocation
+        unsafe { &mut *ptr }
+    }
+}
+
+impl<T: ?Sized> Emplace for Arc<T> {
+    type Target = T;
+
+    fn try_emplace_with<I, L>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        try_arc_with::<T, I, L>(init)
+    }
+}
+
+impl<T: ?Sized> Emplace for UniqueArc<T> {
+    type Target = T;
+
+    fn try_emplace_with<I, L>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        Self::try_new_with::<I, L>(init)
+    }
+}
+
+/// Extension methods for [`Arc`]
+pub trait ArcExt<T: ?Sized>: Sized {
+    /// Drop the value in the [`Arc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    ///
+    /// # Panics
+    ///
+    /// If the layout provider's layout doesn't match the layout of the initialized value
+    fn try_reinit_with<I, L>(self, init: I) -> Result<Arc<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>;
+
+    /// Drop the value in the [`Arc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    ///
+    /// # Panics
+    ///
+    /// If the layout provider's layout doesn't match the layout of the initialized value
+    fn reinit_with<I, L>(self, init: I) -> Arc<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+        L: LayoutProvider<T, I>,
+    {
+        match self.try_reinit_with::<I, L>(init) {
+            Ok(ptr) => ptr,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// Drop the value in the [`Arc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    fn try_reinit<I>(self, init: I) -> Result<Arc<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I> + DefaultLayoutProvider<I>,
+    {
+        self.try_reinit_with::<I, T::LayoutProvider>(init)
+    }
+
+    /// Drop the value in the [`Arc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    fn reinit<I>(self, init: I) -> Arc<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+    {
+        self.reinit_with::<I, T::LayoutProvider>(init)
+    }
+}
+
+impl<T: ?Sized> ArcExt<T> for Arc<T> {
+    fn try_reinit_with<I, L>(self, init: I) -> Result<Arc<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        shared::try_reinit_with::<ArcPtr, T, I, L>(self, init)
+    }
+}
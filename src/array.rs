@@ -61,6 +61,75 @@ where
     }
 }
 
+impl<const N: usize> Initializer<[u8; N]> for slice::FillBytes {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(
+        self,
+        ptr: crate::Uninit<[u8; N]>,
+    ) -> Result<crate::Init<[u8; N]>, Self::Error> {
+        ptr.try_init(from_slice(self))
+    }
+}
+
+impl<T: Ctor<I>, I, F: FnMut(usize) -> I, const N: usize> Initializer<[T; N]>
+    for slice::RepeatWith<F>
+{
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ptr.try_init(from_slice(self))
+    }
+}
+
+impl<T: Copy, const N: usize> Initializer<[T; N]> for slice::CopyFromSliceIter<'_, T> {
+    type Error = slice::InitFromIterError<core::convert::Infallible>;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ptr.try_init(from_slice(self))
+    }
+}
+
+impl<T, I: Iterator, const N: usize> Initializer<[T; N]> for slice::InitFromExactIter<I>
+where
+    T: Ctor<I::Item>,
+{
+    type Error = slice::InitFromIterError<T::Error>;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ptr.try_init(from_slice(self))
+    }
+}
+
+impl<T, I: Iterator, J: Clone, const N: usize> Initializer<[T; N]>
+    for slice::InitFromIterOrDefault<I, J>
+where
+    T: Ctor<I::Item> + Ctor<J, Error = <T as Ctor<I::Item>>::Error>,
+{
+    type Error = <T as Ctor<I::Item>>::Error;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ptr.try_init(from_slice(self))
+    }
+}
+
+impl<T, const N: usize> Initializer<[T; N]> for slice::MoveFromArray<T, N> {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        Ok(ptr.write(self.init))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> Initializer<[T; N]> for slice::MoveFromBoxedSlice<T> {
+    type Error = slice::MoveFromBoxedSliceError<T>;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T; N]>) -> Result<crate::Init<[T; N]>, Self::Error> {
+        ptr.try_init(from_slice(self))
+    }
+}
+
 /// A slice layout provider which can be parameterized on another layout provider
 pub struct ArrayLayoutProvider<L = crate::layout_provider::SizedLayoutProvider>(L);
 
@@ -115,6 +184,30 @@ unsafe impl<T, I, L: LayoutProvider<T, I>, const N: usize>
     }
 }
 
+impl<const N: usize> crate::layout_provider::DefaultLayoutProviderFor<[u8; N]>
+    for slice::FillBytes
+{
+    type LayoutProvider = ArrayLayoutProvider;
+}
+// SAFETY:
+// arrays are sized, so layout and cast are trivial
+// is_zeroed only returns true if every byte is set to zero
+unsafe impl<L, const N: usize> crate::layout_provider::LayoutProvider<[u8; N], slice::FillBytes>
+    for ArrayLayoutProvider<L>
+{
+    fn layout(_: &slice::FillBytes) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::new::<[u8; N]>())
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, _: &slice::FillBytes) -> core::ptr::NonNull<[u8; N]> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(args: &slice::FillBytes) -> bool {
+        args.byte == 0
+    }
+}
+
 impl<I: Iterator, T: DefaultLayoutProvider<I::Item>, const N: usize>
     crate::layout_provider::DefaultLayoutProviderFor<[T; N]> for slice::InitFromIter<I>
 {
@@ -141,6 +234,107 @@ unsafe impl<T, I: Iterator, L: LayoutProvider<T, I::Item>, const N: usize>
     }
 }
 
+impl<I, F: FnMut(usize) -> I, T: DefaultLayoutProvider<I>, const N: usize>
+    crate::layout_provider::DefaultLayoutProviderFor<[T; N]> for slice::RepeatWith<F>
+{
+    type LayoutProvider = ArrayLayoutProvider<T::LayoutProvider>;
+}
+// SAFETY:
+// arrays are sized, so layout and cast are trivial
+// is_zeroed returns false
+unsafe impl<T, I, F: FnMut(usize) -> I, L: LayoutProvider<T, I>, const N: usize>
+    crate::layout_provider::LayoutProvider<[T; N], slice::RepeatWith<F>>
+    for ArrayLayoutProvider<L>
+{
+    fn layout(_: &slice::RepeatWith<F>) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::new::<[T; N]>())
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, _: &slice::RepeatWith<F>) -> core::ptr::NonNull<[T; N]> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_args: &slice::RepeatWith<F>) -> bool {
+        false
+    }
+}
+
+impl<I: Iterator, T: DefaultLayoutProvider<I::Item>, const N: usize>
+    crate::layout_provider::DefaultLayoutProviderFor<[T; N]> for slice::InitFromExactIter<I>
+{
+    type LayoutProvider = ArrayLayoutProvider<T::LayoutProvider>;
+}
+// SAFETY:
+// arrays are sized, so layout and cast are trivial
+// is_zeroed returns false
+unsafe impl<T, I: Iterator, L: LayoutProvider<T, I::Item>, const N: usize>
+    crate::layout_provider::LayoutProvider<[T; N], slice::InitFromExactIter<I>>
+    for ArrayLayoutProvider<L>
+{
+    fn layout(_: &slice::InitFromExactIter<I>) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::new::<[T; N]>())
+    }
+
+    fn cast(
+        ptr: core::ptr::NonNull<()>,
+        _: &slice::InitFromExactIter<I>,
+    ) -> core::ptr::NonNull<[T; N]> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_args: &slice::InitFromExactIter<I>) -> bool {
+        false
+    }
+}
+
+impl<I: Iterator, J, T: DefaultLayoutProvider<I::Item>, const N: usize>
+    crate::layout_provider::DefaultLayoutProviderFor<[T; N]>
+    for slice::InitFromIterOrDefault<I, J>
+{
+    type LayoutProvider = ArrayLayoutProvider<T::LayoutProvider>;
+}
+// SAFETY:
+// arrays are sized, so layout and cast are trivial
+// is_zeroed returns false
+unsafe impl<T, I: Iterator, J, L: LayoutProvider<T, I::Item>, const N: usize>
+    crate::layout_provider::LayoutProvider<[T; N], slice::InitFromIterOrDefault<I, J>>
+    for ArrayLayoutProvider<L>
+{
+    fn layout(_: &slice::InitFromIterOrDefault<I, J>) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::new::<[T; N]>())
+    }
+
+    fn cast(
+        ptr: core::ptr::NonNull<()>,
+        _: &slice::InitFromIterOrDefault<I, J>,
+    ) -> core::ptr::NonNull<[T; N]> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_args: &slice::InitFromIterOrDefault<I, J>) -> bool {
+        false
+    }
+}
+
+impl<T: Copy, const N: usize> crate::layout_provider::DefaultLayoutProviderFor<[T; N]>
+    for slice::CopyFromSliceIter<'_, T>
+{
+    type LayoutProvider = crate::layout_provider::SizedLayoutProvider;
+}
+
+impl<T, const N: usize> crate::layout_provider::DefaultLayoutProviderFor<[T; N]>
+    for slice::MoveFromArray<T, N>
+{
+    type LayoutProvider = crate::layout_provider::SizedLayoutProvider;
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> crate::layout_provider::DefaultLayoutProviderFor<[T; N]>
+    for slice::MoveFromBoxedSlice<T>
+{
+    type LayoutProvider = crate::layout_provider::SizedLayoutProvider;
+}
+
 impl<T: DefaultLayoutProvider<()>, const N: usize>
     crate::layout_provider::DefaultLayoutProviderFor<[T; N]> for ()
 {
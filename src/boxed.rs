@@ -1,85 +1,353 @@
 //! initialize data directly on the heap
 
 use crate::{
-    layout_provider::{DefaultLayoutProvider, LayoutProvider},
-    Ctor,
+    emplace::Emplace,
+    layout_provider::{DefaultLayoutProvider, LayoutProvider, LayoutProviderError},
+    Ctor, EmplaceError, Init, PinCtor, Uninit,
 };
 
 use alloc::{
-    alloc::{alloc, alloc_zeroed, dealloc, handle_alloc_error},
+    alloc::{alloc, alloc_zeroed, dealloc, realloc},
     boxed::Box,
 };
-use core::{alloc::Layout, ptr::NonNull};
+use core::{alloc::Layout, mem::ManuallyDrop, pin::Pin, ptr::NonNull};
 
-struct UninitBox {
-    ptr: *mut u8,
+#[cfg(feature = "allocator_api")]
+use {alloc::boxed::Box as BoxIn, core::alloc::Allocator};
+
+#[cfg(all(feature = "allocator-api2", not(feature = "allocator_api")))]
+use allocator_api2::{alloc::Allocator, boxed::Box as BoxIn};
+
+#[cfg(test)]
+mod tests;
+
+/// An allocator which values can be emplaced into
+trait BoxAllocator {
+    /// The box type which owns allocations from this allocator
+    type Box<T: ?Sized>;
+
+    /// allocate memory for the given non-zero sized layout
+    fn allocate(&self, layout: Layout, zeroed: bool) -> Option<NonNull<u8>>;
+
+    /// # Safety
+    ///
+    /// ptr must have been allocated by this allocator with the given layout
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// # Safety
+    ///
+    /// ptr must point to an initialized value, and if the value isn't zero-sized
+    /// it must have been allocated by this allocator with the layout of the value
+    unsafe fn into_box<T: ?Sized>(self, ptr: *mut T) -> Self::Box<T>;
+}
+
+struct Global;
+
+impl BoxAllocator for Global {
+    type Box<T: ?Sized> = Box<T>;
+
+    fn allocate(&self, layout: Layout, zeroed: bool) -> Option<NonNull<u8>> {
+        // SAFETY: the layout has a non-zero size
+        let ptr = unsafe {
+            if zeroed {
+                alloc_zeroed(layout)
+            } else {
+                alloc(layout)
+            }
+        };
+
+        NonNull::new(ptr)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: the caller ensures that ptr was allocated by the global allocator with layout
+        unsafe { dealloc(ptr.as_ptr(), layout) }
+    }
+
+    unsafe fn into_box<T: ?Sized>(self, ptr: *mut T) -> Box<T> {
+        // SAFETY: the caller ensures that ptr was allocated by the global allocator
+        // with the layout of the initialized value
+        unsafe { Box::from_raw(ptr) }
+    }
+}
+
+#[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+struct InAllocator<A>(A);
+
+#[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+impl<A: Allocator> BoxAllocator for InAllocator<A> {
+    type Box<T: ?Sized> = BoxIn<T, A>;
+
+    fn allocate(&self, layout: Layout, zeroed: bool) -> Option<NonNull<u8>> {
+        let ptr = if zeroed {
+            self.0.allocate_zeroed(layout)
+        } else {
+            self.0.allocate(layout)
+        };
+
+        ptr.ok().map(NonNull::cast)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: the caller ensures that ptr was allocated by this allocator with layout
+        unsafe { self.0.deallocate(ptr, layout) }
+    }
+
+    unsafe fn into_box<T: ?Sized>(self, ptr: *mut T) -> BoxIn<T, A> {
+        // SAFETY: the caller ensures that ptr was allocated by this allocator
+        // with the layout of the initialized value
+        unsafe { BoxIn::from_raw_in(ptr, self.0) }
+    }
+}
+
+/// An allocation which doesn't contain an initialized value
+struct RawBox<A: BoxAllocator> {
+    ptr: NonNull<u8>,
     layout: Layout,
+    alloc: A,
 }
 
-impl Drop for UninitBox {
+impl<A: BoxAllocator> Drop for RawBox<A> {
     fn drop(&mut self) {
-        // SAFETY: This type is only constructed after allocating and
-        // checking that allocation didn't fail
-        unsafe {
-            dealloc(self.ptr, self.layout);
+        if self.layout.size() != 0 {
+            // SAFETY: This type is only constructed after allocating and
+            // checking that allocation didn't fail
+            unsafe { self.alloc.deallocate(self.ptr, self.layout) }
         }
     }
 }
 
-/// initialize a value directly on the heap
-pub fn try_boxed_with<T, I, L>(init: I) -> Result<Box<T>, T::Error>
-where
-    T: ?Sized + Ctor<I>,
-    L: LayoutProvider<T, I>,
-{
-    let Some(layout) = L::layout(&init) else {
-        #[cold]
-        #[inline(never)]
-        fn handle_layout_error() -> ! {
-            panic!("Could not construct layout");
+impl<A: BoxAllocator> RawBox<A> {
+    /// allocate memory for T with the layout from `L`
+    fn new<T, I, L, E>(
+        init: &I,
+        alloc: A,
+        zeroed: bool,
+    ) -> Result<(Self, NonNull<T>), EmplaceError<E>>
+    where
+        T: ?Sized,
+        L: LayoutProvider<T, I>,
+    {
+        let layout = L::try_layout(init).map_err(EmplaceError::Layout)?;
+
+        let ptr = if layout.size() == 0 {
+            // SAFETY: the alignment is never zero
+            unsafe {
+                NonNull::new_unchecked(crate::polyfill::without_provenance_mut(layout.align()))
+            }
+        } else {
+            match alloc.allocate(layout, zeroed) {
+                Some(ptr) => ptr,
+                None => return Err(EmplaceError::Alloc(layout)),
+            }
+        };
+
+        let raw = Self { ptr, layout, alloc };
+        let ptr = L::cast(ptr.cast(), init);
+        Ok((raw, ptr))
+    }
+
+    /// # Safety
+    ///
+    /// ptr must be the pointer returned alongside this allocation from `new`,
+    /// and it must point to an initialized value
+    unsafe fn into_box<T: ?Sized>(self, ptr: NonNull<T>) -> A::Box<T> {
+        let raw = ManuallyDrop::new(self);
+        // SAFETY: raw will never be dropped, so the allocator is only moved out once
+        let alloc = unsafe { core::ptr::read(&raw.alloc) };
+
+        // SAFETY: The RawBox was leaked, so the memory won't be double-freed
+        // and the caller ensures that the value is initialized
+        unsafe { alloc.into_box(ptr.as_ptr()) }
+    }
+}
+
+/// An owned heap allocation which doesn't contain an initialized value yet
+///
+/// This allows allocating memory separately from initializing it
+pub struct UninitBox<T: ?Sized> {
+    raw: RawBox<Global>,
+    ptr: NonNull<T>,
+}
+
+// SAFETY: UninitBox owns the allocation, like Box
+unsafe impl<T: ?Sized + Send> Send for UninitBox<T> {}
+// SAFETY: UninitBox doesn't give out any access to the allocation through a shared reference
+unsafe impl<T: ?Sized + Sync> Sync for UninitBox<T> {}
+
+impl<T: ?Sized> UninitBox<T> {
+    /// allocate memory for a value which will be initialized with `init`
+    pub fn try_new_with<I, L>(init: &I) -> Result<Self, EmplaceError<core::convert::Infallible>>
+    where
+        L: LayoutProvider<T, I>,
+    {
+        let (raw, ptr) = RawBox::new::<T, I, L, _>(init, Global, false)?;
+        Ok(Self { raw, ptr })
+    }
+
+    /// allocate memory for a value which will be initialized with `init`
+    pub fn new_with<I, L>(init: &I) -> Self
+    where
+        L: LayoutProvider<T, I>,
+    {
+        match Self::try_new_with::<I, L>(init) {
+            Ok(bx) => bx,
+            Err(err) => err.handle(),
         }
+    }
+
+    /// allocate memory for a value which will be initialized with `init`
+    pub fn new<I>(init: &I) -> Self
+    where
+        T: DefaultLayoutProvider<I>,
+    {
+        Self::new_with::<I, T::LayoutProvider>(init)
+    }
 
-        handle_layout_error()
-    };
+    /// Drop the value in the box, and keep the allocation
+    pub fn from_box(bx: Box<T>) -> Self {
+        let layout = Layout::for_value::<T>(&bx);
+        let ptr = NonNull::from(Box::leak(bx));
+        let bx = Self {
+            raw: RawBox {
+                ptr: ptr.cast(),
+                layout,
+                alloc: Global,
+            },
+            ptr,
+        };
 
-    let is_zeroed = L::is_zeroed(&init);
+        // SAFETY: the value is initialized, and it won't be used after this
+        unsafe { ptr.as_ptr().drop_in_place() };
 
-    // SAFETY: alloc is only called if the layout has non-zero size
-    let ptr = unsafe {
-        if layout.size() == 0 {
-            layout.align() as *mut u8
-        } else if is_zeroed {
-            alloc_zeroed(layout)
-        } else {
-            alloc(layout)
+        bx
+    }
+
+    /// Get a raw pointer to the uninitialized allocation
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Convert into a [`Box`], assuming the allocation was initialized
+    ///
+    /// # Panics
+    ///
+    /// If the layout of the value doesn't match the layout of the allocation,
+    /// the value is dropped and the allocation is freed before panicking
+    ///
+    /// # Safety
+    ///
+    /// The value must have been initialized, for example by writing
+    /// through the pointer from [`UninitBox::as_mut_ptr`]
+    pub unsafe fn assume_init(self) -> Box<T> {
+        // SAFETY: the caller ensures that the value is initialized, and self is dropped
+        // while unwinding, which only frees the allocation
+        unsafe { crate::shared::check_layout(self.ptr, self.raw.layout) };
+
+        // SAFETY: the caller ensures that the value is initialized, and the box
+        // will free the memory with the layout it was allocated with
+        unsafe { self.raw.into_box(self.ptr) }
+    }
+
+    /// Try to initialize the allocation with `f`, and return the allocation back if `f` fails
+    ///
+    /// `f` may be called with any brand, so it can only return the [`Init`] which was made from its argument
+    ///
+    /// # Panics
+    ///
+    /// If the layout of the initialized value doesn't match the layout of the allocation,
+    /// which may happen if the initializer passed to `new` had a different layout
+    pub fn try_init_with<E>(
+        mut self,
+        f: impl for<'b> FnOnce(Uninit<'b, T>) -> Result<Init<'b, T>, E>,
+    ) -> Result<Box<T>, (Self, E)> {
+        // SAFETY: the pointer was allocated with enough space for T
+        // and the allocation is owned by self
+        let uninit = unsafe { Uninit::from_raw(self.as_mut_ptr()) };
+
+        if let Err(err) = f(uninit).map(Init::take_ownership) {
+            return Err((self, err));
         }
-    };
 
-    let Some(ptr) = NonNull::new(ptr) else {
-        handle_alloc_error(layout)
-    };
+        // SAFETY: f can only return an Init with the same brand as the Uninit it was given,
+        // so the value was just initialized
+        Ok(unsafe { self.assume_init() })
+    }
+
+    /// Initialize the allocation with `f`
+    ///
+    /// `f` may be called with any brand, so it can only return the [`Init`] which was made from its argument
+    pub fn init_with(self, f: impl for<'b> FnOnce(Uninit<'b, T>) -> Init<'b, T>) -> Box<T> {
+        let Ok(bx) = self.try_init_with(|uninit| Ok::<_, core::convert::Infallible>(f(uninit)));
+        bx
+    }
 
-    let bx = UninitBox {
-        ptr: ptr.as_ptr(),
-        layout,
-    };
+    /// Try to initialize the allocation, and return the allocation back if initialization fails
+    pub fn try_init<I>(self, init: I) -> Result<Box<T>, (Self, T::Error)>
+    where
+        T: Ctor<I>,
+    {
+        self.try_init_with(|uninit| uninit.try_init(init))
+    }
+
+    /// Initialize the allocation
+    pub fn init<I>(self, init: I) -> Box<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+    {
+        let Ok(bx) = self.try_init(init);
+        bx
+    }
+}
+
+/// initialize a value directly on the heap
+pub fn try_boxed_with<T, I, L>(init: I) -> Result<Box<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    try_boxed_raw::<T, I, L, _, _>(init, Global, |ptr, init| {
+        ptr.try_init(init)?.take_ownership();
+        Ok(())
+    })
+}
 
-    let ptr = L::cast(ptr.cast(), &init);
+/// allocate memory for T, and then initialize it with `init_with`
+///
+/// `init_with` must initialize the pointer if it returns `Ok`
+fn try_boxed_raw<T, I, L, E, A>(
+    init: I,
+    alloc: A,
+    init_with: impl FnOnce(Uninit<T>, I) -> Result<(), E>,
+) -> Result<A::Box<T>, EmplaceError<E>>
+where
+    T: ?Sized,
+    L: LayoutProvider<T, I>,
+    A: BoxAllocator,
+{
+    let is_zeroed = L::is_zeroed(&init);
+    let (raw, ptr) = RawBox::new::<T, I, L, _>(&init, alloc, is_zeroed)?;
 
-    if !is_zeroed {
+    if is_zeroed {
+        // the allocation is already zeroed
+    } else if let Some(byte) = L::byte_pattern(&init) {
+        // SAFETY: ptr was just allocated with raw.layout, and LayoutProvider L ensures
+        // that setting every byte to `byte` is the same as initializing the value
+        unsafe { ptr.cast::<u8>().write_bytes(byte, raw.layout.size()) };
+    } else {
         // SAFETY: ptr was just allocated with enough space for T
         // LayoutProvider L ensures that the layout is correct
-        unsafe { crate::Uninit::from_raw(ptr.as_ptr()) }
-            .try_init(init)?
-            .take_ownership();
+        init_with(unsafe { Uninit::from_raw(ptr.as_ptr()) }, init).map_err(EmplaceError::Init)?;
     }
 
-    core::mem::forget(bx);
+    // SAFETY: the data has been properly initialized by `init_with`, zeroed if `is_zeroed`
+    // is true, or filled with the byte pattern. The box will free the memory with the
+    // layout of the value, so it must match the layout it was allocated with
+    unsafe { crate::shared::check_layout(ptr, raw.layout) };
 
-    // SAFETY: The UninitBox was leaked, so the memory won't be double-freed
-    // and the data has been properly initialized by `try_init`
-    // is `is_zeroed` is true,
-    Ok(unsafe { Box::from_raw(ptr.as_ptr()) })
+    // SAFETY: the data has been properly initialized by `init_with`, zeroed if `is_zeroed`
+    // is true, or filled with the byte pattern
+    Ok(unsafe { raw.into_box(ptr) })
 }
 
 /// initialize a value directly on the heap
@@ -88,12 +356,14 @@ where
     T: ?Sized + Ctor<I, Error = core::convert::Infallible>,
     L: LayoutProvider<T, I>,
 {
-    let Ok(bx) = try_boxed_with::<T, I, L>(init);
-    bx
+    match try_boxed_with::<T, I, L>(init) {
+        Ok(bx) => bx,
+        Err(err) => err.handle(),
+    }
 }
 
 /// initialize a value directly on the heap
-pub fn try_boxed<T, I>(init: I) -> Result<Box<T>, T::Error>
+pub fn try_boxed<T, I>(init: I) -> Result<Box<T>, EmplaceError<T::Error>>
 where
     T: ?Sized + Ctor<I> + DefaultLayoutProvider<I>,
 {
@@ -105,6 +375,374 @@ pub fn boxed<T, I>(init: I) -> Box<T>
 where
     T: ?Sized + Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
 {
-    let Ok(bx) = try_boxed_with::<T, I, T::LayoutProvider>(init);
-    bx
+    match try_boxed_with::<T, I, T::LayoutProvider>(init) {
+        Ok(bx) => bx,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a `T` directly on the heap, and then unsize it to a `U` with `unsize`
+///
+/// `unsize` should just be an identity closure (`|bx| bx`), which captures the unsizing coercion.
+/// See [`boxed_unsize!`](crate::boxed_unsize) for a shorthand
+pub fn try_boxed_unsize<T, U, I>(
+    init: I,
+    unsize: impl FnOnce(Box<T>) -> Box<U>,
+) -> Result<Box<U>, EmplaceError<T::Error>>
+where
+    T: Ctor<I> + DefaultLayoutProvider<I>,
+    U: ?Sized,
+{
+    try_boxed::<T, I>(init).map(unsize)
+}
+
+/// initialize a `T` directly on the heap, and then unsize it to a `U` with `unsize`
+///
+/// `unsize` should just be an identity closure (`|bx| bx`), which captures the unsizing coercion.
+/// See [`boxed_unsize!`](crate::boxed_unsize) for a shorthand
+pub fn boxed_unsize<T, U, I>(init: I, unsize: impl FnOnce(Box<T>) -> Box<U>) -> Box<U>
+where
+    T: Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+    U: ?Sized,
+{
+    unsize(boxed::<T, I>(init))
+}
+
+/// construct a future directly inside a pinned heap allocation
+///
+/// The future is initialized in place by `init`, so it never needs a temporary stack copy
+/// before being boxed, like it would with `Box::pin(fut)`
+///
+/// ```
+/// # use init::boxed::boxed_future;
+/// struct Buffered {
+///     buffer: [u8; 1 << 16],
+/// }
+///
+/// impl core::future::Future for Buffered {
+///     type Output = usize;
+///
+///     fn poll(
+///         self: core::pin::Pin<&mut Self>,
+///         _: &mut core::task::Context<'_>,
+///     ) -> core::task::Poll<usize> {
+///         core::task::Poll::Ready(self.buffer.len())
+///     }
+/// }
+///
+/// let fut = boxed_future::<Buffered, _>(init::init_struct!(Buffered { buffer: init::zeroed() }));
+/// ```
+pub fn boxed_future<F, I>(init: I) -> Pin<Box<F>>
+where
+    F: core::future::Future + Ctor<I, Error = core::convert::Infallible>,
+{
+    Box::into_pin(boxed_with::<
+        F,
+        I,
+        crate::layout_provider::SizedLayoutProvider,
+    >(init))
+}
+
+/// initialize a pinned value directly on the heap
+pub fn try_emplace_pin<T, I, L>(init: I) -> Result<Pin<Box<T>>, EmplaceError<T::Error>>
+where
+    T: ?Sized + PinCtor<I>,
+    L: LayoutProvider<T, I>,
+{
+    let bx = try_boxed_raw::<T, I, L, _, _>(init, Global, |ptr, init| {
+        // SAFETY: the box is pinned right after it's created, and
+        // the box will drop the value before deallocating it
+        let init = unsafe { ptr.into_pinned() }.try_pin_init(init)?;
+        // SAFETY: the box is pinned right after it's created, and
+        // the box will drop the value before deallocating it
+        unsafe { init.take_ownership() };
+        Ok(())
+    })?;
+
+    Ok(Box::into_pin(bx))
+}
+
+/// initialize a pinned value directly on the heap
+pub fn emplace_pin<T, I, L>(init: I) -> Pin<Box<T>>
+where
+    T: ?Sized + PinCtor<I, Error = core::convert::Infallible>,
+    L: LayoutProvider<T, I>,
+{
+    match try_emplace_pin::<T, I, L>(init) {
+        Ok(bx) => bx,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a value directly inside an allocation from `alloc`
+///
+/// This uses the nightly `Allocator` trait with the `allocator_api` feature,
+/// and `allocator-api2`'s `Allocator` trait and `Box` with the `allocator-api2` feature
+#[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+pub fn try_boxed_in<T, I, L, A>(init: I, alloc: A) -> Result<BoxIn<T, A>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+    A: Allocator,
+{
+    try_boxed_raw::<T, I, L, _, _>(init, InAllocator(alloc), |ptr, init| {
+        ptr.try_init(init)?.take_ownership();
+        Ok(())
+    })
+}
+
+/// initialize a value directly inside an allocation from `alloc`
+///
+/// This uses the nightly `Allocator` trait with the `allocator_api` feature,
+/// and `allocator-api2`'s `Allocator` trait and `Box` with the `allocator-api2` feature
+#[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+pub fn boxed_in<T, I, L, A>(init: I, alloc: A) -> BoxIn<T, A>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible>,
+    L: LayoutProvider<T, I>,
+    A: Allocator,
+{
+    match try_boxed_in::<T, I, L, A>(init, alloc) {
+        Ok(bx) => bx,
+        Err(err) => err.handle(),
+    }
+}
+
+/// Extension methods for [`Box`]
+pub trait BoxExt<T: ?Sized>: Sized {
+    /// Drop the value in the box, and initialize a new value in its place
+    ///
+    /// If the layout of the new value doesn't match the layout of the old value,
+    /// then the old allocation is freed and a new one is allocated instead
+    fn try_reinit_with<I, L>(self, init: I) -> Result<Box<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>;
+
+    /// Drop the value in the box, and initialize a new value in its place
+    ///
+    /// If the layout of the new value doesn't match the layout of the old value,
+    /// then the old allocation is freed and a new one is allocated instead
+    fn reinit_with<I, L>(self, init: I) -> Box<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+        L: LayoutProvider<T, I>,
+    {
+        match self.try_reinit_with::<I, L>(init) {
+            Ok(bx) => bx,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// Drop the value in the box, and initialize a new value in its place
+    ///
+    /// If the layout of the new value doesn't match the layout of the old value,
+    /// then the old allocation is freed and a new one is allocated instead
+    fn try_reinit<I>(self, init: I) -> Result<Box<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I> + DefaultLayoutProvider<I>,
+    {
+        self.try_reinit_with::<I, T::LayoutProvider>(init)
+    }
+
+    /// Drop the value in the box, and initialize a new value in its place
+    ///
+    /// If the layout of the new value doesn't match the layout of the old value,
+    /// then the old allocation is freed and a new one is allocated instead
+    fn reinit<I>(self, init: I) -> Box<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+    {
+        self.reinit_with::<I, T::LayoutProvider>(init)
+    }
+}
+
+impl<T: ?Sized> BoxExt<T> for Box<T> {
+    fn try_reinit_with<I, L>(self, init: I) -> Result<Box<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        if L::layout(&init) != Some(Layout::for_value::<T>(&self)) {
+            drop(self);
+            return try_boxed_with::<T, I, L>(init);
+        }
+
+        let UninitBox { raw, .. } = UninitBox::from_box(self);
+        let ptr = L::cast(raw.ptr.cast(), &init);
+
+        UninitBox { raw, ptr }
+            .try_init(init)
+            .map_err(|(_, err)| EmplaceError::Init(err))
+    }
+}
+
+impl<T: ?Sized> Emplace for Box<T> {
+    type Target = T;
+
+    fn try_emplace_with<I, L>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        try_boxed_with::<T, I, L>(init)
+    }
+}
+
+/// Builds a [`Box<[T]>`] one element at a time, for when the length isn't known up front
+///
+/// Elements are initialized directly inside a growing heap buffer, which is
+/// reallocated as needed (like [`Vec`](alloc::vec::Vec))
+pub struct BoxedSliceBuilder<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    len: usize,
+}
+
+// SAFETY: BoxedSliceBuilder owns its elements, like Box<[T]>
+unsafe impl<T: Send> Send for BoxedSliceBuilder<T> {}
+// SAFETY: BoxedSliceBuilder doesn't give out any access to the elements through a shared reference
+unsafe impl<T: Sync> Sync for BoxedSliceBuilder<T> {}
+
+impl<T> Drop for BoxedSliceBuilder<T> {
+    fn drop(&mut self) {
+        let elements = core::ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len);
+        let _dealloc = RawBox {
+            ptr: self.ptr.cast(),
+            layout: self.layout(),
+            alloc: Global,
+        };
+
+        // SAFETY: the first len elements are initialized
+        unsafe { elements.drop_in_place() }
+    }
+}
+
+impl<T> Default for BoxedSliceBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BoxedSliceBuilder<T> {
+    /// Create a new empty builder, this doesn't allocate
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            cap: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+            len: 0,
+        }
+    }
+
+    /// The number of elements which have been initialized
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no elements have been initialized
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    const fn layout(&self) -> Layout {
+        // SAFETY: this layout was already allocated for the current capacity,
+        // or it's zero-sized
+        unsafe { Layout::from_size_align_unchecked(size_of::<T>() * self.cap, align_of::<T>()) }
+    }
+
+    fn resize<E>(&mut self, cap: usize) -> Result<(), EmplaceError<E>> {
+        let Ok(layout) = Layout::array::<T>(cap) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let old_layout = self.layout();
+
+        let ptr = if layout.size() == 0 {
+            if old_layout.size() != 0 {
+                // SAFETY: the buffer was allocated with the old layout
+                unsafe { dealloc(self.ptr.as_ptr().cast(), old_layout) }
+            }
+
+            NonNull::dangling().as_ptr()
+        } else if old_layout.size() == 0 {
+            // SAFETY: the layout has a non-zero size
+            unsafe { alloc(layout) }
+        } else {
+            // SAFETY: the buffer was allocated with the old layout, and the new size is non-zero
+            unsafe { realloc(self.ptr.as_ptr().cast(), old_layout, layout.size()) }
+        };
+
+        let Some(ptr) = NonNull::new(ptr) else {
+            return Err(EmplaceError::Alloc(layout));
+        };
+
+        self.ptr = ptr.cast();
+        self.cap = cap;
+        Ok(())
+    }
+
+    /// Try to initialize a new element at the end of the slice
+    pub fn try_push<I>(&mut self, init: I) -> Result<(), EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+    {
+        if self.len == self.cap {
+            let cap = self
+                .cap
+                .checked_mul(2)
+                .ok_or(EmplaceError::Layout(LayoutProviderError::Overflow))?
+                .max(4);
+            self.resize(cap)?;
+        }
+
+        // SAFETY: len < cap, so the pointer is in bounds of the allocation
+        let ptr = unsafe { self.ptr.as_ptr().add(self.len) };
+        // SAFETY: the element is in bounds and uninitialized
+        let uninit = unsafe { Uninit::from_raw(ptr) };
+        uninit
+            .try_init(init)
+            .map_err(EmplaceError::Init)?
+            .take_ownership();
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Initialize a new element at the end of the slice
+    pub fn push<I>(&mut self, init: I)
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+    {
+        if let Err(err) = self.try_push(init) {
+            err.handle()
+        }
+    }
+
+    /// Shrink the buffer to fit the elements, and convert it to a [`Box<[T]>`]
+    pub fn finish(mut self) -> Box<[T]> {
+        if size_of::<T>() != 0 && self.cap != self.len {
+            if let Err(err) = self.resize(self.len) {
+                err.handle()
+            }
+        }
+
+        let this = ManuallyDrop::new(self);
+        let ptr = core::ptr::slice_from_raw_parts_mut(this.ptr.as_ptr(), this.len);
+        // SAFETY: the buffer was allocated by the global allocator with exactly
+        // the layout of `[T]` with len elements, and all elements are initialized
+        unsafe { Box::from_raw(ptr) }
+    }
+}
+
+/// initialize a boxed slice from an iterator of initializers, without knowing its length up front
+pub fn try_boxed_slice_from_iter<T, I>(iter: I) -> Result<Box<[T]>, EmplaceError<T::Error>>
+where
+    I: IntoIterator,
+    T: Ctor<I::Item>,
+{
+    let mut builder = BoxedSliceBuilder::new();
+    for init in iter {
+        builder.try_push(init)?;
+    }
+    Ok(builder.finish())
 }
@@ -0,0 +1,277 @@
+use alloc::boxed::Box;
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use crate::{
+    layout_provider::{LayoutProvider, LayoutProviderError, SizedLayoutProvider},
+    EmplaceError, Init, Uninit,
+};
+
+#[test]
+fn zero_sized_error() {
+    let init = crate::try_from_fn(|_: Uninit<()>| Err::<Init<()>, _>("failed"));
+    let bx = super::try_boxed_with::<(), _, SizedLayoutProvider>(init);
+    assert_eq!(bx.err(), Some(EmplaceError::Init("failed")));
+}
+
+#[test]
+#[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+fn boxed_in() {
+    use crate::slice::{self, WithLength};
+
+    let bx = super::boxed_in::<[u32], _, slice::SliceLayoutProvider, _>(
+        WithLength::from_init(3, slice::repeat(7u32)),
+        #[cfg(feature = "allocator_api")]
+        alloc::alloc::Global,
+        #[cfg(not(feature = "allocator_api"))]
+        allocator_api2::alloc::Global,
+    );
+    assert_eq!(*bx, [7, 7, 7]);
+}
+
+#[test]
+fn uninit_box_retry() {
+    let bx = super::UninitBox::<u32>::new(&());
+    let fail = crate::try_from_fn(|_: Uninit<u32>| Err::<Init<u32>, _>("failed"));
+    let Err((bx, "failed")) = bx.try_init(fail) else {
+        panic!("initialization should fail")
+    };
+
+    let bx = bx.init(10);
+    assert_eq!(*bx, 10);
+
+    let bx = super::UninitBox::<u32>::new(&());
+    let Err((bx, "failed")) = bx.try_init_with(|_| Err("failed")) else {
+        panic!("initialization should fail")
+    };
+    let bx = bx.init_with(|uninit| uninit.write(20));
+    assert_eq!(*bx, 20);
+}
+
+#[test]
+fn reinit() {
+    use super::BoxExt;
+    use crate::slice::{self, WithLength};
+
+    let bx = super::boxed::<[u32], _>(WithLength::from_init(3, slice::repeat(1u32)));
+    let ptr = &raw const *bx;
+
+    let bx = bx.reinit(WithLength::from_init(3, slice::repeat(2u32)));
+    assert_eq!(*bx, [2, 2, 2]);
+    assert_eq!(&raw const *bx, ptr);
+
+    let bx = bx.reinit(WithLength::from_init(5, slice::repeat(3u32)));
+    assert_eq!(*bx, [3, 3, 3, 3, 3]);
+}
+
+#[test]
+fn layout_error() {
+    use crate::slice::{self, WithLength};
+
+    let init = WithLength::from_init(usize::MAX, slice::repeat(0u64));
+    let bx = super::try_boxed::<[u64], _>(init);
+    assert!(matches!(bx, Err(EmplaceError::Layout(_))));
+}
+
+#[test]
+fn slice_builder() {
+    let bx = super::try_boxed_slice_from_iter::<u32, _>((0..10).filter(|x| x % 3 == 0));
+    assert_eq!(*bx.ok().unwrap(), [0, 3, 6, 9]);
+
+    let mut builder = super::BoxedSliceBuilder::<()>::new();
+    builder.push(crate::from_fn::with_value(()));
+    builder.push(crate::from_fn::with_value(()));
+    assert_eq!(builder.finish().len(), 2);
+}
+
+#[test]
+fn unsize() {
+    let bx: Box<dyn core::any::Any> = crate::boxed_unsize!(u32, 10);
+    assert_eq!(bx.downcast_ref::<u32>(), Some(&10));
+
+    let bx: Result<Box<dyn core::any::Any>, _> = crate::boxed_unsize!(try u8, 1);
+    assert_eq!(bx.ok().unwrap().downcast_ref::<u8>(), Some(&1));
+}
+
+/// A layout provider with a larger alignment than `u32`, which boxes can't free with
+struct OverAlignedLayoutProvider;
+
+// SAFETY: the layout fits a u32, and the pointer is only cast
+unsafe impl LayoutProvider<u32, u32> for OverAlignedLayoutProvider {
+    fn layout(_: &u32) -> Option<Layout> {
+        Layout::from_size_align(4, 64).ok()
+    }
+
+    fn cast(ptr: NonNull<()>, _: &u32) -> NonNull<u32> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_: &u32) -> bool {
+        false
+    }
+}
+
+/// A layout provider which doesn't support any arguments
+struct UnsupportedLayoutProvider;
+
+// SAFETY: there is never a layout
+unsafe impl LayoutProvider<u32, u32> for UnsupportedLayoutProvider {
+    fn layout(_: &u32) -> Option<Layout> {
+        None
+    }
+
+    fn try_layout(_: &u32) -> Result<Layout, LayoutProviderError> {
+        Err(LayoutProviderError::Unsupported)
+    }
+
+    fn cast(ptr: NonNull<()>, _: &u32) -> NonNull<u32> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_: &u32) -> bool {
+        false
+    }
+}
+
+#[test]
+#[should_panic = "The layout provider's layout doesn't match the layout of the value"]
+fn layout_mismatch() {
+    let _ = super::boxed_with::<u32, _, OverAlignedLayoutProvider>(1u32);
+}
+
+#[test]
+#[should_panic = "The layout provider's layout doesn't match the layout of the value"]
+fn uninit_box_layout_mismatch() {
+    let bx = super::UninitBox::<u32>::new_with::<_, OverAlignedLayoutProvider>(&1u32);
+    let _ = bx.init(1u32);
+}
+
+#[test]
+fn uninit_box_length_mismatch() {
+    use crate::slice::CopyFromSliceError;
+
+    let bx = super::UninitBox::<[u32]>::new(&crate::slice::copy_from_slice(&[1, 2]));
+    let Err((bx, err)) = bx.try_init(crate::slice::copy_from_slice(&[1, 2, 3])) else {
+        panic!("initialized a longer slice into the allocation")
+    };
+    assert!(matches!(
+        err,
+        CopyFromSliceError {
+            src_len: 3,
+            dest_len: 2
+        }
+    ));
+
+    let bx = bx.try_init(crate::slice::copy_from_slice(&[3, 4]));
+    assert_eq!(*bx.ok().unwrap(), [3, 4]);
+}
+
+#[test]
+fn layout_provider_error() {
+    let bx = super::try_boxed_with::<u32, _, UnsupportedLayoutProvider>(1u32);
+    assert!(matches!(
+        bx,
+        Err(EmplaceError::Layout(LayoutProviderError::Unsupported))
+    ));
+}
+
+#[test]
+#[cfg(feature = "ptr_metadata")]
+fn metadata_layout_provider() {
+    use crate::layout_provider::{MetadataLayoutProvider, PointeeMetadata};
+
+    #[repr(C)]
+    struct Named {
+        id: u32,
+        name: [u8],
+    }
+
+    struct NamedInit<'a> {
+        id: u32,
+        name: &'a [u8],
+    }
+
+    // SAFETY: the metadata is the length of the name, which is already allocated
+    unsafe impl PointeeMetadata<Named> for NamedInit<'_> {
+        fn metadata(&self) -> usize {
+            self.name.len()
+        }
+    }
+
+    impl crate::Initializer<Named> for NamedInit<'_> {
+        type Error = core::convert::Infallible;
+
+        fn try_init_into(self, mut ptr: Uninit<Named>) -> Result<Init<Named>, Self::Error> {
+            let raw = ptr.as_mut_ptr();
+            // SAFETY: the pointer is valid for writes of a Named with the name's length
+            unsafe {
+                (&raw mut (*raw).id).write(self.id);
+                (&raw mut (*raw).name)
+                    .cast::<u8>()
+                    .copy_from_nonoverlapping(self.name.as_ptr(), self.name.len());
+                Ok(ptr.assume_init())
+            }
+        }
+    }
+
+    let bx = super::boxed_with::<Named, _, MetadataLayoutProvider>(NamedInit {
+        id: 3,
+        name: b"init",
+    });
+    assert_eq!(bx.id, 3);
+    assert_eq!(&bx.name, b"init");
+}
+
+#[test]
+fn cached_layout_provider() {
+    use crate::{
+        layout_provider::{LayoutProvider, WithLayout},
+        slice::{copy_from_slice, SliceLayoutProvider},
+    };
+
+    let rows: [&[u16]; 3] = [&[1, 2], &[3, 4], &[5, 6]];
+    let layout =
+        <SliceLayoutProvider as LayoutProvider<[u16], _>>::layout(&copy_from_slice(rows[0]));
+    let layout = layout.unwrap();
+
+    for row in rows {
+        // SAFETY: all rows have the same length, so they have the same layout
+        let init = unsafe {
+            WithLayout::<[u16], _, SliceLayoutProvider>::new_unchecked(copy_from_slice(row), layout)
+        };
+        let bx = super::try_boxed::<[u16], _>(init);
+        assert_eq!(*bx.ok().unwrap(), *row);
+    }
+
+    let init = WithLayout::<[u16], _, SliceLayoutProvider>::new(copy_from_slice(rows[1]));
+    let init = init.unwrap();
+    assert_eq!(init.layout(), layout);
+    for _ in 0..2 {
+        let bx = super::try_boxed::<[u16], _>(init);
+        assert_eq!(*bx.ok().unwrap(), [3, 4]);
+    }
+}
+
+#[test]
+fn byte_pattern() {
+    use crate::slice::{self, SliceLayoutProvider, WithLength};
+    use crate::PrimitiveLayoutProvider;
+
+    type L = SliceLayoutProvider<PrimitiveLayoutProvider>;
+
+    let init = WithLength::from_init(100, slice::repeat(u32::MAX));
+    assert_eq!(
+        <L as LayoutProvider<[u32], _>>::byte_pattern(&init),
+        Some(0xff)
+    );
+    let bx = super::boxed::<[u32], _>(init);
+    assert_eq!(*bx, [u32::MAX; 100]);
+
+    let init = WithLength::from_init(3, slice::repeat(0x0102_0304u32));
+    assert_eq!(<L as LayoutProvider<[u32], _>>::byte_pattern(&init), None);
+    let bx = super::boxed::<[u32], _>(init);
+    assert_eq!(*bx, [0x0102_0304; 3]);
+
+    let bx = super::boxed::<[bool], _>(WithLength::from_init(5, slice::repeat(true)));
+    assert_eq!(*bx, [true; 5]);
+}
@@ -0,0 +1,45 @@
+//! typestate builders which initialize a struct one field at a time
+//!
+//! A builder tracks which fields have been initialized in its type, so
+//! it can only be finished once every field has been initialized.
+//! These are usually generated with `#[derive(Builder)]`, and can be turned into
+//! an initializer with [`try_from_fn`](crate::try_from_fn) by creating the
+//! builder with [`Build::builder`] and returning the finished builder.
+
+use crate::Uninit;
+
+mod seal {
+    pub trait Seal {}
+}
+
+/// The initialization state of a field in a builder, either [`Set`] or [`Unset`]
+pub trait FieldState: seal::Seal {
+    /// If the field has been initialized
+    const IS_SET: bool;
+}
+
+/// A field which has been initialized
+pub enum Set {}
+
+/// A field which hasn't been initialized yet
+pub enum Unset {}
+
+impl seal::Seal for Set {}
+impl seal::Seal for Unset {}
+
+impl FieldState for Set {
+    const IS_SET: bool = true;
+}
+
+impl FieldState for Unset {
+    const IS_SET: bool = false;
+}
+
+/// A type which can be initialized with a typestate builder
+pub trait Build {
+    /// The builder, with no fields initialized
+    type Builder<'a>;
+
+    /// Create a new builder which will initialize `uninit`
+    fn builder(uninit: Uninit<'_, Self>) -> Self::Builder<'_>;
+}
@@ -0,0 +1,95 @@
+//! initializers for [`bytemuck`](::bytemuck) types
+//!
+//! [`zeroed`] zeroes out any [`bytemuck::Zeroable`](::bytemuck::Zeroable) type, and reports that
+//! the value is zeroed to the allocator, like [`crate::zeroed::zeroed`].
+//! [`from_bytes`] copies bytes into any [`bytemuck::Pod`](::bytemuck::Pod) type, or slice of them
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider},
+    zeroed::ZeroedLayoutProvider,
+    Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Initialize a value by zeroing out its memory
+///
+/// see [`zeroed`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct Zeroed;
+
+/// Initialize a [`bytemuck::Zeroable`](Zeroable) value (or a slice of them) by zeroing out its memory
+///
+/// The layout provider for this initializer reports that the value is zeroed,
+/// so allocating with [`boxed`](crate::boxed::boxed) uses zeroed allocations
+/// instead of writing the zeros.
+pub const fn zeroed() -> Zeroed {
+    Zeroed
+}
+
+impl<T: Zeroable> Initializer<T> for Zeroed {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        Ok(ptr.write(T::zeroed()))
+    }
+}
+
+impl<T: Zeroable> Initializer<[T]> for Zeroed {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, mut ptr: Uninit<[T]>) -> Result<Init<[T]>, Self::Error> {
+        let len = ptr.len();
+        // SAFETY: the pointer is valid for writes of `len` `T`s
+        unsafe { ptr.as_mut_ptr().cast::<T>().write_bytes(0, len) };
+        // SAFETY: all zero bytes is a valid `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: Zeroable> DefaultLayoutProviderFor<T> for Zeroed {
+    type LayoutProvider = ZeroedLayoutProvider;
+}
+
+// SAFETY: T is sized, so layout and cast are trivial, and
+// Zeroed only zeroes out the memory, so is_zeroed may return true
+unsafe impl<T: Zeroable> LayoutProvider<T, Zeroed> for ZeroedLayoutProvider {
+    fn layout(_: &Zeroed) -> Option<Layout> {
+        Some(Layout::new::<T>())
+    }
+
+    fn cast(ptr: NonNull<()>, _: &Zeroed) -> NonNull<T> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_args: &Zeroed) -> bool {
+        true
+    }
+}
+
+/// Copy bytes into a [`bytemuck::Pod`](Pod) value, or a slice of them
+///
+/// see [`from_bytes`] for details
+pub type FromBytes<'a> = crate::from_bytes::FromBytes<'a, Bytemuck>;
+
+pub use crate::from_bytes::{FromBytesLayoutProvider, SizeMismatchError};
+
+/// The backend for [`FromBytes`] which allows any [`bytemuck::Pod`](Pod) type
+pub enum Bytemuck {}
+
+// SAFETY: any bytes are a valid Pod type
+unsafe impl<T: Pod> crate::from_bytes::FromBytesBackend<T> for Bytemuck {}
+
+/// Copy the bytes into a [`bytemuck::Pod`](Pod) value, or a slice of them
+///
+/// The bytes don't need to be aligned. When initializing a slice, the slice's length
+/// is the number of bytes divided by the size of the element.
+/// Initialization fails if the bytes don't exactly fill the value
+pub const fn from_bytes(bytes: &[u8]) -> FromBytes<'_> {
+    FromBytes::new(bytes)
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "alloc")]
+use super::{from_bytes, zeroed, SizeMismatchError};
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed() {
+    let bytes = [1u16, 2, 3].map(u16::to_ne_bytes).concat();
+    let bx = crate::boxed::try_boxed::<[u16], _>(from_bytes(&bytes));
+    assert_eq!(*bx.ok().unwrap(), [1, 2, 3]);
+
+    let bx = crate::boxed::try_boxed::<u32, _>(from_bytes(&[1, 2]));
+    assert!(matches!(
+        bx,
+        Err(crate::EmplaceError::Init(SizeMismatchError {
+            src_len: 2,
+            dest_len: 4
+        }))
+    ));
+
+    let bx = crate::boxed::boxed::<[u64; 4], _>(zeroed());
+    assert_eq!(*bx, [0; 4]);
+}
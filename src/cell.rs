@@ -0,0 +1,140 @@
+//! initializers for [`Cell`], [`RefCell`] and [`UnsafeCell`]
+
+use core::{
+    alloc::Layout,
+    cell::{Cell, RefCell, UnsafeCell},
+    mem::MaybeUninit,
+    ptr::NonNull,
+};
+
+use crate::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider, LayoutProviderError},
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Initialize the value inside of a cell
+///
+/// see [`in_cell`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct InCell<I>(I);
+
+/// Initialize the value inside of a [`Cell`], [`RefCell`] or [`UnsafeCell`] with `init`
+///
+/// [`Cell`] and [`UnsafeCell`] have the same layout as their value, so the value is
+/// initialized in place. The layout of [`RefCell`] isn't specified, so the value is
+/// initialized on the stack, and then moved into the [`RefCell`]
+pub const fn in_cell<I>(init: I) -> InCell<I> {
+    InCell(init)
+}
+
+impl<T: ?Sized + Ctor<I>, I> Initializer<Cell<T>> for InCell<I> {
+    type Error = T::Error;
+
+    fn try_init_into(self, mut ptr: Uninit<Cell<T>>) -> Result<Init<Cell<T>>, Self::Error> {
+        // SAFETY: Cell is `#[repr(transparent)]`, so it's valid to initialize the inner value
+        let inner = unsafe { Uninit::from_raw(ptr.as_mut_ptr() as *mut T) };
+        inner.try_init(self.0)?.take_ownership();
+        // SAFETY: the inner value was initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: ?Sized + Ctor<I>, I> Initializer<UnsafeCell<T>> for InCell<I> {
+    type Error = T::Error;
+
+    fn try_init_into(
+        self,
+        mut ptr: Uninit<UnsafeCell<T>>,
+    ) -> Result<Init<UnsafeCell<T>>, Self::Error> {
+        // SAFETY: UnsafeCell is `#[repr(transparent)]`, so it's valid to initialize the inner value
+        let inner = unsafe { Uninit::from_raw(ptr.as_mut_ptr() as *mut T) };
+        inner.try_init(self.0)?.take_ownership();
+        // SAFETY: the inner value was initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: Ctor<I>, I> Initializer<RefCell<T>> for InCell<I> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<RefCell<T>>) -> Result<Init<RefCell<T>>, Self::Error> {
+        Ok(ptr.write(RefCell::new(try_init_on_stack(self.0)?)))
+    }
+}
+
+/// Initialize a value on the stack, for types whose layout isn't specified
+pub(crate) fn try_init_on_stack<T: Ctor<I>, I>(init: I) -> Result<T, T::Error> {
+    let mut value = MaybeUninit::<T>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+    uninit.try_init(init)?.take_ownership();
+    // SAFETY: the value was just initialized, and ownership was taken from the `Init`
+    Ok(unsafe { value.assume_init() })
+}
+
+/// A [`LayoutProvider`] for [`InCell`], which uses `L` for the value inside the cell
+pub struct CellLayoutProvider<L>(L);
+
+impl<T: ?Sized, I: DefaultLayoutProviderFor<T>> DefaultLayoutProviderFor<Cell<T>> for InCell<I> {
+    type LayoutProvider = CellLayoutProvider<I::LayoutProvider>;
+}
+
+impl<T: ?Sized, I: DefaultLayoutProviderFor<T>> DefaultLayoutProviderFor<UnsafeCell<T>>
+    for InCell<I>
+{
+    type LayoutProvider = CellLayoutProvider<I::LayoutProvider>;
+}
+
+impl<T, I: DefaultLayoutProviderFor<T>> DefaultLayoutProviderFor<RefCell<T>> for InCell<I> {
+    type LayoutProvider = CellLayoutProvider<I::LayoutProvider>;
+}
+
+macro_rules! transparent_cell {
+    ($($cell:ident)*) => {$(
+        // SAFETY: the cell is `#[repr(transparent)]`, so it has the same layout and pointer
+        // metadata as its value, and the rest is forwarded to L
+        unsafe impl<T: ?Sized, I, L: LayoutProvider<T, I>> LayoutProvider<$cell<T>, InCell<I>>
+            for CellLayoutProvider<L>
+        {
+            fn layout(args: &InCell<I>) -> Option<Layout> {
+                L::layout(&args.0)
+            }
+
+            fn try_layout(args: &InCell<I>) -> Result<Layout, LayoutProviderError> {
+                L::try_layout(&args.0)
+            }
+
+            fn cast(ptr: NonNull<()>, args: &InCell<I>) -> NonNull<$cell<T>> {
+                let ptr = L::cast(ptr, &args.0);
+                // SAFETY: the pointer is non-null
+                unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut $cell<T>) }
+            }
+
+            fn is_zeroed(args: &InCell<I>) -> bool {
+                L::is_zeroed(&args.0)
+            }
+        }
+    )*};
+}
+
+transparent_cell!(Cell UnsafeCell);
+
+// SAFETY: RefCell is sized, so layout and cast are trivial, and is_zeroed returns false
+unsafe impl<T, I, L: LayoutProvider<T, I>> LayoutProvider<RefCell<T>, InCell<I>>
+    for CellLayoutProvider<L>
+{
+    fn layout(_: &InCell<I>) -> Option<Layout> {
+        Some(Layout::new::<RefCell<T>>())
+    }
+
+    fn cast(ptr: NonNull<()>, _: &InCell<I>) -> NonNull<RefCell<T>> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_args: &InCell<I>) -> bool {
+        false
+    }
+}
@@ -0,0 +1,34 @@
+use core::cell::{Cell, RefCell, UnsafeCell};
+
+use super::in_cell;
+
+#[test]
+#[cfg(feature = "alloc")]
+fn cells() {
+    use crate::slice::{repeat, WithLength};
+
+    let cell = crate::boxed::boxed::<Cell<u32>, _>(in_cell(5u32));
+    cell.set(cell.get() + 1);
+    assert_eq!(cell.get(), 6);
+
+    let mut cell =
+        crate::boxed::boxed::<UnsafeCell<[u8]>, _>(in_cell(WithLength::from_init(3, repeat(2u8))));
+    assert_eq!(*cell.get_mut(), [2, 2, 2]);
+
+    let cell = crate::boxed::boxed::<RefCell<u64>, _>(in_cell(()));
+    *cell.borrow_mut() += 1;
+    assert_eq!(*cell.borrow(), 1);
+}
+
+#[test]
+fn zeroed_cells() {
+    use crate::layout_provider::{DefaultLayoutProvider, LayoutProvider};
+
+    fn is_zeroed<T: DefaultLayoutProvider<I>, I>(args: I) -> bool {
+        <T::LayoutProvider as LayoutProvider<T, I>>::is_zeroed(&args)
+    }
+
+    assert!(is_zeroed::<Cell<u32>, _>(in_cell(())));
+    assert!(!is_zeroed::<UnsafeCell<u32>, _>(in_cell(1u32)));
+    assert!(!is_zeroed::<RefCell<u32>, _>(in_cell(())));
+}
@@ -0,0 +1,208 @@
+//! combinators which adapt the behavior of an existing initializer
+//!
+//! see [`InitializerCombinators`] for details
+
+use core::{alloc::Layout, marker::PhantomData, ptr::NonNull};
+
+use crate::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider, LayoutProviderError},
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Combinators which adapt the behavior of an initializer for `T`
+///
+/// The combinators remember the type being initialized, so it can be inferred
+/// from the place the combinator is used
+pub trait InitializerCombinators<T: ?Sized>: Initializer<T> + Sized {
+    /// Convert the error from this initializer with `f`
+    fn map_err<E, F: FnOnce(Self::Error) -> E>(self, f: F) -> MapErr<T, Self, F> {
+        MapErr {
+            init: self,
+            f,
+            _value: PhantomData,
+        }
+    }
+
+    /// If this initializer fails, initialize the value with `fallback` instead
+    ///
+    /// The memory is laid out for this initializer, so for unsized values the
+    /// fallback must accept a value with the same pointer metadata
+    fn or_else<J>(self, fallback: J) -> OrElse<T, Self, J>
+    where
+        T: Ctor<J>,
+    {
+        OrElse {
+            init: self,
+            fallback,
+            _value: PhantomData,
+        }
+    }
+
+    /// After this initializer succeeds, run `f` on the initialized value
+    ///
+    /// `f` may modify the value, or fail with the initializer's error. If `f` fails
+    /// then it is responsible for dropping the value, which happens when the [`Init`] is dropped.
+    fn and_then<F>(self, f: F) -> AndThen<T, Self, F>
+    where
+        F: for<'a> FnOnce(Init<'a, T>) -> Result<Init<'a, T>, Self::Error>,
+    {
+        AndThen {
+            init: self,
+            f,
+            _value: PhantomData,
+        }
+    }
+
+    /// After this initializer succeeds, call `f` with a reference to the initialized value
+    fn inspect_init<F: FnOnce(&T)>(self, f: F) -> Inspect<T, Self, F> {
+        Inspect {
+            init: self,
+            f,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized, I: Initializer<T>> InitializerCombinators<T> for I {}
+
+/// An initializer which converts the error of `I`, see [`InitializerCombinators::map_err`]
+pub struct MapErr<T: ?Sized, I, F> {
+    init: I,
+    f: F,
+    _value: PhantomData<fn() -> *const T>,
+}
+
+/// An initializer which falls back to `J` if `I` fails, see [`InitializerCombinators::or_else`]
+pub struct OrElse<T: ?Sized, I, J> {
+    init: I,
+    fallback: J,
+    _value: PhantomData<fn() -> *const T>,
+}
+
+/// An initializer which runs `F` after `I` succeeds, see [`InitializerCombinators::and_then`]
+pub struct AndThen<T: ?Sized, I, F> {
+    init: I,
+    f: F,
+    _value: PhantomData<fn() -> *const T>,
+}
+
+/// An initializer which inspects the value after `I` succeeds, see [`InitializerCombinators::inspect_init`]
+pub struct Inspect<T: ?Sized, I, F> {
+    init: I,
+    f: F,
+    _value: PhantomData<fn() -> *const T>,
+}
+
+impl<T: ?Sized + Ctor<I>, I, E, F: FnOnce(T::Error) -> E> Initializer<T> for MapErr<T, I, F> {
+    type Error = E;
+
+    fn try_init_into(self, ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        ptr.try_init(self.init).map_err(self.f)
+    }
+}
+
+impl<T: ?Sized + Ctor<I> + Ctor<J>, I, J> Initializer<T> for OrElse<T, I, J> {
+    type Error = <T as Ctor<J>>::Error;
+
+    fn try_init_into(self, mut ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        let raw = ptr.as_mut_ptr();
+        match ptr.try_init(self.init) {
+            Ok(init) => Ok(init),
+            // SAFETY: initializers leave the memory uninitialized when they fail,
+            // so the pointer is still valid to initialize
+            Err(_) => unsafe { Uninit::from_raw(raw) }.try_init(self.fallback),
+        }
+    }
+}
+
+impl<T: ?Sized + Ctor<I>, I, F> Initializer<T> for AndThen<T, I, F>
+where
+    F: for<'a> FnOnce(Init<'a, T>) -> Result<Init<'a, T>, T::Error>,
+{
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        (self.f)(ptr.try_init(self.init)?)
+    }
+}
+
+impl<T: ?Sized + Ctor<I>, I, F: FnOnce(&T)> Initializer<T> for Inspect<T, I, F> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        let init = ptr.try_init(self.init)?;
+        (self.f)(init.as_ref());
+        Ok(init)
+    }
+}
+
+/// A [`LayoutProvider`] for the combinators in this module, which forwards the layout to `L`
+///
+/// Only [`MapErr`] forwards `is_zeroed`, since the other combinators must run
+/// even if the value is zeroed
+pub struct CombinatorLayoutProvider<L>(L);
+
+macro_rules! combinator {
+    ($($combinator:ident($generic:ident $field:ident) => |$args:ident| $is_zeroed:expr;)*) => {$(
+        impl<T: ?Sized, I: Clone, $generic: Clone> Clone for $combinator<T, I, $generic> {
+            fn clone(&self) -> Self {
+                Self {
+                    init: self.init.clone(),
+                    $field: self.$field.clone(),
+                    _value: PhantomData,
+                }
+            }
+        }
+
+        impl<T: ?Sized, I: Copy, $generic: Copy> Copy for $combinator<T, I, $generic> {}
+
+        impl<T: ?Sized, I: core::fmt::Debug, $generic: core::fmt::Debug> core::fmt::Debug
+            for $combinator<T, I, $generic>
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($combinator))
+                    .field("init", &self.init)
+                    .field(stringify!($field), &self.$field)
+                    .finish()
+            }
+        }
+
+        impl<T: ?Sized, I: DefaultLayoutProviderFor<T>, $generic> DefaultLayoutProviderFor<T>
+            for $combinator<T, I, $generic>
+        {
+            type LayoutProvider = CombinatorLayoutProvider<I::LayoutProvider>;
+        }
+
+        // SAFETY: the layout and cast are forwarded to L, and is_zeroed only returns
+        // true if the combinator doesn't run any code after the initializer
+        unsafe impl<T: ?Sized, I, $generic, L: LayoutProvider<T, I>>
+            LayoutProvider<T, $combinator<T, I, $generic>> for CombinatorLayoutProvider<L>
+        {
+            fn layout(args: &$combinator<T, I, $generic>) -> Option<Layout> {
+                L::layout(&args.init)
+            }
+
+            fn try_layout(args: &$combinator<T, I, $generic>) -> Result<Layout, LayoutProviderError> {
+                L::try_layout(&args.init)
+            }
+
+            fn cast(ptr: NonNull<()>, args: &$combinator<T, I, $generic>) -> NonNull<T> {
+                L::cast(ptr, &args.init)
+            }
+
+            fn is_zeroed($args: &$combinator<T, I, $generic>) -> bool {
+                $is_zeroed
+            }
+        }
+    )*};
+}
+
+combinator! {
+    MapErr(F f) => |args| L::is_zeroed(&args.init);
+    OrElse(J fallback) => |_args| false;
+    AndThen(F f) => |_args| false;
+    Inspect(F f) => |_args| false;
+}
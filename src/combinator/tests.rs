@@ -0,0 +1,87 @@
+use core::{cell::Cell, mem::MaybeUninit, num::NonZero};
+
+use super::InitializerCombinators;
+use crate::{Ctor, Init, Uninit, ZeroError};
+
+fn init<T: Ctor<I>, I>(init: I) -> Result<T, T::Error> {
+    let mut value = MaybeUninit::<T>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+    uninit.try_init(init)?.take_ownership();
+    // SAFETY: the value was just initialized
+    Ok(unsafe { value.assume_init() })
+}
+
+#[test]
+fn map_err() {
+    let value = init::<NonZero<u8>, _>(0u8.map_err(|ZeroError| "zero"));
+    assert_eq!(value, Err("zero"));
+}
+
+#[test]
+fn or_else() {
+    assert_eq!(
+        init::<NonZero<u8>, _>(0u8.or_else(3u8)),
+        Ok(NonZero::new(3).unwrap())
+    );
+    assert_eq!(
+        init::<NonZero<u8>, _>(2u8.or_else(3u8)),
+        Ok(NonZero::new(2).unwrap())
+    );
+    assert_eq!(init::<NonZero<u8>, _>(0u8.or_else(0u8)), Err(ZeroError));
+}
+
+#[test]
+fn and_then() {
+    let value = init::<[u32; 4], _>(().and_then(|mut init: Init<[u32; 4]>| {
+        // SAFETY: the pointer is valid for writes, and the value stays initialized
+        unsafe { (*init.as_mut_ptr())[2] = 7 };
+        Ok(init)
+    }));
+    assert_eq!(value, Ok([0, 0, 7, 0]));
+
+    let value = init::<NonZero<u8>, _>(1u8.and_then(|_| Err(ZeroError)));
+    assert_eq!(value, Err(ZeroError));
+}
+
+#[test]
+fn inspect() {
+    let seen = Cell::new(0);
+    let value = init::<u32, _>(5u32.inspect_init(|value| seen.set(*value)));
+    assert_eq!(value, Ok(5));
+    assert_eq!(seen.get(), 5);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_combinators() {
+    use crate::layout_provider::{DefaultLayoutProvider, LayoutProvider};
+    use crate::slice::{repeat, WithLength};
+
+    fn is_zeroed<T: ?Sized + DefaultLayoutProvider<I>, I>(args: I) -> bool {
+        <T::LayoutProvider as LayoutProvider<T, I>>::is_zeroed(&args)
+    }
+
+    let seen = Cell::new(0);
+    let slice = crate::boxed::boxed::<[u8], _>(
+        WithLength::from_init(3, repeat(())).inspect_init(|slice: &[u8]| seen.set(slice.len())),
+    );
+    assert_eq!(*slice, [0, 0, 0]);
+    assert_eq!(seen.get(), 3);
+
+    assert!(is_zeroed::<u32, _>(
+        ().map_err(|e: core::convert::Infallible| e)
+    ));
+    assert!(!is_zeroed::<u32, _>(().inspect_init(|_: &u32| ())));
+}
+
+#[test]
+fn iterator_methods() {
+    #[allow(unused_imports)]
+    use crate::*;
+
+    let mut seen = 0;
+    let sum = [1u32, 2, 3].into_iter().inspect(|x| seen += x).sum::<u32>();
+    assert_eq!(sum, 6);
+    assert_eq!(seen, 6);
+}
@@ -0,0 +1,321 @@
+//! traits and errors shared by all of the emplacement entry points
+
+use core::{
+    alloc::Layout,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{
+    layout_provider::{DefaultLayoutProvider, LayoutProvider, LayoutProviderError},
+    Ctor, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A smart pointer which can have a value initialized directly inside its allocation
+pub trait Emplace: Sized {
+    /// The type of the value the pointer points to
+    type Target: ?Sized;
+
+    /// initialize a value directly inside a new allocation
+    fn try_emplace_with<I, L>(
+        init: I,
+    ) -> Result<Self, EmplaceError<<Self::Target as Ctor<I>>::Error>>
+    where
+        Self::Target: Ctor<I>,
+        L: LayoutProvider<Self::Target, I>;
+
+    /// initialize a value directly inside a new allocation
+    #[cfg(feature = "alloc")]
+    fn emplace_with<I, L>(init: I) -> Self
+    where
+        Self::Target: Ctor<I, Error = core::convert::Infallible>,
+        L: LayoutProvider<Self::Target, I>,
+    {
+        match Self::try_emplace_with::<I, L>(init) {
+            Ok(ptr) => ptr,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// initialize a value directly inside a new allocation
+    fn try_emplace<I>(init: I) -> Result<Self, EmplaceError<<Self::Target as Ctor<I>>::Error>>
+    where
+        Self::Target: Ctor<I> + DefaultLayoutProvider<I>,
+    {
+        Self::try_emplace_with::<I, <Self::Target as DefaultLayoutProvider<I>>::LayoutProvider>(
+            init,
+        )
+    }
+
+    /// initialize a value directly inside a new allocation
+    #[cfg(feature = "alloc")]
+    fn emplace<I>(init: I) -> Self
+    where
+        Self::Target: Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+    {
+        Self::emplace_with::<I, <Self::Target as DefaultLayoutProvider<I>>::LayoutProvider>(init)
+    }
+}
+
+/// The ways emplacing a value into a new allocation can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmplaceError<E> {
+    /// The layout provider couldn't compute a layout for the value
+    Layout(LayoutProviderError),
+    /// The allocator couldn't allocate memory with the given layout
+    Alloc(Layout),
+    /// The initializer failed
+    Init(E),
+}
+
+/// Terminal combinators which emplace an initializer into a smart pointer
+///
+/// The pointee type is inferred from context, and its default layout provider is used
+#[cfg(feature = "alloc")]
+pub trait EmplaceExt: Sized {
+    /// initialize a value directly inside a new allocation
+    fn try_emplace<P>(self) -> Result<P, EmplaceError<<P::Target as Ctor<Self>>::Error>>
+    where
+        P: Emplace,
+        P::Target: Ctor<Self> + DefaultLayoutProvider<Self>,
+    {
+        P::try_emplace(self)
+    }
+
+    /// initialize a value directly inside a new allocation
+    fn emplace<P>(self) -> P
+    where
+        P: Emplace,
+        P::Target: Ctor<Self, Error = core::convert::Infallible> + DefaultLayoutProvider<Self>,
+    {
+        P::emplace(self)
+    }
+
+    /// initialize a value directly on the heap
+    fn try_boxed<T>(self) -> Result<alloc::boxed::Box<T>, EmplaceError<T::Error>>
+    where
+        T: ?Sized + Ctor<Self> + DefaultLayoutProvider<Self>,
+    {
+        crate::boxed::try_boxed(self)
+    }
+
+    /// initialize a value directly on the heap
+    fn boxed<T>(self) -> alloc::boxed::Box<T>
+    where
+        T: ?Sized + Ctor<Self, Error = core::convert::Infallible> + DefaultLayoutProvider<Self>,
+    {
+        crate::boxed::boxed(self)
+    }
+
+    /// initialize a pinned value directly on the heap
+    fn try_pin_boxed<T>(
+        self,
+    ) -> Result<
+        core::pin::Pin<alloc::boxed::Box<T>>,
+        EmplaceError<<T as crate::PinCtor<Self>>::Error>,
+    >
+    where
+        T: ?Sized + crate::PinCtor<Self> + DefaultLayoutProvider<Self>,
+    {
+        crate::boxed::try_emplace_pin::<T, Self, T::LayoutProvider>(self)
+    }
+
+    /// initialize a pinned value directly on the heap
+    fn pin_boxed<T>(self) -> core::pin::Pin<alloc::boxed::Box<T>>
+    where
+        T: ?Sized
+            + crate::PinCtor<Self, Error = core::convert::Infallible>
+            + DefaultLayoutProvider<Self>,
+    {
+        crate::boxed::emplace_pin::<T, Self, T::LayoutProvider>(self)
+    }
+
+    /// initialize a value directly inside an [`Rc`](alloc::rc::Rc) allocation
+    fn try_rced<T>(self) -> Result<alloc::rc::Rc<T>, EmplaceError<T::Error>>
+    where
+        T: ?Sized + Ctor<Self> + DefaultLayoutProvider<Self>,
+    {
+        crate::rc::try_rc(self)
+    }
+
+    /// initialize a value directly inside an [`Rc`](alloc::rc::Rc) allocation
+    fn rced<T>(self) -> alloc::rc::Rc<T>
+    where
+        T: ?Sized + Ctor<Self, Error = core::convert::Infallible> + DefaultLayoutProvider<Self>,
+    {
+        crate::rc::rc(self)
+    }
+
+    /// initialize a value directly inside an [`Arc`](alloc::sync::Arc) allocation
+    #[cfg(target_has_atomic = "ptr")]
+    fn try_arced<T>(self) -> Result<alloc::sync::Arc<T>, EmplaceError<T::Error>>
+    where
+        T: ?Sized + Ctor<Self> + DefaultLayoutProvider<Self>,
+    {
+        crate::arc::try_arc(self)
+    }
+
+    /// initialize a value directly inside an [`Arc`](alloc::sync::Arc) allocation
+    #[cfg(target_has_atomic = "ptr")]
+    fn arced<T>(self) -> alloc::sync::Arc<T>
+    where
+        T: ?Sized + Ctor<Self, Error = core::convert::Infallible> + DefaultLayoutProvider<Self>,
+    {
+        crate::arc::arc(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> EmplaceExt for I {}
+
+/// An owning pointer to a value inside a borrowed buffer, created by [`try_emplace_in`]
+///
+/// The value is dropped when this is dropped
+pub struct Emplaced<'a, T: ?Sized> {
+    ptr: NonNull<T>,
+    _buffer: PhantomData<(&'a mut [MaybeUninit<u8>], T)>,
+}
+
+// SAFETY: Emplaced owns the value, like Box
+unsafe impl<T: ?Sized + Send> Send for Emplaced<'_, T> {}
+// SAFETY: Emplaced only gives shared access to the value through a shared reference
+unsafe impl<T: ?Sized + Sync> Sync for Emplaced<'_, T> {}
+
+impl<T: ?Sized> Drop for Emplaced<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the value is initialized, and owned by self
+        unsafe { self.ptr.as_ptr().drop_in_place() }
+    }
+}
+
+impl<T: ?Sized> Deref for Emplaced<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the value is initialized, and owned by self
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for Emplaced<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the value is initialized, and uniquely owned by self
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+/// initialize a value inside of the memory of `buffer`
+///
+/// The value is placed at the first offset in the buffer which is aligned for the value,
+/// and if the value doesn't fit in the rest of the buffer, this returns [`EmplaceError::Alloc`]
+pub fn try_emplace_in<'a, T, I, L>(
+    buffer: &'a mut [MaybeUninit<u8>],
+    init: I,
+) -> Result<Emplaced<'a, T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    let ptr = NonNull::from(&mut *buffer).cast::<u8>();
+    // SAFETY: the buffer is mutably borrowed for 'a, and is valid for
+    // reads and writes of its entire length
+    unsafe { try_emplace_in_raw::<T, I, L>(ptr, buffer.len(), init) }
+}
+
+/// initialize a value inside of the memory of `buffer`
+///
+/// The value is placed at the first offset in the buffer which is aligned for the value,
+/// and if the value doesn't fit in the rest of the buffer, this returns [`EmplaceError::Alloc`]
+pub fn try_emplace_in_default<'a, T, I>(
+    buffer: &'a mut [MaybeUninit<u8>],
+    init: I,
+) -> Result<Emplaced<'a, T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I> + DefaultLayoutProvider<I>,
+{
+    try_emplace_in::<T, I, T::LayoutProvider>(buffer, init)
+}
+
+/// initialize a value inside of the memory starting at `ptr` with `capacity` bytes
+///
+/// The value is placed at the first offset in the memory which is aligned for the value,
+/// and if the value doesn't fit in the rest of the memory, this returns [`EmplaceError::Alloc`]
+///
+/// # Safety
+///
+/// * `ptr` must be valid for reads and writes of `capacity` bytes for the lifetime `'a`
+/// * the memory must not be accessed through any other pointer for the lifetime `'a`
+pub unsafe fn try_emplace_in_raw<'a, T, I, L>(
+    ptr: NonNull<u8>,
+    capacity: usize,
+    init: I,
+) -> Result<Emplaced<'a, T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    let layout = L::try_layout(&init).map_err(EmplaceError::Layout)?;
+
+    let offset = ptr.as_ptr().align_offset(layout.align());
+    let fits = offset
+        .checked_add(layout.size())
+        .is_some_and(|end| end <= capacity);
+
+    if !fits {
+        return Err(EmplaceError::Alloc(layout));
+    }
+
+    // SAFETY: offset + layout.size() <= capacity, so the offset is in bounds
+    let ptr = unsafe { ptr.add(offset) };
+    let ptr = L::cast(ptr.cast(), &init);
+
+    // SAFETY: the pointer is aligned for the layout, the value fits in the memory,
+    // and the caller ensures that the memory is valid and unaliased for 'a
+    let uninit = unsafe { Uninit::from_raw(ptr.as_ptr()) };
+    let init = uninit.try_init(init).map_err(EmplaceError::Init)?;
+    // the value is owned by the returned pointer from now on
+    init.take_ownership();
+    Ok(Emplaced {
+        ptr,
+        _buffer: PhantomData,
+    })
+}
+
+impl<E> EmplaceError<E> {
+    /// Get the initializer's error, panicking on layout failures
+    /// and calling [`handle_alloc_error`](alloc::alloc::handle_alloc_error)
+    /// on allocation failures
+    #[cfg(feature = "alloc")]
+    pub fn into_init_error(self) -> E {
+        match self {
+            Self::Layout(err) => handle_layout_error(err),
+            Self::Alloc(layout) => alloc::alloc::handle_alloc_error(layout),
+            Self::Init(err) => err,
+        }
+    }
+}
+
+impl EmplaceError<core::convert::Infallible> {
+    /// Panic on layout failures and call [`handle_alloc_error`](alloc::alloc::handle_alloc_error)
+    /// on allocation failures, since the initializer can't fail
+    #[cfg(feature = "alloc")]
+    pub fn handle(self) -> ! {
+        match self {
+            Self::Layout(err) => handle_layout_error(err),
+            Self::Alloc(layout) => alloc::alloc::handle_alloc_error(layout),
+            Self::Init(err) => match err {},
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cold]
+#[inline(never)]
+fn handle_layout_error(err: LayoutProviderError) -> ! {
+    panic!("Could not construct layout: {err:?}");
+}
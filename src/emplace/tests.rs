@@ -0,0 +1,51 @@
+use core::mem::MaybeUninit;
+
+use crate::{
+    slice::{self, WithLength},
+    EmplaceError,
+};
+
+#[test]
+fn emplace_in() {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 32];
+
+    let init = WithLength::from_init(3, slice::repeat(7u32));
+    let value = super::try_emplace_in_default::<[u32], _>(&mut buffer, init);
+    let value = value.ok().unwrap();
+    assert_eq!(*value, [7, 7, 7]);
+    assert_eq!(value.as_ptr().cast::<u32>().align_offset(4), 0);
+    drop(value);
+
+    let init = WithLength::from_init(9, slice::repeat(7u32));
+    let value = super::try_emplace_in_default::<[u32], _>(&mut buffer, init);
+    assert!(matches!(value, Err(EmplaceError::Alloc(_))));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn combinators() {
+    use super::EmplaceExt;
+    use alloc::{boxed::Box, rc::Rc};
+
+    let bx: Box<[u32]> = WithLength::from_init(2, slice::repeat(1u32)).boxed();
+    assert_eq!(*bx, [1, 1]);
+
+    let rc: Rc<u32> = 10.rced();
+    assert_eq!(*rc, 10);
+
+    let bx: Box<u32> = 10.emplace();
+    assert_eq!(*bx, 10);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn combinators_from_root() {
+    use crate::*;
+    use alloc::boxed::Box;
+
+    let bx: Box<u32> = 10u32.boxed();
+    assert_eq!(*bx, 10);
+
+    let bx: Box<[u32]> = slice::WithLength::from_init(2, slice::repeat(3u32)).boxed();
+    assert_eq!(*bx, [3, 3]);
+}
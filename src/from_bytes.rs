@@ -0,0 +1,163 @@
+//! The byte copying initializer shared by the [`bytemuck`](crate::bytemuck) and
+//! [`zerocopy`](crate::zerocopy) integrations, which only differ in which types
+//! can be initialized from any bytes
+
+use core::{alloc::Layout, marker::PhantomData, ptr::NonNull};
+
+use crate::{
+    layout_provider::{
+        DefaultLayoutProviderFor, LayoutProvider, LayoutProviderError, SizedLayoutProvider,
+    },
+    Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A marker for a crate which knows when any bytes are a valid `T`
+///
+/// # Safety
+///
+/// Any initialized bytes must be a valid `T`
+pub unsafe trait FromBytesBackend<T> {}
+
+/// Copy bytes into a value, or a slice of them, if `B` allows any bytes for the value
+///
+/// see [`bytemuck::from_bytes`](crate::bytemuck::from_bytes) and
+/// [`zerocopy::from_bytes`](crate::zerocopy::from_bytes) for details
+pub struct FromBytes<'a, B> {
+    bytes: &'a [u8],
+    _backend: PhantomData<fn() -> B>,
+}
+
+impl<B> Clone for FromBytes<'_, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B> Copy for FromBytes<'_, B> {}
+
+impl<B> core::fmt::Debug for FromBytes<'_, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FromBytes")
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+/// The error type for [`FromBytes`], the number of bytes doesn't match the size of the value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeMismatchError {
+    /// The number of bytes to copy from
+    pub src_len: usize,
+    /// The size of the value to copy into
+    pub dest_len: usize,
+}
+
+impl<'a, B> FromBytes<'a, B> {
+    pub(crate) const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Copy the bytes into `ptr`, which must be valid for writes of `dest_len` bytes
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of `dest_len` bytes, and not overlap the bytes
+    const unsafe fn copy_to(self, ptr: *mut u8, dest_len: usize) -> Result<(), SizeMismatchError> {
+        if self.bytes.len() != dest_len {
+            return Err(SizeMismatchError {
+                src_len: self.bytes.len(),
+                dest_len,
+            });
+        }
+
+        // SAFETY: the caller ensures that ptr is valid for writes of exactly as many bytes
+        // as we have, and they don't overlap
+        unsafe { ptr.copy_from_nonoverlapping(self.bytes.as_ptr(), dest_len) };
+        Ok(())
+    }
+}
+
+impl<T, B: FromBytesBackend<T>> Initializer<T> for FromBytes<'_, B> {
+    type Error = SizeMismatchError;
+
+    fn try_init_into(self, mut ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        // SAFETY: the pointer is valid for writes of one `T`, and isn't aliased
+        unsafe { self.copy_to(ptr.as_mut_ptr().cast(), size_of::<T>())? };
+        // SAFETY: B ensures that any bytes are a valid `T`, and all of them were written
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T, B: FromBytesBackend<T>> Initializer<[T]> for FromBytes<'_, B> {
+    type Error = SizeMismatchError;
+
+    fn try_init_into(self, mut ptr: Uninit<[T]>) -> Result<Init<[T]>, Self::Error> {
+        // the slice fits in memory, so its size can't overflow
+        let dest_len = ptr.len() * size_of::<T>();
+        // SAFETY: the pointer is valid for writes of the whole slice, and isn't aliased
+        unsafe { self.copy_to(ptr.as_mut_ptr().cast(), dest_len)? };
+        // SAFETY: B ensures that any bytes are a valid `T`, and all of them were written
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T, B: FromBytesBackend<T>> DefaultLayoutProviderFor<T> for FromBytes<'_, B> {
+    type LayoutProvider = SizedLayoutProvider;
+}
+
+impl<T, B: FromBytesBackend<T>> DefaultLayoutProviderFor<[T]> for FromBytes<'_, B> {
+    type LayoutProvider = FromBytesLayoutProvider;
+}
+
+/// A layout provider for slices initialized with [`FromBytes`], where the length
+/// is the number of bytes divided by the size of the element
+///
+/// If the number of bytes isn't a multiple of the size of the element, then
+/// [`LayoutProviderError::InvalidLength`] is returned, and zero-sized elements
+/// are [`LayoutProviderError::Unsupported`]
+pub struct FromBytesLayoutProvider;
+
+impl FromBytesLayoutProvider {
+    const fn len<T, B>(args: &FromBytes<'_, B>) -> Result<usize, LayoutProviderError> {
+        let size = size_of::<T>();
+        if size == 0 {
+            return Err(LayoutProviderError::Unsupported);
+        }
+
+        if !args.bytes.len().is_multiple_of(size) {
+            return Err(LayoutProviderError::InvalidLength);
+        }
+
+        Ok(args.bytes.len() / size)
+    }
+}
+
+// SAFETY: the layout is an array of `T` with the length from `len`, and cast returns
+// a slice with the same length, is_zeroed returns false
+unsafe impl<T, B: FromBytesBackend<T>> LayoutProvider<[T], FromBytes<'_, B>>
+    for FromBytesLayoutProvider
+{
+    fn layout(args: &FromBytes<'_, B>) -> Option<Layout> {
+        <Self as LayoutProvider<[T], _>>::try_layout(args).ok()
+    }
+
+    fn try_layout(args: &FromBytes<'_, B>) -> Result<Layout, LayoutProviderError> {
+        let len = Self::len::<T, B>(args)?;
+        Layout::array::<T>(len).map_err(|_| LayoutProviderError::Overflow)
+    }
+
+    fn cast(ptr: NonNull<()>, args: &FromBytes<'_, B>) -> NonNull<[T]> {
+        let len = Self::len::<T, B>(args).unwrap_or(0);
+        NonNull::slice_from_raw_parts(ptr.cast(), len)
+    }
+
+    fn is_zeroed(_args: &FromBytes<'_, B>) -> bool {
+        false
+    }
+}
@@ -0,0 +1,52 @@
+use super::{FromBytes, FromBytesBackend, FromBytesLayoutProvider, SizeMismatchError};
+use crate::layout_provider::{LayoutProvider, LayoutProviderError};
+
+/// A backend for the tests which only allows integers
+struct Integers;
+
+// SAFETY: any bytes are a valid integer
+unsafe impl FromBytesBackend<u16> for Integers {}
+// SAFETY: any bytes are a valid integer
+unsafe impl FromBytesBackend<u32> for Integers {}
+// SAFETY: there are no bytes in a unit
+unsafe impl FromBytesBackend<()> for Integers {}
+
+fn from_bytes(bytes: &[u8]) -> FromBytes<'_, Integers> {
+    FromBytes::new(bytes)
+}
+
+#[test]
+fn from_bytes_stack() {
+    let mut value = core::mem::MaybeUninit::<u32>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(value.as_mut_ptr()) };
+    let bytes = 7u32.to_ne_bytes();
+    let init = uninit.try_init(from_bytes(&bytes)).ok().unwrap();
+    assert_eq!(*init.as_ref(), 7);
+
+    let mut value = core::mem::MaybeUninit::<u32>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(value.as_mut_ptr()) };
+    let err = uninit.try_init(from_bytes(&[1, 2])).err().unwrap();
+    assert_eq!(
+        err,
+        SizeMismatchError {
+            src_len: 2,
+            dest_len: 4
+        }
+    );
+}
+
+#[test]
+fn from_bytes_layout() {
+    type L = FromBytesLayoutProvider;
+
+    let layout = <L as LayoutProvider<[u16], _>>::try_layout(&from_bytes(&[0; 6]));
+    assert_eq!(layout, Ok(core::alloc::Layout::new::<[u16; 3]>()));
+
+    let layout = <L as LayoutProvider<[u16], _>>::try_layout(&from_bytes(&[0; 5]));
+    assert_eq!(layout, Err(LayoutProviderError::InvalidLength));
+
+    let layout = <L as LayoutProvider<[()], _>>::try_layout(&from_bytes(&[]));
+    assert_eq!(layout, Err(LayoutProviderError::Unsupported));
+}
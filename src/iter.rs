@@ -0,0 +1,393 @@
+//! iterators over the elements of [`Uninit<[T]>`] and [`Init<[T]>`]
+
+use core::{iter::FusedIterator, marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+use crate::{polyfill, Init, Uninit};
+
+#[cfg(test)]
+mod tests;
+
+/// An iterator over a [`Uninit<[T]>`]
+pub struct UninitSliceIter<'brand, T> {
+    /// The pointer to the next element to return, or the past-the-end location
+    /// if the iterator is empty.
+    ///
+    /// This address will be used for all ZST elements, never changed.
+    ptr: NonNull<T>,
+    /// For non-ZSTs, the non-null pointer to the past-the-end element.
+    ///
+    /// For ZSTs, this is `ptr::without_provenance_mut(len)`.
+    end_or_len: *mut T,
+    _marker: PhantomData<Uninit<'brand, [T]>>,
+}
+
+/// An iterator over a [`Init<[T]>`]
+///
+/// Any elements which weren't yielded are dropped when the iterator is dropped
+pub struct InitSliceIter<'brand, T> {
+    iter: UninitSliceIter<'brand, T>,
+}
+
+impl<T> Drop for InitSliceIter<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the remaining elements of the iterator are initialized and owned by the iterator
+        drop(unsafe { Init::from_parts(self.iter.ptr, self.iter.len()) })
+    }
+}
+
+impl<T> UninitSliceIter<'_, T> {
+    const IS_ZST: bool = core::mem::size_of::<T>() == 0;
+
+    pub(crate) const fn new(ptr: NonNull<[T]>) -> Self {
+        UninitSliceIter {
+            ptr: ptr.cast(),
+            end_or_len: if Self::IS_ZST {
+                polyfill::without_provenance_mut(ptr.len())
+            } else {
+                // SAFETY: ptr.len() is correct, so adding it to ptr.as_ptr()
+                // will not go past the bounds of the slice
+                unsafe { ptr.as_ptr().cast::<T>().add(ptr.len()) }
+            },
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'brand, T> UninitSliceIter<'brand, T> {
+    /// Convert the iterator back into the slice of elements which weren't yielded yet
+    ///
+    /// # Safety
+    ///
+    /// The remainder keeps the brand of the original slice, but doesn't cover its whole allocation,
+    /// so it may not be used in place of the original slice, for example by returning
+    /// the initialized remainder from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub unsafe fn into_remainder(self) -> Uninit<'brand, [T]> {
+        let ptr = core::ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len());
+        // SAFETY: the remaining elements are owned by the iterator, and the caller ensures
+        // that the remainder isn't used in place of the original slice
+        unsafe { Uninit::from_raw(ptr) }
+    }
+
+    /// Get a raw pointer to the elements which weren't yielded yet
+    ///
+    /// # Safety
+    ///
+    /// You may not write through this pointer
+    pub fn as_raw_slice(&self) -> *const [T] {
+        core::ptr::slice_from_raw_parts(self.ptr.as_ptr(), self.len())
+    }
+
+    pub(crate) const unsafe fn unlink<'a>(self) -> UninitSliceIter<'a, T> {
+        UninitSliceIter {
+            ptr: self.ptr,
+            end_or_len: self.end_or_len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        if Self::IS_ZST {
+            polyfill::addr(self.end_or_len)
+        } else {
+            // Safety: self.end_or_len come from the same slice as self.ptr
+            unsafe { self.end_or_len.offset_from(self.ptr.as_ptr()) as usize }
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        if Self::IS_ZST {
+            self.end_or_len.is_null()
+        } else {
+            self.ptr.as_ptr() == self.end_or_len
+        }
+    }
+
+    pub(crate) const unsafe fn next_unchecked(&mut self) -> Uninit<'brand, T> {
+        if Self::IS_ZST {
+            self.end_or_len = self.end_or_len.wrapping_byte_sub(1);
+            // SAFETY: the pointer is aligned and non-null, and is valid for ZSTs
+            unsafe { Uninit::from_raw(self.ptr.as_ptr()) }
+        } else {
+            let ptr = self.ptr;
+            // SAFETY: there is at least one more element in the slice, so
+            // add will stay in bounds
+            self.ptr = unsafe { self.ptr.add(1) };
+            // SAFETY: the element is in bounds of the slice, and is owned by the iterator
+            unsafe { Uninit::from_raw(ptr.as_ptr()) }
+        }
+    }
+
+    pub(crate) const unsafe fn next_back_unchecked(&mut self) -> Uninit<'brand, T> {
+        if Self::IS_ZST {
+            self.end_or_len = self.end_or_len.wrapping_byte_sub(1);
+            // SAFETY: the pointer is aligned and non-null, and is valid for ZSTs
+            unsafe { Uninit::from_raw(self.ptr.as_ptr()) }
+        } else {
+            // SAFETY: there is at least one more element in the slice, so
+            // sub will stay in bounds, and since it is in bounds end_or_len
+            // must be non-null
+            unsafe {
+                let end_or_len = self.end_or_len.sub(1);
+                self.end_or_len = end_or_len;
+                Uninit::from_raw(end_or_len)
+            }
+        }
+    }
+
+    const fn reset(&mut self) {
+        if Self::IS_ZST {
+            self.end_or_len = core::ptr::null_mut();
+        } else {
+            self.end_or_len = self.ptr.as_ptr();
+        }
+    }
+
+    pub(crate) const fn reset_if(&mut self, b: bool) {
+        if b {
+            self.reset();
+        }
+    }
+
+    const fn fwd_unchecked(&mut self, n: usize) {
+        if Self::IS_ZST {
+            self.end_or_len = self.end_or_len.wrapping_byte_sub(n);
+        } else {
+            // SAFETY: n < the number of remaining elements in the slice
+            self.ptr = unsafe { self.ptr.add(n) };
+        }
+    }
+
+    const fn bck_unchecked(&mut self, n: usize) {
+        if Self::IS_ZST {
+            self.end_or_len = self.end_or_len.wrapping_byte_sub(n);
+        } else {
+            // SAFETY: n < the number of remaining elements in the slice
+            self.end_or_len = unsafe { self.end_or_len.sub(n) };
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for UninitSliceIter<'_, T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+impl<'brand, T> Iterator for UninitSliceIter<'brand, T> {
+    type Item = Uninit<'brand, T>;
+
+    #[allow(unstable_name_collisions)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: !is_empty
+            Some(unsafe { self.next_unchecked() })
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.reset();
+            None
+        } else {
+            self.fwd_unchecked(n);
+            // SAFETY: self.len() - n > 0
+            Some(unsafe { self.next_unchecked() })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for _ in 0..self.len() {
+            // SAFETY: this is repeated once for each remaining element
+            acc = f(acc, unsafe { self.next_unchecked() });
+        }
+        acc
+    }
+}
+
+impl<T> DoubleEndedIterator for UninitSliceIter<'_, T> {
+    #[allow(unstable_name_collisions)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: !is_empty
+            Some(unsafe { self.next_back_unchecked() })
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.reset();
+            None
+        } else {
+            self.bck_unchecked(n);
+            // SAFETY: self.len() - n > 0
+            Some(unsafe { self.next_back_unchecked() })
+        }
+    }
+
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for _ in 0..self.len() {
+            // SAFETY: this is repeated once for each remaining element
+            acc = f(acc, unsafe { self.next_back_unchecked() });
+        }
+        acc
+    }
+}
+
+impl<T> FusedIterator for UninitSliceIter<'_, T> {}
+
+impl<'brand, T> InitSliceIter<'brand, T> {
+    /// Create an iterator over an initialized slice
+    ///
+    /// # Safety
+    ///
+    /// all elements of the slice must be initialized and owned by the brand
+    pub(crate) const unsafe fn new(ptr: NonNull<[T]>) -> Self {
+        Self {
+            iter: UninitSliceIter::new(ptr),
+        }
+    }
+
+    /// Convert the iterator back into the slice of elements which weren't yielded yet
+    ///
+    /// # Safety
+    ///
+    /// The remainder keeps the brand of the original slice, but doesn't cover its whole allocation,
+    /// so it may not be used in place of the original slice, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub unsafe fn into_remainder(self) -> Init<'brand, [T]> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: the remaining elements of the iterator are initialized and owned by the iterator,
+        // and the iterator won't drop them. The caller ensures that the remainder
+        // isn't used in place of the original slice
+        unsafe { Init::from_parts(this.iter.ptr, this.iter.len()) }
+    }
+
+    /// Skip the next `n` elements, by dropping them
+    fn drop_front(&mut self, n: usize) {
+        let n = n.min(self.iter.len());
+        let ptr = self.iter.ptr;
+        self.iter.fwd_unchecked(n);
+        // SAFETY: the skipped elements are initialized and no longer owned by the iterator
+        drop(unsafe { Init::from_parts(ptr, n) })
+    }
+
+    /// Skip the last `n` elements, by dropping them
+    fn drop_back(&mut self, n: usize) {
+        let n = n.min(self.iter.len());
+        self.iter.bck_unchecked(n);
+        // SAFETY: the skipped elements are initialized and no longer owned by the iterator
+        drop(unsafe { Init::from_parts(self.iter.back_ptr(), n) })
+    }
+}
+
+impl<T> UninitSliceIter<'_, T> {
+    /// A pointer to the past-the-end element
+    const fn back_ptr(&self) -> NonNull<T> {
+        if Self::IS_ZST {
+            self.ptr
+        } else {
+            // SAFETY: end_or_len is non-null for non-ZSTs
+            unsafe { NonNull::new_unchecked(self.end_or_len) }
+        }
+    }
+}
+
+const fn iter_assume_init<T>(value: Uninit<T>) -> Init<T> {
+    // SAFETY: This is only called in [`InitSliceIter`]
+    // and the iterator is only created from a `Init<[T]>`
+    unsafe { value.assume_init() }
+}
+
+impl<T> ExactSizeIterator for InitSliceIter<'_, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+impl<'brand, T> Iterator for InitSliceIter<'brand, T> {
+    type Item = Init<'brand, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(iter_assume_init)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.drop_front(n);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for _ in 0..self.len() {
+            // SAFETY: this is repeated once for each remaining element
+            acc = f(acc, iter_assume_init(unsafe { self.iter.next_unchecked() }));
+        }
+        acc
+    }
+}
+
+impl<T> DoubleEndedIterator for InitSliceIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(iter_assume_init)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.drop_back(n);
+        self.next_back()
+    }
+
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        for _ in 0..self.len() {
+            // SAFETY: this is repeated once for each remaining element
+            acc = f(
+                acc,
+                iter_assume_init(unsafe { self.iter.next_back_unchecked() }),
+            );
+        }
+        acc
+    }
+}
+
+impl<T> FusedIterator for InitSliceIter<'_, T> {}
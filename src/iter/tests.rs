@@ -0,0 +1,82 @@
+use core::{cell::Cell, mem::MaybeUninit};
+
+use crate::{Init, Uninit};
+
+struct DropCounter<'a>(&'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn assume_init<T>(buffer: &mut [MaybeUninit<T>]) -> Init<'_, [T]> {
+    let ptr = core::ptr::slice_from_raw_parts_mut(buffer.as_mut_ptr().cast::<T>(), buffer.len());
+    // SAFETY: the callers only pass initialized buffers, and a MaybeUninit is always valid to pass to Uninit::from_raw
+    unsafe { Uninit::from_raw(ptr).assume_init() }
+}
+
+#[test]
+fn init_nth_drops_skipped() {
+    let drops = Cell::new(0);
+    let mut buffer: [_; 6] = core::array::from_fn(|_| MaybeUninit::new(DropCounter(&drops)));
+    let mut iter = assume_init(&mut buffer).into_iter();
+
+    drop(iter.nth(1));
+    assert_eq!(drops.get(), 2);
+    drop(iter.nth_back(2));
+    assert_eq!(drops.get(), 5);
+    assert_eq!(iter.len(), 1);
+    assert!(iter.nth(1).is_none());
+    assert_eq!(drops.get(), 6);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn fold() {
+    let mut buffer = [1u32, 2, 3, 4].map(MaybeUninit::new);
+    let sum = assume_init(&mut buffer)
+        .into_iter()
+        .fold(0, |acc, x| acc * 10 + *x.as_ref());
+    assert_eq!(sum, 1234);
+
+    let mut buffer = [1u32, 2, 3, 4].map(MaybeUninit::new);
+    let sum = assume_init(&mut buffer)
+        .into_iter()
+        .rfold(0, |acc, x| acc * 10 + *x.as_ref());
+    assert_eq!(sum, 4321);
+
+    let mut buffer = [0u32; 4];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    uninit
+        .into_iter()
+        .enumerate()
+        .for_each(|(i, u)| u.init(i as u32).take_ownership());
+    assert_eq!(buffer, [0, 1, 2, 3]);
+}
+
+#[test]
+fn zero_sized() {
+    let mut units = [(); 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let mut iter = unsafe { Uninit::from_raw(&mut units[..]) }.into_iter();
+    assert_eq!(iter.len(), 5);
+    assert!(iter.nth(3).is_some());
+    assert_eq!(iter.count(), 1);
+
+    let mut units = [MaybeUninit::new(()); 3];
+    let mut iter = assume_init(&mut units).into_iter();
+    assert!(iter.nth_back(1).is_some());
+    assert_eq!(iter.count(), 1);
+}
+
+#[test]
+fn last_drops_the_rest() {
+    let drops = Cell::new(0);
+    let mut buffer: [_; 4] = core::array::from_fn(|_| MaybeUninit::new(DropCounter(&drops)));
+    let last = assume_init(&mut buffer).into_iter().last();
+    assert_eq!(drops.get(), 3);
+    drop(last);
+    assert_eq!(drops.get(), 4);
+}
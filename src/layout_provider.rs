@@ -1,6 +1,6 @@
 //! This module provides a way to go from initializer arguments to layouts
 
-use core::{alloc::Layout, ptr::NonNull};
+use core::{alloc::Layout, marker::PhantomData, ptr::NonNull};
 
 /// Specifies the default layout provider to use for a given initializer
 pub trait DefaultLayoutProviderFor<T: ?Sized>: Sized {
@@ -28,12 +28,23 @@ impl<T: ?Sized, I: DefaultLayoutProviderFor<T>> DefaultLayoutProvider<I> for T {
 /// * layout must give a layout that will fit T
 /// * cast must return a pointer that is valid for the associated layout
 /// * is_zeroed may only return true if the only thing args does is zero out the memory
+/// * byte_pattern may only return `Some(byte)` if the only thing args does is set every byte
+///   of the layout to `byte`
+/// * try_layout must return the same layout as layout, or an error if layout returns None
 ///
 /// If Args can be cloned, then all clones must produce the same values when applied to any of these functions
 pub unsafe trait LayoutProvider<T: ?Sized, Args> {
     /// The layout to allocate a pointer to T with when given the following args
     fn layout(args: &Args) -> Option<Layout>;
 
+    /// The layout to allocate a pointer to T with when given the following args,
+    /// or the reason that there is no such layout
+    ///
+    /// By default, a missing layout is reported as [`LayoutProviderError::Overflow`]
+    fn try_layout(args: &Args) -> Result<Layout, LayoutProviderError> {
+        Self::layout(args).ok_or(LayoutProviderError::Overflow)
+    }
+
     /// Cast an untyped pointer to T (a potentially wide pointer)
     ///
     /// implementors of cast may not read from the pointer
@@ -41,6 +52,23 @@ pub unsafe trait LayoutProvider<T: ?Sized, Args> {
 
     /// Check if args only zeros out the memory
     fn is_zeroed(_args: &Args) -> bool;
+
+    /// If args only sets every byte of the memory to the same value, returns that byte.
+    /// This allows the value to be initialized with a single `write_bytes`
+    fn byte_pattern(_args: &Args) -> Option<u8> {
+        None
+    }
+}
+
+/// The reasons a [`LayoutProvider`] can fail to compute a layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutProviderError {
+    /// The size of the layout overflowed
+    Overflow,
+    /// The arguments specified an invalid length
+    InvalidLength,
+    /// The arguments aren't supported by the layout provider
+    Unsupported,
 }
 
 /// A [`LayoutProvider`] for [`Sized`] types
@@ -60,3 +88,231 @@ unsafe impl<T, Args> LayoutProvider<T, Args> for SizedLayoutProvider {
         false
     }
 }
+
+/// An initializer for `T` with a layout which was precomputed by the layout provider `L`,
+/// see [`CachedLayoutProvider`]
+pub struct WithLayout<T: ?Sized, I, L> {
+    init: I,
+    layout: Layout,
+    _provider: PhantomData<fn() -> (*const T, L)>,
+}
+
+impl<T: ?Sized, I: Clone, L> Clone for WithLayout<T, I, L> {
+    fn clone(&self) -> Self {
+        Self {
+            init: self.init.clone(),
+            layout: self.layout,
+            _provider: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized, I: Copy, L> Copy for WithLayout<T, I, L> {}
+
+impl<T: ?Sized, I: core::fmt::Debug, L> core::fmt::Debug for WithLayout<T, I, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WithLayout")
+            .field("init", &self.init)
+            .field("layout", &self.layout)
+            .finish()
+    }
+}
+
+impl<T: ?Sized, I, L: LayoutProvider<T, I>> WithLayout<T, I, L> {
+    /// Pair `init` with the layout that `L` computes for it
+    ///
+    /// This is useful when emplacing many clones of the same initializer, since
+    /// the layout only needs to be computed once
+    pub fn new(init: I) -> Result<Self, LayoutProviderError> {
+        let layout = L::try_layout(&init)?;
+        // SAFETY: the layout was just computed by L
+        Ok(unsafe { Self::new_unchecked(init, layout) })
+    }
+}
+
+impl<T: ?Sized, I, L> WithLayout<T, I, L> {
+    /// Pair `init` with a layout which was computed ahead of time
+    ///
+    /// This is useful when emplacing many values with the same shape (like slices of the
+    /// same length), since the layout only needs to be computed once
+    ///
+    /// # Safety
+    ///
+    /// `layout` must be the layout that `L` returns for `init`
+    pub const unsafe fn new_unchecked(init: I, layout: Layout) -> Self {
+        Self {
+            init,
+            layout,
+            _provider: PhantomData,
+        }
+    }
+
+    /// The precomputed layout
+    pub const fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The initializer
+    pub fn into_inner(self) -> I {
+        self.init
+    }
+}
+
+impl<T: ?Sized + crate::Ctor<I>, I, L> crate::Initializer<T> for WithLayout<T, I, L> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: crate::Uninit<T>) -> Result<crate::Init<T>, Self::Error> {
+        ptr.try_init(self.init)
+    }
+}
+
+/// A [`LayoutProvider`] for [`WithLayout`], which returns the precomputed layout
+/// instead of asking `L`, and forwards everything else to `L`
+pub struct CachedLayoutProvider<L>(L);
+
+impl<T: ?Sized, I, L: LayoutProvider<T, I>> DefaultLayoutProviderFor<T> for WithLayout<T, I, L> {
+    type LayoutProvider = CachedLayoutProvider<L>;
+}
+
+// # Safety: WithLayout ensures that the layout is the one L would return for T,
+// and the rest is forwarded to L
+unsafe impl<T: ?Sized, I, L: LayoutProvider<T, I>> LayoutProvider<T, WithLayout<T, I, L>>
+    for CachedLayoutProvider<L>
+{
+    fn layout(args: &WithLayout<T, I, L>) -> Option<Layout> {
+        Some(args.layout)
+    }
+
+    fn cast(ptr: NonNull<()>, args: &WithLayout<T, I, L>) -> NonNull<T> {
+        L::cast(ptr, &args.init)
+    }
+
+    fn is_zeroed(args: &WithLayout<T, I, L>) -> bool {
+        L::is_zeroed(&args.init)
+    }
+}
+
+/// A builder for the layout of a `#[repr(C)]` struct, which records the offset of each field
+///
+/// Each method appends a field after all previous fields, and returns its offset from the
+/// start of the struct, which can then be used to cast or project pointers to the struct.
+///
+/// ```
+/// # use init::layout_provider::{LayoutBuilder, LayoutProviderError};
+/// # fn main() -> Result<(), LayoutProviderError> {
+/// let mut builder = LayoutBuilder::new();
+/// assert_eq!(builder.field::<u8>()?, 0);
+/// assert_eq!(builder.field::<u32>()?, 4);
+/// assert_eq!(builder.slice::<u16>(3)?, 8);
+///
+/// let layout = builder.finish_padded();
+/// assert_eq!(layout.size(), 16);
+/// assert_eq!(layout.align(), 4);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutBuilder {
+    layout: Layout,
+}
+
+impl Default for LayoutBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LayoutBuilder {
+    /// Create a builder for a struct with no fields
+    pub const fn new() -> Self {
+        Self {
+            layout: Layout::new::<()>(),
+        }
+    }
+
+    /// Append a field with the given layout, and return its offset
+    pub fn layout(&mut self, field: Layout) -> Result<usize, LayoutProviderError> {
+        let (layout, offset) = self
+            .layout
+            .extend(field)
+            .map_err(|_| LayoutProviderError::Overflow)?;
+        self.layout = layout;
+        Ok(offset)
+    }
+
+    /// Append a `T` field, and return its offset
+    pub fn field<T>(&mut self) -> Result<usize, LayoutProviderError> {
+        self.layout(Layout::new::<T>())
+    }
+
+    /// Append a `[T]` field with `len` elements, and return its offset
+    pub fn slice<T>(&mut self, len: usize) -> Result<usize, LayoutProviderError> {
+        let field = Layout::array::<T>(len).map_err(|_| LayoutProviderError::Overflow)?;
+        self.layout(field)
+    }
+
+    /// Raise the alignment of the struct to at least `align`, like `#[repr(align(N))]`
+    ///
+    /// If `align` isn't a power of two, then [`LayoutProviderError::Unsupported`] is returned
+    pub fn align_to(&mut self, align: usize) -> Result<(), LayoutProviderError> {
+        self.layout = self.layout.align_to(align).map_err(|_| {
+            if align.is_power_of_two() {
+                LayoutProviderError::Overflow
+            } else {
+                LayoutProviderError::Unsupported
+            }
+        })?;
+        Ok(())
+    }
+
+    /// The layout of the fields so far, without any trailing padding
+    pub const fn current(&self) -> Layout {
+        self.layout
+    }
+
+    /// The layout of the struct, padded to a multiple of its alignment
+    pub const fn finish_padded(self) -> Layout {
+        self.layout.pad_to_align()
+    }
+}
+
+/// An initializer which knows the pointer metadata of the value it initializes
+///
+/// This is used by [`MetadataLayoutProvider`] to emplace custom dynamically sized types
+///
+/// # Safety
+///
+/// The metadata must be valid for `T`, and the size of a `T` with this metadata
+/// must not overflow `isize`. All calls to `metadata` must return the same value
+#[cfg(feature = "ptr_metadata")]
+pub unsafe trait PointeeMetadata<T: ?Sized> {
+    /// The pointer metadata of the value which will be initialized
+    fn metadata(&self) -> <T as core::ptr::Pointee>::Metadata;
+}
+
+/// A [`LayoutProvider`] for any type, using the pointer metadata from a [`PointeeMetadata`] initializer
+#[cfg(feature = "ptr_metadata")]
+pub struct MetadataLayoutProvider;
+
+#[cfg(feature = "ptr_metadata")]
+// SAFETY: the layout is the layout of a `T` with the metadata from the initializer,
+// and cast returns a pointer with that metadata. is_zeroed always returns false
+unsafe impl<T: ?Sized, Args: PointeeMetadata<T>> LayoutProvider<T, Args>
+    for MetadataLayoutProvider
+{
+    fn layout(args: &Args) -> Option<Layout> {
+        let ptr =
+            core::ptr::from_raw_parts::<T>(NonNull::<()>::dangling().as_ptr(), args.metadata());
+        // SAFETY: PointeeMetadata ensures that the metadata is valid for T,
+        // and that the size of T doesn't overflow isize
+        Some(unsafe { Layout::for_value_raw(ptr) })
+    }
+
+    fn cast(ptr: NonNull<()>, args: &Args) -> NonNull<T> {
+        NonNull::from_raw_parts(ptr, args.metadata())
+    }
+
+    fn is_zeroed(_args: &Args) -> bool {
+        false
+    }
+}
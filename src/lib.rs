@@ -1,4 +1,10 @@
 #![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(
+    feature = "ptr_metadata",
+    feature(ptr_metadata, layout_for_ptr, sized_hierarchy)
+)]
+#![cfg_attr(all(test, feature = "ptr_metadata"), feature(extern_types))]
 #![forbid(
     unsafe_op_in_unsafe_fn,
     missing_docs,
@@ -23,23 +29,66 @@ extern crate std;
 #[path = "macros.rs"]
 pub mod __private_macros;
 
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+mod from_bytes;
 mod polyfill;
 mod ptr;
 
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub mod arc;
 pub mod array;
 #[cfg(feature = "alloc")]
 pub mod boxed;
+pub mod builder;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+pub mod cell;
+pub mod combinator;
+pub mod emplace;
 pub mod from_fn;
+pub mod iter;
 pub mod layout_provider;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod option;
+pub mod pin;
+pub mod project;
+#[cfg(feature = "alloc")]
+pub mod rc;
+pub mod result;
+#[cfg(feature = "alloc")]
+mod shared;
 pub mod slice;
+pub mod str;
+#[cfg(feature = "std")]
+pub mod sync;
+pub mod tail;
+#[cfg(feature = "alloc")]
+pub mod thin;
+pub mod tuple;
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
+pub mod zeroed;
 
 mod primitive;
 
 pub mod slice_writer;
 
+pub use combinator::InitializerCombinators;
+#[cfg(feature = "alloc")]
+pub use emplace::EmplaceExt;
+pub use emplace::{Emplace, EmplaceError};
 pub use from_fn::{from_fn, try_from_fn};
-pub use primitive::PrimitiveLayoutProvider;
+pub use primitive::{PrimitiveLayoutProvider, WrapperLayoutProvider, ZeroError};
+pub use project::Project;
+pub use zeroed::{zeroed, Zeroable};
+
+#[cfg(all(feature = "derive", feature = "alloc"))]
+pub use init_derive::Erasable;
+#[cfg(feature = "derive")]
+pub use init_derive::{Builder, DefaultLayoutProvider, Project, Zeroable};
 
+pub use pin::{PinnedInit, PinnedUninit};
 pub use ptr::{Init, Uninit};
 
 /// A constructor trait, specifies how to initialize a `T` in place
@@ -71,3 +120,34 @@ impl<T: ?Sized, Args: Initializer<T>> Ctor<Args> for T {
         args.try_init_into(ptr)
     }
 }
+
+/// A constructor trait, specifies how to initialize a `T` in place, where `T` will be pinned
+///
+/// To be implemented on the host type
+pub trait PinCtor<Args = ()> {
+    /// The error type in case initialization fails
+    type Error;
+
+    /// initialize self in place
+    fn try_pin_init(ptr: PinnedUninit<Self>, args: Args) -> Result<PinnedInit<Self>, Self::Error>;
+}
+
+/// An initializer trait, specifies how to initialize a `T` in place, where `T` will be pinned
+///
+/// To be implemented on the argument type to initialize with. This allows 3rd party initializers.
+/// Any [`Initializer`] can be used as a [`PinInitializer`] with [`pin::from_init`]
+pub trait PinInitializer<T: ?Sized> {
+    /// The error type in case initialization fails
+    type Error;
+
+    /// initialize ptr in place
+    fn try_pin_init_into(self, ptr: PinnedUninit<T>) -> Result<PinnedInit<T>, Self::Error>;
+}
+
+impl<T: ?Sized, Args: PinInitializer<T>> PinCtor<Args> for T {
+    type Error = Args::Error;
+
+    fn try_pin_init(ptr: PinnedUninit<Self>, args: Args) -> Result<PinnedInit<Self>, Self::Error> {
+        args.try_pin_init_into(ptr)
+    }
+}
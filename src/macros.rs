@@ -1,5 +1,11 @@
 pub use core;
-use core::marker::PhantomData;
+use core::{
+    alloc::{Layout, LayoutError},
+    marker::PhantomData,
+    ptr::NonNull,
+};
+
+use crate::layout_provider::{LayoutBuilder, LayoutProvider, LayoutProviderError};
 
 #[macro_export]
 macro_rules! init_struct {
@@ -30,6 +36,311 @@ macro_rules! init_struct {
     };
 }
 
+/// Create a pinned initializer for a struct, which initializes each field in place
+///
+/// Fields marked with `#[pin]` are initialized with pinned initializers
+/// (see [`PinInitializer`](crate::PinInitializer)), and all other fields are initialized
+/// with normal initializers (see [`Initializer`](crate::Initializer)).
+///
+/// # Safety
+///
+/// This macro must be invoked inside of an `unsafe` block, since fields marked with `#[pin]`
+/// are treated as structurally pinned. So the struct must uphold the same rules as
+/// structural pinning with [`Pin`](core::pin::Pin):
+///
+/// * the struct must not implement [`Unpin`] unless those fields do
+/// * the struct must not move out of those fields, for example in its [`Drop`] impl
+///   (use [`pinned_drop!`](crate::pinned_drop) instead)
+/// * the struct must not be `#[repr(packed)]`
+#[macro_export]
+macro_rules! pin_init_struct {
+    (@init #[pin] $uninit:ident $field_value:expr) => {
+        $uninit.try_pin_init($field_value)
+    };
+    (@init $uninit:ident $field_value:expr) => {
+        $uninit.try_init($field_value)
+    };
+    ($struct_name:path { $($(#[$pin:ident])? $field:ident:$field_value:expr),+ $(,)? }) => {
+        $crate::pin::try_from_fn(|mut uninit| {
+            let $struct_name { $($field: _,)* };
+            $crate::__private_macros::assume_structurally_pinned::<$struct_name>();
+
+            let ptr: *mut $struct_name = uninit.as_mut_ptr();
+            $(
+                // the macro is invoked inside of an unsafe block
+                #[allow(unused_unsafe)]
+                // SAFETY: re-borrowing a field as an uninit is sound, and the field
+                // will be pinned because the struct will be pinned
+                let field = unsafe { $crate::Uninit::from_raw(&raw mut (*ptr).$field).into_pinned() };
+                let mut $field = match $crate::pin_init_struct!(@init $(#[$pin])? field $field_value) {
+                    Ok(field) => field,
+                    Err(x) => {
+                        use $crate::__private_macros::GetConverter;
+                        let w = $crate::__private_macros::Wrapper(&x);
+                        let converter = (&&&&w).__private_init_get_converter();
+                        return Err(converter.convert(x))
+                    },
+                };
+
+                let _ = $field.as_mut_ptr(); // to silence unused mut warnings
+            )*
+            $(
+                #[allow(unused_unsafe)]
+                // SAFETY: the struct takes ownership of the field, and the struct is pinned
+                unsafe { $field.take_ownership() };
+            )*
+            #[allow(unused_unsafe)]
+            // SAFETY: all fields were initialized
+            let init = unsafe { uninit.assume_init() };
+            Ok(init)
+        })
+    };
+}
+
+/// Implement [`Drop`] for a type which implements [`PinnedDrop`](crate::pin::PinnedDrop)
+///
+/// `pinned_drop!(Type)` or with generics `pinned_drop!(for[T: Bound] Type<T> where [T: Bound])`,
+/// where the bounds must match the type's definition (like any [`Drop`] impl).
+///
+/// ```
+/// use core::{marker::PhantomPinned, pin::Pin};
+/// use init::pin::{OnlyCallFromDrop, PinnedDrop};
+///
+/// struct Node {
+///     next: *const Node,
+///     _pinned: PhantomPinned,
+/// }
+///
+/// impl PinnedDrop for Node {
+///     fn drop(self: Pin<&mut Self>, _: OnlyCallFromDrop) {
+///         // unlink self from the list
+///     }
+/// }
+///
+/// init::pinned_drop!(Node);
+/// ```
+#[macro_export]
+macro_rules! pinned_drop {
+    (for[$($binder:tt)*] $type:ty $(where [$($bounds:tt)*])?) => {
+        impl<$($binder)*> $crate::__private_macros::core::ops::Drop for $type
+        $(where $($bounds)*)?
+        {
+            fn drop(&mut self) {
+                // SAFETY: the value is never used again after it is dropped,
+                // so it is never moved after this point
+                let this = unsafe { $crate::__private_macros::core::pin::Pin::new_unchecked(self) };
+                // SAFETY: this is called from Drop::drop
+                let token = unsafe { $crate::pin::OnlyCallFromDrop::__new() };
+                <$type as $crate::pin::PinnedDrop>::drop(this, token)
+            }
+        }
+    };
+    ($type:ty $(where [$($bounds:tt)*])?) => {
+        $crate::pinned_drop!(for[] $type $(where [$($bounds)*])?);
+    };
+}
+
+/// Pin-initialize a value on the stack, and bind a [`Pin<&mut T>`](core::pin::Pin) to it
+///
+/// `stack_pin_init!(let name: T = init)` initializes a `T` in a stack slot with the
+/// pinned initializer `init`, and binds `name` to a pinned reference to it. The value
+/// is dropped at the end of the enclosing scope. The type annotation is optional.
+///
+/// see [`stack_try_pin_init!`](crate::stack_try_pin_init) for fallible initializers
+#[macro_export]
+macro_rules! stack_pin_init {
+    (let $name:ident $(: $type:ty)? = $init:expr) => {
+        let mut slot = $crate::__private_macros::core::mem::MaybeUninit$(::<$type>)?::uninit();
+        // SAFETY: slot can't be named outside of this macro, so it is never moved or
+        // reused until after the value is dropped, which happens when `guard` is dropped
+        let mut guard = unsafe { $crate::__private_macros::stack_slot(&mut slot) }.pin_init($init);
+        let $name = guard.as_mut();
+    };
+}
+
+/// Try to pin-initialize a value on the stack, and bind a
+/// `Result<Pin<&mut T>, E>` to it
+///
+/// `stack_try_pin_init!(let name: T = init)` tries to initialize a `T` in a stack slot with the
+/// pinned initializer `init`, and binds `name` to either a pinned reference to it, or the error.
+/// The value is dropped at the end of the enclosing scope. The type annotation is optional.
+#[macro_export]
+macro_rules! stack_try_pin_init {
+    (let $name:ident $(: $type:ty)? = $init:expr) => {
+        let mut slot = $crate::__private_macros::core::mem::MaybeUninit$(::<$type>)?::uninit();
+        // SAFETY: slot can't be named outside of this macro, so it is never moved or
+        // reused until after the value is dropped, which happens when `guard` is dropped
+        let uninit = unsafe { $crate::__private_macros::stack_slot(&mut slot) };
+        let mut guard = $crate::__private_macros::core::option::Option::None;
+        let $name = $crate::__private_macros::pin_result(&mut guard, uninit.try_pin_init($init));
+    };
+}
+
+/// Initialize a value directly on the heap, and unsize the box
+///
+/// `boxed_unsize!(T, init)` initializes a `T` with `init`, and coerces the `Box<T>` to the
+/// box type expected by the context, like a `Box<dyn Trait>`.
+/// `boxed_unsize!(try T, init)` does the same for fallible initializers, and returns a `Result`.
+///
+/// ```
+/// # use init::boxed_unsize;
+/// let bx: Box<dyn core::fmt::Debug> = boxed_unsize!(u32, 10);
+/// assert_eq!(format!("{bx:?}"), "10");
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! boxed_unsize {
+    (try $type:ty, $init:expr) => {
+        $crate::boxed::try_boxed_unsize::<$type, _, _>($init, |bx| bx as _)
+    };
+    ($type:ty, $init:expr) => {
+        $crate::boxed::boxed_unsize::<$type, _, _>($init, |bx| bx as _)
+    };
+}
+
+/// Format text directly into a [`Box<str>`](alloc::boxed::Box), which is allocated with exactly the right size
+///
+/// This takes the same arguments as [`format!`](alloc::format), see [`str::format`](crate::str::format) for details.
+///
+/// ```
+/// # use init::format_boxed;
+/// let bx: Box<str> = format_boxed!("{} + {} = {}", 1, 2, 1 + 2);
+/// assert_eq!(&*bx, "1 + 2 = 3");
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! format_boxed {
+    ($($args:tt)*) => {
+        $crate::str::format_boxed($crate::__private_macros::core::format_args!($($args)*))
+    };
+}
+
+/// Initialize a value directly on the heap behind a [`ThinDyn`](crate::thin::ThinDyn)
+///
+/// `thin_dyn!(T, init)` initializes a `T` with `init`, and unsizes it to the trait object
+/// expected by the context, like a `ThinDyn<dyn Trait>`.
+/// `thin_dyn!(try T, init)` does the same for fallible initializers, and returns a `Result`.
+///
+/// ```
+/// # use init::{thin::ThinDyn, thin_dyn};
+/// let thin: ThinDyn<dyn core::fmt::Debug> = thin_dyn!(u32, 10);
+/// assert_eq!(format!("{:?}", &*thin), "10");
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! thin_dyn {
+    (try $type:ty, $init:expr) => {
+        $crate::thin::ThinDyn::try_new::<$type, _>($init, |value| value as _)
+    };
+    ($type:ty, $init:expr) => {
+        $crate::thin::ThinDyn::new::<$type, _>($init, |value| value as _)
+    };
+}
+
+/// Project an [`Uninit`](crate::Uninit) pointer to a struct into an [`Uninit`](crate::Uninit)
+/// pointer to one (or more) of its fields
+///
+/// `project_uninit!(Type, uninit, field)` returns the field's pointer, and
+/// `project_uninit!(Type, uninit, a, b, ...)` returns a tuple of the fields' pointers.
+/// The parent pointer is mutably borrowed for as long as any of the fields' pointers are live,
+/// and each field may only be named once. Fields of `#[repr(packed)]` structs may be unaligned,
+/// so they are rejected at compile time.
+///
+/// ```
+/// # use init::{project_uninit, Uninit};
+/// struct Pair {
+///     a: u32,
+///     b: u64,
+/// }
+///
+/// let mut pair = core::mem::MaybeUninit::<Pair>::uninit();
+/// // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+/// let mut uninit = unsafe { Uninit::from_raw(pair.as_mut_ptr()) };
+///
+/// let (a, b) = project_uninit!(Pair, uninit, a, b);
+/// a.write(1).take_ownership();
+/// b.write(2).take_ownership();
+///
+/// // SAFETY: all fields were initialized
+/// let pair = unsafe { uninit.assume_init() };
+/// assert_eq!(pair.as_ref().a + pair.as_ref().b as u32, 3);
+/// ```
+#[macro_export]
+macro_rules! project_uninit {
+    ($type:path, $uninit:expr, $field:tt $(,)?) => {{
+        let $type { $field: _, .. };
+        // references to fields of packed structs are rejected, since they may be unaligned
+        let _ = |parent: &$type| {
+            let _ = &parent.$field;
+        };
+        let (brand, ptr) = $crate::__private_macros::brand::<$type>(&mut $uninit);
+        // SAFETY: the pattern above checks that the field exists on `$type`, and
+        // this is the only field projected from the brand
+        unsafe { brand.field(&raw mut (*ptr).$field) }
+    }};
+    ($type:path, $uninit:expr, $($field:tt),+ $(,)?) => {{
+        // the pattern can't name a field more than once, so the fields are disjoint
+        #[allow(clippy::unneeded_wildcard_pattern)]
+        let $type { $($field: _,)* .. };
+        // references to fields of packed structs are rejected, since they may be unaligned
+        let _ = |parent: &$type| {
+            $(let _ = &parent.$field;)*
+        };
+        let (brand, ptr) = $crate::__private_macros::brand::<$type>(&mut $uninit);
+        ($(
+            // SAFETY: the pattern above checks that the fields exist on `$type`, and
+            // that each field is only projected once
+            unsafe { brand.field(&raw mut (*ptr).$field) },
+        )*)
+    }};
+}
+
+/// Project a [`PinnedUninit`](crate::PinnedUninit) pointer to a struct into a
+/// [`PinnedUninit`](crate::PinnedUninit) pointer to one (or more) of its fields
+///
+/// `project_pin!(Type, uninit, field)` returns the field's pointer, and
+/// `project_pin!(Type, uninit, a, b, ...)` returns a tuple of the fields' pointers.
+/// The parent pointer is mutably borrowed for as long as any of the fields' pointers are live,
+/// and each field may only be named once.
+///
+/// # Safety
+///
+/// This macro must be invoked inside of an `unsafe` block, since the projected fields are treated
+/// as structurally pinned, with the same rules as [`pin_init_struct!`](crate::pin_init_struct)
+#[macro_export]
+macro_rules! project_pin {
+    ($type:path, $uninit:expr, $field:tt $(,)?) => {{
+        let $type { $field: _, .. };
+        // references to fields of packed structs are rejected, since they may be unaligned
+        let _ = |parent: &$type| {
+            let _ = &parent.$field;
+        };
+        $crate::__private_macros::assume_structurally_pinned::<$type>();
+        let (brand, ptr) = $crate::__private_macros::pin_brand::<$type>(&mut $uninit);
+        #[allow(unused_unsafe)]
+        // SAFETY: the pattern above checks that the field exists on `$type`, and
+        // this is the only field projected from the brand. The field is pinned because the parent is pinned
+        unsafe { brand.field(&raw mut (*ptr).$field).into_pinned() }
+    }};
+    ($type:path, $uninit:expr, $($field:tt),+ $(,)?) => {{
+        // the pattern can't name a field more than once, so the fields are disjoint
+        #[allow(clippy::unneeded_wildcard_pattern)]
+        let $type { $($field: _,)* .. };
+        // references to fields of packed structs are rejected, since they may be unaligned
+        let _ = |parent: &$type| {
+            $(let _ = &parent.$field;)*
+        };
+        $crate::__private_macros::assume_structurally_pinned::<$type>();
+        let (brand, ptr) = $crate::__private_macros::pin_brand::<$type>(&mut $uninit);
+        #[allow(unused_unsafe)]
+        ($(
+            // SAFETY: the pattern above checks that the fields exist on `$type`, and
+            // that each field is only projected once. The fields are pinned because the parent is pinned
+            unsafe { brand.field(&raw mut (*ptr).$field).into_pinned() },
+        )*)
+    }};
+}
+
 pub trait GetConverter<T, U> {
     type Converter;
 
@@ -82,3 +393,108 @@ impl<T, U: From<T>> TagFrom<T, U> {
         U::from(t)
     }
 }
+
+/// A brand tied to a mutable borrow of a parent [`Uninit`](crate::Uninit),
+/// used to hand out field pointers which can't outlive that borrow
+#[derive(Clone, Copy)]
+pub struct Brand<'a>(PhantomData<&'a mut ()>);
+
+/// Split a borrowed [`Uninit`](crate::Uninit) into a brand and its raw pointer
+pub const fn brand<'a, T: ?Sized>(uninit: &'a mut crate::Uninit<'_, T>) -> (Brand<'a>, *mut T) {
+    (Brand(PhantomData), uninit.as_mut_ptr())
+}
+
+/// Split a borrowed [`PinnedUninit`](crate::PinnedUninit) into a brand and its raw pointer
+pub const fn pin_brand<'a, T: ?Sized>(
+    uninit: &'a mut crate::PinnedUninit<'_, T>,
+) -> (Brand<'a>, *mut T) {
+    (Brand(PhantomData), uninit.as_mut_ptr())
+}
+
+impl<'a> Brand<'a> {
+    /// Create a pointer to a field of the parent pointer
+    ///
+    /// # Safety
+    ///
+    /// * ptr must point to a field of the parent pointer which created this brand
+    /// * no other pointer created from this brand may point to the same field
+    pub const unsafe fn field<T: ?Sized>(self, ptr: *mut T) -> crate::Uninit<'a, T> {
+        // SAFETY: the caller ensures that ptr is a unique field of the parent,
+        // and the parent is mutably borrowed for 'a
+        unsafe { crate::Uninit::from_raw(ptr) }
+    }
+}
+
+/// Create a pinned pointer to a stack slot
+///
+/// # Safety
+///
+/// The slot must not be moved or reused until after the value initialized in it is dropped
+pub const unsafe fn stack_slot<T>(
+    slot: &mut core::mem::MaybeUninit<T>,
+) -> crate::PinnedUninit<'_, T> {
+    // SAFETY: the slot is valid for reads and writes of T, and the caller
+    // ensures that the value will be pinned
+    unsafe { crate::Uninit::from_raw(slot.as_mut_ptr()).into_pinned() }
+}
+
+/// Called by the pinned projection macros, so that they can only be invoked inside of an `unsafe` block
+///
+/// # Safety
+///
+/// The projected fields of `T` must be structurally pinned
+pub const unsafe fn assume_structurally_pinned<T: ?Sized>() {}
+
+/// Store the initialized value in `guard`, and return a pinned reference to it
+pub fn pin_result<'a, 'b, T: ?Sized, E>(
+    guard: &'a mut Option<crate::PinnedInit<'b, T>>,
+    result: Result<crate::PinnedInit<'b, T>, E>,
+) -> Result<core::pin::Pin<&'a mut T>, E> {
+    match result {
+        Ok(init) => Ok(guard.insert(init).as_mut()),
+        Err(err) => Err(err),
+    }
+}
+
+/// The layout of a `#[repr(C)]` struct with the given sized fields, followed by the tail,
+/// aligned to at least `align`
+pub fn repr_c_layout(
+    fields: &[Layout],
+    tail: Result<Layout, LayoutError>,
+    align: usize,
+) -> Result<Layout, LayoutProviderError> {
+    let tail = tail.map_err(|_| LayoutProviderError::Overflow)?;
+    let mut builder = LayoutBuilder::new();
+    builder.align_to(align)?;
+    for &field in fields {
+        builder.layout(field)?;
+    }
+    builder.layout(tail)?;
+    Ok(builder.finish_padded())
+}
+
+/// The length of the tail which `L` would create from `args`
+pub fn tail_len<T: ?Sized + Tail, I, L: LayoutProvider<T, I>>(args: &I) -> usize {
+    T::len(L::cast(NonNull::dangling(), args))
+}
+
+/// A dynamically sized tail, whose pointer metadata is its length
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be the tail of a length-prefixed struct",
+    label = "the last field must be a slice or `str`"
+)]
+pub trait Tail {
+    fn len(ptr: NonNull<Self>) -> usize;
+}
+
+impl<T> Tail for [T] {
+    fn len(ptr: NonNull<Self>) -> usize {
+        ptr.len()
+    }
+}
+
+impl Tail for str {
+    fn len(ptr: NonNull<Self>) -> usize {
+        (ptr.as_ptr() as *mut [u8]).len()
+    }
+}
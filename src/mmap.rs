@@ -0,0 +1,119 @@
+//! initialize data directly inside anonymous memory maps
+//!
+//! This is useful for very large values, like multi-gigabyte slices,
+//! which would otherwise put a lot of pressure on the allocator
+
+use crate::{
+    layout_provider::{DefaultLayoutProvider, LayoutProvider, LayoutProviderError},
+    Ctor, EmplaceError, Uninit,
+};
+
+use core::{
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+use memmap2::MmapMut;
+
+#[cfg(test)]
+mod tests;
+
+/// An owning pointer to a value inside an anonymous memory map
+///
+/// The value is dropped, and the memory is unmapped when this is dropped
+pub struct MmapBox<T: ?Sized> {
+    ptr: NonNull<T>,
+    _map: MmapMut,
+}
+
+// SAFETY: MmapBox owns the value, like Box
+unsafe impl<T: ?Sized + Send> Send for MmapBox<T> {}
+// SAFETY: MmapBox only gives shared access to the value through a shared reference
+unsafe impl<T: ?Sized + Sync> Sync for MmapBox<T> {}
+
+impl<T: ?Sized> Drop for MmapBox<T> {
+    fn drop(&mut self) {
+        // SAFETY: the value is initialized, and the map is unmapped after this
+        unsafe { self.ptr.as_ptr().drop_in_place() }
+    }
+}
+
+impl<T: ?Sized> Deref for MmapBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the value is initialized, and owned by self
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MmapBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the value is initialized, and uniquely owned by self
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+/// initialize a value directly inside an anonymous memory map
+///
+/// Anonymous maps are zeroed, so initializers which only zero memory are skipped.
+/// If the map can't be created, this returns [`EmplaceError::Alloc`]
+pub fn try_mmap_with<T, I, L>(init: I) -> Result<MmapBox<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    let layout = L::try_layout(&init).map_err(EmplaceError::Layout)?;
+
+    // maps are page-aligned, but leave room for larger alignments
+    let Some(len) = layout.size().checked_add(layout.align() - 1) else {
+        return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+    };
+
+    let Ok(mut map) = MmapMut::map_anon(len.max(1)) else {
+        return Err(EmplaceError::Alloc(layout));
+    };
+
+    let offset = map.as_mut_ptr().align_offset(layout.align());
+    // SAFETY: offset < layout.align(), so it's in bounds of the map
+    let ptr = unsafe { NonNull::new_unchecked(map.as_mut_ptr().add(offset)) };
+    let ptr = L::cast(ptr.cast(), &init);
+
+    if !L::is_zeroed(&init) {
+        // SAFETY: the pointer is aligned, the value fits in the map, and the map is owned here
+        let uninit = unsafe { Uninit::from_raw(ptr.as_ptr()) };
+        uninit
+            .try_init(init)
+            .map_err(EmplaceError::Init)?
+            .take_ownership();
+    }
+
+    Ok(MmapBox { ptr, _map: map })
+}
+
+/// initialize a value directly inside an anonymous memory map
+pub fn mmap_with<T, I, L>(init: I) -> MmapBox<T>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible>,
+    L: LayoutProvider<T, I>,
+{
+    match try_mmap_with::<T, I, L>(init) {
+        Ok(bx) => bx,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a value directly inside an anonymous memory map
+pub fn try_mmap<T, I>(init: I) -> Result<MmapBox<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I> + DefaultLayoutProvider<I>,
+{
+    try_mmap_with::<T, I, T::LayoutProvider>(init)
+}
+
+/// initialize a value directly inside an anonymous memory map
+pub fn mmap<T, I>(init: I) -> MmapBox<T>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+{
+    mmap_with::<T, I, T::LayoutProvider>(init)
+}
@@ -0,0 +1,10 @@
+use crate::slice::{self, WithLength};
+
+#[test]
+fn mmap_slice() {
+    let mut map = super::mmap::<[u32], _>(WithLength::from_init(1 << 16, slice::repeat(3u32)));
+    assert!(map.iter().all(|&x| x == 3));
+
+    map[10] = 4;
+    assert_eq!(map.iter().sum::<u32>(), 3 * (1 << 16) + 1);
+}
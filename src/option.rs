@@ -0,0 +1,61 @@
+//! initializers for [`Option`]
+
+use crate::{
+    cell::try_init_on_stack,
+    layout_provider::{DefaultLayoutProviderFor, SizedLayoutProvider},
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Initialize an [`Option`] with `Some`
+///
+/// see [`some`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct InitSome<I>(I);
+
+/// Initialize an [`Option`] with `Some`, where the payload is initialized with `init`
+///
+/// This isn't an in-place initializer: the layout of an [`Option`] isn't specified (and may
+/// use a niche in the payload), so there is no way to set the discriminant of an uninitialized
+/// [`Option`] without a payload to move into it. Instead the payload is initialized on the stack,
+/// and then moved into the [`Option`]
+pub const fn some<I>(init: I) -> InitSome<I> {
+    InitSome(init)
+}
+
+/// Initialize an [`Option`] with `None`
+///
+/// see [`none`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct InitNone;
+
+/// Initialize an [`Option`] with `None`
+pub const fn none() -> InitNone {
+    InitNone
+}
+
+impl<T: Ctor<I>, I> Initializer<Option<T>> for InitSome<I> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<Option<T>>) -> Result<Init<Option<T>>, Self::Error> {
+        Ok(ptr.write(Some(try_init_on_stack(self.0)?)))
+    }
+}
+
+impl<T> Initializer<Option<T>> for InitNone {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, ptr: Uninit<Option<T>>) -> Result<Init<Option<T>>, Self::Error> {
+        Ok(ptr.write(None))
+    }
+}
+
+impl<T, I> DefaultLayoutProviderFor<Option<T>> for InitSome<I> {
+    type LayoutProvider = SizedLayoutProvider;
+}
+
+impl<T> DefaultLayoutProviderFor<Option<T>> for InitNone {
+    type LayoutProvider = SizedLayoutProvider;
+}
@@ -0,0 +1,23 @@
+use super::{none, some};
+
+#[test]
+fn options() {
+    let mut value = core::mem::MaybeUninit::<Option<u32>>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(value.as_mut_ptr()) };
+    let Ok(init) = uninit.try_init(some(5u32));
+    assert_eq!(*init.as_ref(), Some(5));
+
+    let mut value = core::mem::MaybeUninit::<Option<u32>>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(value.as_mut_ptr()) };
+    let Ok(init) = uninit.try_init(none());
+    assert_eq!(*init.as_ref(), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_option() {
+    let opt = crate::boxed::boxed::<Option<[u16; 8]>, _>(some(()));
+    assert_eq!(*opt, Some([0; 8]));
+}
@@ -0,0 +1,273 @@
+//! pinned in-place initialization
+//!
+//! A [`PinnedUninit`] is an [`Uninit`] which promises that the value initialized in it
+//! will be pinned. i.e. it will not be moved or invalidated until after its destructor runs.
+//! This allows address-sensitive (e.g. self-referential) types to be initialized in place.
+
+use core::pin::Pin;
+
+use crate::{Ctor, Init, Initializer, PinCtor, PinInitializer, Uninit};
+
+#[cfg(test)]
+mod tests;
+
+/// A pointer type which represents a pointer to some uninitialized allocated memory,
+/// which will be pinned once it is initialized
+#[repr(transparent)]
+pub struct PinnedUninit<'brand, T: ?Sized> {
+    raw: Uninit<'brand, T>,
+}
+
+/// A pointer type which represents a pointer to some initialized and pinned allocated memory
+#[repr(transparent)]
+pub struct PinnedInit<'brand, T: ?Sized> {
+    raw: Init<'brand, T>,
+}
+
+impl<'brand, T: ?Sized> Uninit<'brand, T> {
+    /// Promise that the value initialized in this pointer will be pinned
+    ///
+    /// # Safety
+    ///
+    /// The memory is owned by the allocation the brand came from, which must uphold the pinning guarantees.
+    /// i.e. the memory may not be reused or invalidated until the value initialized in it is dropped,
+    /// even if the [`PinnedInit`] is leaked
+    pub const unsafe fn into_pinned(self) -> PinnedUninit<'brand, T> {
+        PinnedUninit { raw: self }
+    }
+}
+
+impl<'brand, T: ?Sized> Init<'brand, T> {
+    /// Pin the value behind this pointer
+    ///
+    /// # Safety
+    ///
+    /// The memory is owned by the allocation the brand came from, which must uphold the pinning guarantees.
+    /// i.e. the memory may not be reused or invalidated until the value is dropped,
+    /// even if the [`PinnedInit`] is leaked
+    pub const unsafe fn into_pinned(self) -> PinnedInit<'brand, T> {
+        PinnedInit { raw: self }
+    }
+}
+
+impl<'brand, T: ?Sized> PinnedUninit<'brand, T> {
+    /// Get the underlying [`Uninit`] pointer
+    ///
+    /// This is safe because there is no value to pin yet
+    pub const fn into_inner(self) -> Uninit<'brand, T> {
+        self.raw
+    }
+
+    /// Get the underlying raw pointer
+    ///
+    /// # Safety
+    ///
+    /// You may not write through this pointer
+    pub const fn as_ptr(&self) -> *const T {
+        self.raw.as_ptr()
+    }
+
+    /// Get the underlying mutable raw pointer
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.raw.as_mut_ptr()
+    }
+
+    /// Convert this [`PinnedUninit`] into a [`PinnedInit`] without checking if it is initialized
+    ///
+    /// # Safety
+    ///
+    /// This pointer must point to an initialized value
+    pub const unsafe fn assume_init(self) -> PinnedInit<'brand, T> {
+        PinnedInit {
+            // SAFETY: the caller ensures that the value is initialized
+            raw: unsafe { self.raw.assume_init() },
+        }
+    }
+
+    /// Try to initialize self in place with the given arguments
+    pub fn try_pin_init<Args>(self, args: Args) -> Result<PinnedInit<'brand, T>, T::Error>
+    where
+        T: PinCtor<Args>,
+    {
+        PinCtor::try_pin_init(self, args)
+    }
+
+    /// Initialize self in place with the given arguments
+    pub fn pin_init<Args>(self, args: Args) -> PinnedInit<'brand, T>
+    where
+        T: PinCtor<Args, Error = core::convert::Infallible>,
+    {
+        let Ok(init) = self.try_pin_init(args);
+        init
+    }
+
+    /// Try to initialize self in place with the given (unpinned) arguments
+    pub fn try_init<Args>(self, args: Args) -> Result<PinnedInit<'brand, T>, T::Error>
+    where
+        T: Ctor<Args>,
+    {
+        self.raw
+            .try_init(args)
+            // SAFETY: the memory was already promised to be pinned when this PinnedUninit was created
+            .map(|init| unsafe { init.into_pinned() })
+    }
+
+    /// Initialize self in place with the given (unpinned) arguments
+    pub fn init<Args>(self, args: Args) -> PinnedInit<'brand, T>
+    where
+        T: Ctor<Args, Error = core::convert::Infallible>,
+    {
+        let Ok(init) = self.try_init(args);
+        init
+    }
+}
+
+impl<'brand, T> PinnedUninit<'brand, T> {
+    /// Write `value` into the pointer, and return the initialized pointer
+    pub const fn write(self, value: T) -> PinnedInit<'brand, T> {
+        // SAFETY: the memory was already promised to be pinned when this PinnedUninit was created
+        unsafe { self.raw.write(value).into_pinned() }
+    }
+}
+
+impl<'brand, T: ?Sized> PinnedInit<'brand, T> {
+    /// Get a pinned reference to the underlying value
+    pub const fn as_ref(&self) -> Pin<&T> {
+        // SAFETY: the value is pinned
+        unsafe { Pin::new_unchecked(self.raw.as_ref()) }
+    }
+
+    /// Get a pinned mutable reference to the underlying value
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        // SAFETY: the pointer is non-null, aligned, allocated, unique,
+        // and points to an initialized value which is pinned
+        unsafe { Pin::new_unchecked(&mut *self.raw.as_mut_ptr()) }
+    }
+
+    /// Get the underlying raw pointer
+    pub const fn as_ptr(&self) -> *const T {
+        self.raw.as_ptr()
+    }
+
+    /// Get the underlying mutable raw pointer
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.raw.as_mut_ptr()
+    }
+
+    /// Get the underlying [`Init`] pointer
+    ///
+    /// # Safety
+    ///
+    /// The value must continue to be treated as pinned
+    pub unsafe fn into_inner_unchecked(self) -> Init<'brand, T> {
+        self.raw
+    }
+
+    /// skips running the destructor for T, since someone
+    /// else is taking ownership of it
+    ///
+    /// # Safety
+    ///
+    /// The new owner must uphold the pinning guarantees, i.e. the value may not
+    /// be moved, and the memory may not be invalidated until the value is dropped
+    pub const unsafe fn take_ownership(self) {
+        core::mem::forget(self);
+    }
+}
+
+impl<'brand, T: Unpin + ?Sized> PinnedInit<'brand, T> {
+    /// Get the underlying [`Init`] pointer
+    pub fn into_inner(self) -> Init<'brand, T> {
+        // SAFETY: T is Unpin, so it doesn't need to be treated as pinned
+        unsafe { self.into_inner_unchecked() }
+    }
+}
+
+/// A destructor which receives a pinned reference to the value
+///
+/// Types which are structurally pinned can't soundly implement [`Drop`] by hand, since
+/// it only provides `&mut Self`. Instead implement this trait, and then use
+/// [`pinned_drop!`](crate::pinned_drop) to implement [`Drop`] in terms of it.
+pub trait PinnedDrop {
+    /// Run the destructor
+    ///
+    /// This can only be called from the [`Drop`] impl generated by
+    /// [`pinned_drop!`](crate::pinned_drop), since [`OnlyCallFromDrop`] can't be constructed.
+    fn drop(self: Pin<&mut Self>, only_call_from_drop: OnlyCallFromDrop);
+}
+
+/// A token which ensures that [`PinnedDrop::drop`] is only called from [`Drop::drop`]
+pub struct OnlyCallFromDrop(());
+
+impl OnlyCallFromDrop {
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// This must only be called from inside [`Drop::drop`]
+    pub const unsafe fn __new() -> Self {
+        Self(())
+    }
+}
+
+/// Converts a closure to a pinned initializer
+#[derive(Clone, Copy)]
+pub struct PinInitFn<F>(F);
+
+impl<T: ?Sized, F: FnOnce(PinnedUninit<T>) -> PinnedInit<T>> PinInitializer<T> for PinInitFn<F> {
+    type Error = core::convert::Infallible;
+
+    fn try_pin_init_into(self, ptr: PinnedUninit<T>) -> Result<PinnedInit<T>, Self::Error> {
+        Ok((self.0)(ptr))
+    }
+}
+
+/// Create a pinned initializer from a function/closure
+pub const fn from_fn<T, F>(f: F) -> PinInitFn<F>
+where
+    T: ?Sized,
+    F: FnOnce(PinnedUninit<T>) -> PinnedInit<T>,
+{
+    PinInitFn(f)
+}
+
+/// Converts a closure to a pinned initializer
+#[derive(Clone, Copy)]
+pub struct TryPinInitFn<F>(F);
+
+impl<T: ?Sized, E, F: FnOnce(PinnedUninit<T>) -> Result<PinnedInit<T>, E>> PinInitializer<T>
+    for TryPinInitFn<F>
+{
+    type Error = E;
+
+    fn try_pin_init_into(self, ptr: PinnedUninit<T>) -> Result<PinnedInit<T>, Self::Error> {
+        (self.0)(ptr)
+    }
+}
+
+/// Create a pinned initializer from a function/closure
+pub const fn try_from_fn<T, E, F>(f: F) -> TryPinInitFn<F>
+where
+    T: ?Sized,
+    F: FnOnce(PinnedUninit<T>) -> Result<PinnedInit<T>, E>,
+{
+    TryPinInitFn(f)
+}
+
+/// Converts an [`Initializer`] to a pinned initializer
+///
+/// see [`from_init`] for details
+#[derive(Clone, Copy)]
+pub struct FromInit<I>(I);
+
+impl<T: ?Sized, I: Initializer<T>> PinInitializer<T> for FromInit<I> {
+    type Error = I::Error;
+
+    fn try_pin_init_into(self, ptr: PinnedUninit<T>) -> Result<PinnedInit<T>, Self::Error> {
+        ptr.try_init(self.0)
+    }
+}
+
+/// Use an [`Initializer`] where a pinned initializer is expected
+pub const fn from_init<I>(init: I) -> FromInit<I> {
+    FromInit(init)
+}
@@ -0,0 +1,127 @@
+use core::{convert::Infallible, marker::PhantomPinned, mem::MaybeUninit};
+
+use super::{PinnedInit, PinnedUninit};
+use crate::{PinInitializer, Uninit};
+
+struct SelfRef {
+    value: u32,
+    ptr: *const u32,
+    _pinned: PhantomPinned,
+}
+
+fn self_ref(value: u32) -> impl PinInitializer<SelfRef, Error = Infallible> {
+    super::from_fn(move |mut uninit: PinnedUninit<SelfRef>| {
+        let ptr = uninit.as_mut_ptr();
+        uninit.write(SelfRef {
+            value,
+            // SAFETY: ptr is valid, and the value will be pinned
+            ptr: unsafe { &raw const (*ptr).value },
+            _pinned: PhantomPinned,
+        })
+    })
+}
+
+#[test]
+fn pin_init() {
+    let mut slot = MaybeUninit::<SelfRef>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw,
+    // and the value is dropped before the slot goes out of scope
+    let uninit = unsafe { Uninit::from_raw(slot.as_mut_ptr()).into_pinned() };
+
+    let init: PinnedInit<SelfRef> = uninit.pin_init(self_ref(10));
+
+    let value = init.as_ref();
+    assert_eq!(value.value, 10);
+    assert_eq!(value.ptr, &raw const value.value);
+}
+
+struct Outer {
+    inner: SelfRef,
+    count: usize,
+}
+
+#[test]
+fn pin_init_struct() {
+    let mut slot = MaybeUninit::<Outer>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw,
+    // and the value is dropped before the slot goes out of scope
+    let uninit = unsafe { Uninit::from_raw(slot.as_mut_ptr()).into_pinned() };
+
+    // SAFETY: Outer is !Unpin because of SelfRef, and doesn't move out of inner
+    let init = uninit.pin_init(unsafe {
+        crate::pin_init_struct!(Outer {
+            #[pin]
+            inner: self_ref(10),
+            count: 3usize,
+        })
+    });
+
+    let value = init.as_ref();
+    assert_eq!(value.count, 3);
+    assert_eq!(value.inner.ptr, &raw const value.inner.value);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn emplace_pin() {
+    let bx = crate::boxed::emplace_pin::<SelfRef, _, crate::layout_provider::SizedLayoutProvider>(
+        self_ref(10),
+    );
+
+    assert_eq!(bx.ptr, &raw const bx.value);
+}
+
+#[test]
+fn stack_pin_init() {
+    crate::stack_pin_init!(let value: SelfRef = self_ref(10));
+    assert_eq!(value.value, 10);
+    assert_eq!(value.ptr, &raw const value.value);
+
+    crate::stack_try_pin_init!(let value: SelfRef = self_ref(20));
+    let Ok(value) = value;
+    assert_eq!(value.value, 20);
+    assert_eq!(value.ptr, &raw const value.value);
+}
+
+#[test]
+fn pin_slice_writer() {
+    let mut slot = MaybeUninit::<[SelfRef; 3]>::uninit();
+    let slice = core::ptr::slice_from_raw_parts_mut(slot.as_mut_ptr().cast::<SelfRef>(), 3);
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw,
+    // and the value is dropped before the slot goes out of scope
+    let uninit = unsafe { Uninit::from_raw(slice).into_pinned() };
+
+    let mut writer = crate::slice_writer::PinSliceWriter::new(uninit);
+    for i in 0..3 {
+        assert!(matches!(writer.try_pin_init(self_ref(i)), Ok(Ok(()))));
+    }
+    assert!(writer.try_pin_init(self_ref(3)).is_err());
+
+    let init = writer.finish();
+    for (i, value) in init.as_ref().iter().enumerate() {
+        assert_eq!(value.value, i as u32);
+        assert_eq!(value.ptr, &raw const value.value);
+    }
+}
+
+#[test]
+fn project_pin() {
+    let mut slot = MaybeUninit::<Outer>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw,
+    // and the value is dropped before the slot goes out of scope
+    let mut uninit = unsafe { Uninit::from_raw(slot.as_mut_ptr()).into_pinned() };
+
+    // SAFETY: Outer is !Unpin because of SelfRef, and doesn't move out of its fields
+    let (inner, count) = unsafe { crate::project_pin!(Outer, uninit, inner, count) };
+    // SAFETY: the parent takes ownership of the fields, and is pinned
+    unsafe {
+        inner.pin_init(self_ref(10)).take_ownership();
+        count.write(3).take_ownership();
+    }
+    // SAFETY: all fields were initialized
+    let init = unsafe { uninit.assume_init() };
+
+    let value = init.as_ref();
+    assert_eq!(value.count, 3);
+    assert_eq!(value.inner.ptr, &raw const value.inner.value);
+}
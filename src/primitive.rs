@@ -1,17 +1,34 @@
 #![allow(clippy::cmp_null, ambiguous_wide_pointer_comparisons)]
 
 use crate::{
-    layout_provider::{DefaultLayoutProviderFor, LayoutProvider},
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider, SizedLayoutProvider},
     Init, Uninit,
 };
 
-use core::{alloc::Layout, marker::PhantomData, ptr::NonNull};
+use core::{
+    alloc::Layout,
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    num::{NonZero, Saturating, Wrapping},
+    ptr::NonNull,
+};
+
+#[cfg(test)]
+mod tests;
 
 /// A [`LayoutProvider`] for [`Sized`] types
 pub struct PrimitiveLayoutProvider;
 
+/// A [`LayoutProvider`] for `#[repr(transparent)]` wrappers like [`Wrapping`], which
+/// forwards `is_zeroed` to the layout provider of the inner initializer
+pub struct WrapperLayoutProvider<L>(L);
+
+/// The error type for initializing a [`NonZero`] from an integer, the integer was zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroError;
+
 macro_rules! prim {
-    ($(=> [$($binder:tt)*])? $t:ty => $zero:expr) => {
+    ($(=> [$($binder:tt)*])? $t:ty => $zero:expr $(, |$value:ident| $bytes:expr)?) => {
         impl<$($($binder)*)?> DefaultLayoutProviderFor<$t> for () {
             type LayoutProvider = PrimitiveLayoutProvider;
         }
@@ -41,7 +58,8 @@ macro_rules! prim {
         impl<$($($binder)*)?> DefaultLayoutProviderFor<$t> for $t {
             type LayoutProvider = PrimitiveLayoutProvider;
         }
-        /// SAFETY: is_zeroed only returns true if args is zero
+        /// SAFETY: is_zeroed only returns true if args is zero, and byte_pattern
+        /// only returns a byte if every byte of args is that byte
         unsafe impl<$($($binder)*)?> LayoutProvider<$t, $t> for PrimitiveLayoutProvider {
             fn layout(_: &$t) -> Option<Layout> {
                 Some(Layout::new::<$t>())
@@ -56,6 +74,11 @@ macro_rules! prim {
                 *_args == $zero
 
             }
+
+            $(fn byte_pattern($value: &$t) -> Option<u8> {
+                let $value = *$value;
+                byte_pattern(&$bytes)
+            })?
         }
 
         impl<$($($binder)*)?> crate::Initializer<$t> for $t {
@@ -97,25 +120,31 @@ macro_rules! prim {
     };
 }
 
-prim!(u8 => 0);
-prim!(u16 => 0);
-prim!(u32 => 0);
-prim!(u64 => 0);
-prim!(u128 => 0);
-prim!(usize => 0);
-
-prim!(i8 => 0);
-prim!(i16 => 0);
-prim!(i32 => 0);
-prim!(i64 => 0);
-prim!(i128 => 0);
-prim!(isize => 0);
-
-prim!(f32 => 0.0);
-prim!(f64 => 0.0);
-
-prim!(bool => false);
-prim!(char => '\0');
+/// If all bytes are the same, returns that byte
+fn byte_pattern(bytes: &[u8]) -> Option<u8> {
+    let (&first, rest) = bytes.split_first()?;
+    rest.iter().all(|&byte| byte == first).then_some(first)
+}
+
+prim!(u8 => 0, |v| v.to_ne_bytes());
+prim!(u16 => 0, |v| v.to_ne_bytes());
+prim!(u32 => 0, |v| v.to_ne_bytes());
+prim!(u64 => 0, |v| v.to_ne_bytes());
+prim!(u128 => 0, |v| v.to_ne_bytes());
+prim!(usize => 0, |v| v.to_ne_bytes());
+
+prim!(i8 => 0, |v| v.to_ne_bytes());
+prim!(i16 => 0, |v| v.to_ne_bytes());
+prim!(i32 => 0, |v| v.to_ne_bytes());
+prim!(i64 => 0, |v| v.to_ne_bytes());
+prim!(i128 => 0, |v| v.to_ne_bytes());
+prim!(isize => 0, |v| v.to_ne_bytes());
+
+prim!(f32 => 0.0, |v| v.to_bits().to_ne_bytes());
+prim!(f64 => 0.0, |v| v.to_bits().to_ne_bytes());
+
+prim!(bool => false, |v| [v as u8]);
+prim!(char => '\0', |v| (v as u32).to_ne_bytes());
 prim!(=> [T] *const T => core::ptr::null());
 prim!(=> [T] *mut T => core::ptr::null_mut());
 prim!(=> [T] *const [T] => core::ptr::slice_from_raw_parts(core::ptr::null(), 0));
@@ -128,3 +157,213 @@ prim!(=> ['a, T: ?Sized] &'a T);
 prim!(=> ['a, T: ?Sized] &'a mut T);
 prim!(core::time::Duration);
 prim!(core::cmp::Ordering);
+
+macro_rules! non_zero {
+    ($($t:ty)*) => {$(
+        prim!(NonZero<$t>);
+        prim!(Option<NonZero<$t>> => None);
+
+        impl DefaultLayoutProviderFor<NonZero<$t>> for $t {
+            type LayoutProvider = PrimitiveLayoutProvider;
+        }
+        /// SAFETY: is_zeroed never returns true, since zero is rejected
+        unsafe impl LayoutProvider<NonZero<$t>, $t> for PrimitiveLayoutProvider {
+            fn layout(_: &$t) -> Option<Layout> {
+                Some(Layout::new::<NonZero<$t>>())
+            }
+
+            fn cast(ptr: NonNull<()>, _: &$t) -> NonNull<NonZero<$t>> {
+                ptr.cast()
+            }
+
+            fn is_zeroed(_args: &$t) -> bool {
+                false
+            }
+        }
+
+        impl crate::Initializer<NonZero<$t>> for $t {
+            type Error = ZeroError;
+
+            fn try_init_into(self, u: Uninit<NonZero<$t>>) -> Result<Init<NonZero<$t>>, Self::Error> {
+                match NonZero::new(self) {
+                    Some(value) => Ok(u.write(value)),
+                    None => Err(ZeroError),
+                }
+            }
+        }
+    )*};
+}
+
+non_zero!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! wrapper {
+    ($($wrapper:ident)*) => {$(
+        impl<T, I: DefaultLayoutProviderFor<T>> DefaultLayoutProviderFor<$wrapper<T>> for $wrapper<I> {
+            type LayoutProvider = WrapperLayoutProvider<I::LayoutProvider>;
+        }
+        /// SAFETY: the wrapper is sized, and is_zeroed forwards to the layout provider
+        /// of the inner initializer, and the wrapper is `#[repr(transparent)]`
+        unsafe impl<T, I, L: LayoutProvider<T, I>> LayoutProvider<$wrapper<T>, $wrapper<I>>
+            for WrapperLayoutProvider<L>
+        {
+            fn layout(_: &$wrapper<I>) -> Option<Layout> {
+                Some(Layout::new::<$wrapper<T>>())
+            }
+
+            fn cast(ptr: NonNull<()>, _: &$wrapper<I>) -> NonNull<$wrapper<T>> {
+                ptr.cast()
+            }
+
+            fn is_zeroed(args: &$wrapper<I>) -> bool {
+                L::is_zeroed(&args.0)
+            }
+        }
+
+        impl<T: crate::Ctor<I>, I> crate::Initializer<$wrapper<T>> for $wrapper<I> {
+            type Error = T::Error;
+
+            fn try_init_into(self, mut u: Uninit<$wrapper<T>>) -> Result<Init<$wrapper<T>>, Self::Error> {
+                // SAFETY: the wrapper is `#[repr(transparent)]`, so it's valid to initialize the inner value
+                let inner = unsafe { Uninit::from_raw(u.as_mut_ptr().cast::<T>()) };
+                inner.try_init(self.0)?.take_ownership();
+                // SAFETY: the inner value was initialized
+                Ok(unsafe { u.assume_init() })
+            }
+        }
+    )*};
+}
+
+wrapper!(Wrapping Saturating);
+
+impl<T, I: DefaultLayoutProviderFor<T>> DefaultLayoutProviderFor<ManuallyDrop<T>>
+    for ManuallyDrop<I>
+{
+    type LayoutProvider = WrapperLayoutProvider<I::LayoutProvider>;
+}
+/// SAFETY: ManuallyDrop is sized, and is_zeroed forwards to the layout provider
+/// of the inner initializer, and ManuallyDrop is `#[repr(transparent)]`
+unsafe impl<T, I, L: LayoutProvider<T, I>> LayoutProvider<ManuallyDrop<T>, ManuallyDrop<I>>
+    for WrapperLayoutProvider<L>
+{
+    fn layout(_: &ManuallyDrop<I>) -> Option<Layout> {
+        Some(Layout::new::<ManuallyDrop<T>>())
+    }
+
+    fn cast(ptr: NonNull<()>, _: &ManuallyDrop<I>) -> NonNull<ManuallyDrop<T>> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(args: &ManuallyDrop<I>) -> bool {
+        L::is_zeroed(args)
+    }
+}
+
+impl<T: crate::Ctor<I>, I> crate::Initializer<ManuallyDrop<T>> for ManuallyDrop<I> {
+    type Error = T::Error;
+
+    fn try_init_into(
+        self,
+        mut u: Uninit<ManuallyDrop<T>>,
+    ) -> Result<Init<ManuallyDrop<T>>, Self::Error> {
+        // SAFETY: ManuallyDrop is `#[repr(transparent)]`, so it's valid to initialize the inner value
+        let inner = unsafe { Uninit::from_raw(u.as_mut_ptr().cast::<T>()) };
+        inner
+            .try_init(ManuallyDrop::into_inner(self))?
+            .take_ownership();
+        // SAFETY: the inner value was initialized
+        Ok(unsafe { u.assume_init() })
+    }
+}
+
+impl<T> DefaultLayoutProviderFor<MaybeUninit<T>> for () {
+    type LayoutProvider = SizedLayoutProvider;
+}
+
+/// Leaves the value uninitialized, since any bytes are a valid [`MaybeUninit`]
+impl<T> crate::Initializer<MaybeUninit<T>> for () {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(
+        self,
+        u: Uninit<MaybeUninit<T>>,
+    ) -> Result<Init<MaybeUninit<T>>, Self::Error> {
+        // SAFETY: an uninitialized MaybeUninit is always valid
+        Ok(unsafe { u.assume_init() })
+    }
+}
+
+macro_rules! atomic {
+    ($($size:literal: $(=> [$($binder:tt)*])? $atomic:ty => $t:ty => $zero:expr;)*) => {$(
+        #[cfg(target_has_atomic = $size)]
+        const _: () = {
+            impl<$($($binder)*)?> DefaultLayoutProviderFor<$atomic> for () {
+                type LayoutProvider = PrimitiveLayoutProvider;
+            }
+            /// SAFETY: an atomic has the same in-memory representation as its value,
+            /// so it's zeroed if it's initialized with zero
+            unsafe impl<$($($binder)*)?> LayoutProvider<$atomic, ()> for PrimitiveLayoutProvider {
+                fn layout(_: &()) -> Option<Layout> {
+                    Some(Layout::new::<$atomic>())
+                }
+
+                fn cast(ptr: NonNull<()>, _: &()) -> NonNull<$atomic> {
+                    ptr.cast()
+                }
+
+                fn is_zeroed(_args: &()) -> bool {
+                    true
+                }
+            }
+
+            impl<$($($binder)*)?> crate::Initializer<$atomic> for () {
+                type Error = core::convert::Infallible;
+
+                fn try_init_into(self, u: Uninit<$atomic>) -> Result<Init<$atomic>, Self::Error> {
+                    Ok(u.write(<$atomic>::new($zero)))
+                }
+            }
+
+            impl<$($($binder)*)?> DefaultLayoutProviderFor<$atomic> for $t {
+                type LayoutProvider = PrimitiveLayoutProvider;
+            }
+            /// SAFETY: an atomic has the same in-memory representation as its value,
+            /// so it's zeroed if it's initialized with zero
+            unsafe impl<$($($binder)*)?> LayoutProvider<$atomic, $t> for PrimitiveLayoutProvider {
+                fn layout(_: &$t) -> Option<Layout> {
+                    Some(Layout::new::<$atomic>())
+                }
+
+                fn cast(ptr: NonNull<()>, _: &$t) -> NonNull<$atomic> {
+                    ptr.cast()
+                }
+
+                fn is_zeroed(args: &$t) -> bool {
+                    *args == $zero
+                }
+            }
+
+            impl<$($($binder)*)?> crate::Initializer<$atomic> for $t {
+                type Error = core::convert::Infallible;
+
+                fn try_init_into(self, u: Uninit<$atomic>) -> Result<Init<$atomic>, Self::Error> {
+                    Ok(u.write(<$atomic>::new(self)))
+                }
+            }
+        };
+    )*};
+}
+
+atomic! {
+    "8": core::sync::atomic::AtomicBool => bool => false;
+    "8": core::sync::atomic::AtomicU8 => u8 => 0;
+    "8": core::sync::atomic::AtomicI8 => i8 => 0;
+    "16": core::sync::atomic::AtomicU16 => u16 => 0;
+    "16": core::sync::atomic::AtomicI16 => i16 => 0;
+    "32": core::sync::atomic::AtomicU32 => u32 => 0;
+    "32": core::sync::atomic::AtomicI32 => i32 => 0;
+    "64": core::sync::atomic::AtomicU64 => u64 => 0;
+    "64": core::sync::atomic::AtomicI64 => i64 => 0;
+    "ptr": core::sync::atomic::AtomicUsize => usize => 0;
+    "ptr": core::sync::atomic::AtomicIsize => isize => 0;
+    "ptr": => [T] core::sync::atomic::AtomicPtr<T> => *mut T => core::ptr::null_mut();
+}
@@ -0,0 +1,97 @@
+use core::{
+    mem::{ManuallyDrop, MaybeUninit},
+    num::{NonZero, Saturating, Wrapping},
+};
+
+use crate::{
+    layout_provider::{DefaultLayoutProvider, LayoutProvider},
+    Ctor, Uninit,
+};
+
+use super::ZeroError;
+
+fn init<T: Ctor<I>, I>(init: I) -> Result<T, T::Error> {
+    let mut value = MaybeUninit::<T>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+    uninit.try_init(init)?.take_ownership();
+    // SAFETY: the value was just initialized
+    Ok(unsafe { value.assume_init() })
+}
+
+fn is_zeroed<T: DefaultLayoutProvider<I>, I>(args: I) -> bool {
+    <T::LayoutProvider as LayoutProvider<T, I>>::is_zeroed(&args)
+}
+
+#[test]
+fn non_zero() {
+    assert_eq!(init::<NonZero<u32>, _>(5u32), Ok(NonZero::new(5).unwrap()));
+    assert_eq!(init::<NonZero<i8>, _>(0i8), Err(ZeroError));
+    assert!(!is_zeroed::<NonZero<u32>, _>(1u32));
+
+    assert_eq!(init::<Option<NonZero<u64>>, _>(()), Ok(None));
+    assert!(is_zeroed::<Option<NonZero<u64>>, _>(()));
+    assert!(is_zeroed::<Option<NonZero<u64>>, _>(None::<NonZero<u64>>));
+}
+
+#[test]
+fn wrappers() {
+    assert_eq!(init::<Wrapping<u8>, _>(Wrapping(3u8)), Ok(Wrapping(3)));
+    assert_eq!(
+        init::<Saturating<i16>, _>(Saturating(())),
+        Ok(Saturating(0))
+    );
+    assert!(is_zeroed::<Wrapping<u8>, _>(Wrapping(())));
+    assert!(!is_zeroed::<Saturating<u8>, _>(Saturating(1u8)));
+
+    assert_eq!(
+        init::<ManuallyDrop<u32>, _>(ManuallyDrop::new(5u32)),
+        Ok(ManuallyDrop::new(5))
+    );
+    assert!(is_zeroed::<ManuallyDrop<u32>, _>(ManuallyDrop::new(())));
+}
+
+#[test]
+fn maybe_uninit() {
+    struct Lazy {
+        ready: bool,
+        buffer: MaybeUninit<[u8; 64]>,
+    }
+
+    let lazy: Result<Lazy, core::convert::Infallible> = init(crate::init_struct!(Lazy {
+        ready: false,
+        buffer: (),
+    }));
+    let Ok(mut lazy) = lazy;
+    lazy.buffer.write([1; 64]);
+    lazy.ready = true;
+    // SAFETY: the buffer was just initialized
+    assert_eq!(unsafe { lazy.buffer.assume_init_ref() }[63], 1);
+    assert!(!is_zeroed::<MaybeUninit<u32>, _>(()));
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn atomics() {
+    use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+    struct Node {
+        count: AtomicUsize,
+        locked: AtomicBool,
+        next: AtomicPtr<Node>,
+    }
+
+    let node: Result<Node, core::convert::Infallible> = init(crate::init_struct!(Node {
+        count: 3usize,
+        locked: (),
+        next: core::ptr::null_mut::<Node>(),
+    }));
+    let Ok(node) = node;
+    assert_eq!(node.count.load(Ordering::Relaxed), 3);
+    assert!(!node.locked.load(Ordering::Relaxed));
+    assert!(node.next.load(Ordering::Relaxed).is_null());
+
+    assert!(is_zeroed::<AtomicUsize, _>(()));
+    assert!(is_zeroed::<AtomicBool, _>(false));
+    assert!(!is_zeroed::<AtomicUsize, _>(1usize));
+}
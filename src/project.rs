@@ -0,0 +1,17 @@
+//! safe field projections for [`Uninit`] pointers
+
+use crate::Uninit;
+
+/// A type whose fields can be projected out of an [`Uninit`] pointer to it
+///
+/// This is usually implemented with `#[derive(Project)]`, which generates a
+/// `{Name}Projection` struct containing an [`Uninit`] pointer for each field
+pub trait Project {
+    /// The type which holds an [`Uninit`] pointer to each field of `Self`
+    type Projection<'a>
+    where
+        Self: 'a;
+
+    /// Split the pointer into pointers to each of its fields
+    fn project<'a>(uninit: &'a mut Uninit<'_, Self>) -> Self::Projection<'a>;
+}
@@ -1,6 +1,16 @@
-use core::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
-
-use crate::{polyfill, Ctor};
+use core::{
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Bound, Range, RangeBounds},
+    ptr::NonNull,
+};
+
+use crate::{
+    iter::{InitSliceIter, UninitSliceIter},
+    project::Project,
+    str::FromUtf8Error,
+    Ctor,
+};
 
 #[cfg(test)]
 mod tests;
@@ -18,25 +28,6 @@ pub struct Init<'brand, T: ?Sized> {
     raw: Uninit<'brand, T>,
 }
 
-/// An iterator over a [`Uninit<[T]>`]
-pub struct UninitSliceIter<'brand, T> {
-    /// The pointer to the next element to return, or the past-the-end location
-    /// if the iterator is empty.
-    ///
-    /// This address will be used for all ZST elements, never changed.
-    ptr: NonNull<T>,
-    /// For non-ZSTs, the non-null pointer to the past-the-end element.
-    ///
-    /// For ZSTs, this is `ptr::without_provenance_mut(len)`.
-    end_or_len: *mut T,
-    _marker: PhantomData<Uninit<'brand, [T]>>,
-}
-
-/// An iterator over a [`Init<[T]>`]
-pub struct InitSliceIter<'brand, T> {
-    iter: UninitSliceIter<'brand, T>,
-}
-
 impl<T: ?Sized> Drop for Init<'_, T> {
     fn drop(&mut self) {
         // SAFETY: `Init` represents a raw pointer to an initialized,
@@ -104,9 +95,38 @@ impl<'brand, T: ?Sized> Uninit<'brand, T> {
         let Ok(init) = self.try_init(args);
         init
     }
+
+    /// Split this pointer into pointers to each of the fields of `T`
+    pub fn project(&mut self) -> T::Projection<'_>
+    where
+        T: Project,
+    {
+        T::project(self)
+    }
 }
 
 impl<'brand, T> Uninit<'brand, T> {
+    /// Create an [`Uninit`] which points to the memory inside of `value`
+    ///
+    /// # Safety
+    ///
+    /// The brand is taken from the lifetime of the reference, which the caller can choose,
+    /// so the pointer may not be used in place of any other pointer with the same brand, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub const unsafe fn from_maybe_uninit(value: &'brand mut MaybeUninit<T>) -> Self {
+        // SAFETY: a reference to a MaybeUninit is non-null, aligned, and valid for reads and writes of T,
+        // and the caller ensures that the brand isn't misused
+        unsafe { Self::from_raw(value.as_mut_ptr()) }
+    }
+
+    /// Set every byte of the pointee to `byte`
+    ///
+    /// This doesn't initialize the pointer, since `byte` may not be a valid bit-pattern for `T`
+    pub const fn write_bytes(&mut self, byte: u8) {
+        // SAFETY: as_mut_ptr returns a pointer which is valid for writes
+        unsafe { self.as_mut_ptr().write_bytes(byte, 1) }
+    }
+
     /// Write `value` into the pointer, and return the initialized pointer
     pub const fn write(mut self, value: T) -> Init<'brand, T> {
         // SAFETY: as_mut_ptr returns a pointer which is valid for writes
@@ -116,27 +136,86 @@ impl<'brand, T> Uninit<'brand, T> {
     }
 }
 
-impl<T> UninitSliceIter<'_, T> {
-    const IS_ZST: bool = core::mem::size_of::<T>() == 0;
-
-    const fn new(ptr: NonNull<[T]>) -> Self {
-        UninitSliceIter {
-            ptr: ptr.cast(),
-            end_or_len: if Self::IS_ZST {
-                polyfill::without_provenance_mut(ptr.len())
-            } else {
-                // SAFETY: ptr.len() is correct, so adding it to ptr.as_ptr()
-                // will not go past the bounds of the slice
-                unsafe { ptr.as_ptr().cast::<T>().add(ptr.len()) }
-            },
-            _marker: PhantomData,
-        }
+impl<'brand, T> Uninit<'brand, [T]> {
+    /// Create an [`Uninit`] which points to the memory inside of `slice`
+    ///
+    /// # Safety
+    ///
+    /// The brand is taken from the lifetime of the reference, which the caller can choose,
+    /// so the pointer may not be used in place of any other pointer with the same brand, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub const unsafe fn from_maybe_uninit_slice(slice: &'brand mut [MaybeUninit<T>]) -> Self {
+        let ptr = slice as *mut [MaybeUninit<T>] as *mut [T];
+        // SAFETY: a reference to a slice of MaybeUninit is non-null, aligned,
+        // and valid for reads and writes of the same number of Ts,
+        // and the caller ensures that the brand isn't misused
+        unsafe { Self::from_raw(ptr) }
+    }
+
+    /// Copy `slice` into the pointer, and return the initialized pointer
+    ///
+    /// # Panics
+    ///
+    /// if the lengths of the slices don't match
+    pub fn write_slice(mut self, slice: &[T]) -> Init<'brand, [T]>
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            self.len(),
+            slice.len(),
+            "the slices must have the same length"
+        );
+        // SAFETY: the uninit is not aliased so it doesn't overlap with slice
+        // and we just checked that they have the same lengths
+        unsafe {
+            self.as_mut_ptr()
+                .cast::<T>()
+                .copy_from_nonoverlapping(slice.as_ptr(), slice.len())
+        };
+        // SAFETY: all elements were just initialized
+        unsafe { self.assume_init() }
+    }
+
+    /// Set every byte of every element of the slice to `byte`
+    ///
+    /// This doesn't initialize the slice, since `byte` may not be a valid bit-pattern for `T`
+    pub const fn write_bytes(&mut self, byte: u8) {
+        let len = self.len();
+        // SAFETY: as_mut_ptr returns a pointer which is valid for writes of len elements
+        unsafe { self.as_mut_ptr().cast::<T>().write_bytes(byte, len) }
+    }
+
+    /// Move the elements of `array` into the pointer, and return the initialized pointer
+    ///
+    /// # Panics
+    ///
+    /// if the slice doesn't have exactly `N` elements
+    pub fn write_array<const N: usize>(mut self, array: [T; N]) -> Init<'brand, [T]> {
+        assert_eq!(
+            self.len(),
+            N,
+            "the slice must have the same length as the array"
+        );
+        // SAFETY: the slice has exactly N elements, so it has the same layout as [T; N]
+        unsafe { self.as_mut_ptr().cast::<[T; N]>().write(array) };
+        // SAFETY: all elements were just initialized
+        unsafe { self.assume_init() }
+    }
+}
+
+impl<'brand> Uninit<'brand, [u8]> {
+    /// Set every byte of the slice to `byte`, and return the initialized pointer
+    pub const fn fill(mut self, byte: u8) -> Init<'brand, [u8]> {
+        self.write_bytes(byte);
+        // SAFETY: every byte was just initialized
+        unsafe { self.assume_init() }
     }
 }
 
 impl<T> Uninit<'_, [T]> {
     /// Get an iterator over [`Uninit<T>`] which points to each element of the slice
-    pub fn iter_mut(&mut self) -> UninitSliceIter<'_, T> {
+    pub const fn iter_mut(&mut self) -> UninitSliceIter<'_, T> {
         UninitSliceIter::new(self.ptr)
     }
 
@@ -149,6 +228,50 @@ impl<T> Uninit<'_, [T]> {
     pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Get a mutable reference to the slice, as a slice of possibly uninitialized elements
+    pub const fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let ptr = self.as_mut_ptr() as *mut [MaybeUninit<T>];
+        // SAFETY: the pointer is non-null, aligned, unique, and allocated for the slice,
+        // and MaybeUninit<T> has the same layout as T, and doesn't need to be initialized
+        unsafe { &mut *ptr }
+    }
+
+    /// Borrow the elements in `range` as possibly uninitialized elements, or `None` if it is out of bounds
+    pub fn get(&mut self, range: impl RangeBounds<usize>) -> Option<&mut [MaybeUninit<T>]> {
+        let range = slice_range(range, self.len())?;
+        // SAFETY: slice_range ensures that the range is in bounds
+        Some(unsafe { self.get_unchecked(range) })
+    }
+
+    /// Borrow the elements in `range` as possibly uninitialized elements without checking if it is in bounds
+    ///
+    /// # Safety
+    ///
+    /// `range` must be in bounds of the slice
+    pub unsafe fn get_unchecked(&mut self, range: Range<usize>) -> &mut [MaybeUninit<T>] {
+        debug_assert!(range.start <= range.end && range.end <= self.len());
+        // SAFETY: the caller ensures that the range is in bounds
+        unsafe { self.as_uninit_slice_mut().get_unchecked_mut(range) }
+    }
+}
+
+impl<'brand> Uninit<'brand, str> {
+    /// The length of the string in bytes
+    pub const fn len(&self) -> usize {
+        (self.ptr.as_ptr() as *mut [u8]).len()
+    }
+
+    /// Returns true if the string has a length of 0
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Convert this pointer into a pointer to the bytes of the string
+    pub const fn into_bytes(self) -> Uninit<'brand, [u8]> {
+        // SAFETY: str has the same layout as [u8], so the pointer is valid for the same number of bytes
+        unsafe { Uninit::from_raw(self.ptr.as_ptr() as *mut [u8]) }
+    }
 }
 
 impl<T: ?Sized> AsRef<T> for Init<'_, T> {
@@ -157,6 +280,12 @@ impl<T: ?Sized> AsRef<T> for Init<'_, T> {
     }
 }
 
+impl<T: ?Sized> AsMut<T> for Init<'_, T> {
+    fn as_mut(&mut self) -> &mut T {
+        self.as_mut()
+    }
+}
+
 impl<T: ?Sized> Init<'_, T> {
     /// Get a reference to the underlying value
     pub const fn as_ref(&self) -> &T {
@@ -164,6 +293,12 @@ impl<T: ?Sized> Init<'_, T> {
         unsafe { self.raw.ptr.as_ref() }
     }
 
+    /// Get a mutable reference to the underlying value
+    pub const fn as_mut(&mut self) -> &mut T {
+        // SAFETY: The pointer is non-null, aligned, allocated, unique, and points to an initialized value
+        unsafe { self.raw.ptr.as_mut() }
+    }
+
     /// Get the underlying raw pointer
     pub const fn as_ptr(&self) -> *const T {
         self.raw.as_ptr()
@@ -181,214 +316,220 @@ impl<T: ?Sized> Init<'_, T> {
     }
 }
 
-impl<'brand, T> IntoIterator for Uninit<'brand, [T]> {
-    type IntoIter = UninitSliceIter<'brand, T>;
-    type Item = Uninit<'brand, T>;
-
-    fn into_iter(self) -> UninitSliceIter<'brand, T> {
-        UninitSliceIter::new(ManuallyDrop::new(self).ptr)
-    }
-}
-
-impl<'brand, T> IntoIterator for Init<'brand, [T]> {
-    type IntoIter = InitSliceIter<'brand, T>;
-    type Item = Init<'brand, T>;
-
-    fn into_iter(self) -> InitSliceIter<'brand, T> {
-        InitSliceIter {
-            iter: UninitSliceIter::new(ManuallyDrop::new(self).raw.ptr),
-        }
-    }
-}
-
-const fn iter_assume_init<T>(value: Uninit<T>) -> Init<T> {
-    // SAFETY: This is only called in [`InitSliceIter`]
-    // and the iterator is only created from a `Init<[T]>`
-    unsafe { value.assume_init() }
+/// Convert `range` to a range of indices into a slice of length `len`, or `None` if it's out of bounds
+pub(crate) fn slice_range(range: impl RangeBounds<usize>, len: usize) -> Option<Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    (start <= end && end <= len).then_some(start..end)
 }
 
-impl<'brand, T> UninitSliceIter<'brand, T> {
-    pub(crate) const unsafe fn unlink<'a>(self) -> UninitSliceIter<'a, T> {
-        UninitSliceIter {
-            ptr: self.ptr,
-            end_or_len: self.end_or_len,
-            _marker: PhantomData,
-        }
-    }
-
-    pub(crate) fn len(&self) -> usize {
-        if Self::IS_ZST {
-            polyfill::addr(self.end_or_len)
-        } else {
-            // Safety: self.end_or_len come from the same slice as self.ptr
-            unsafe { self.end_or_len.offset_from(self.ptr.as_ptr()) as usize }
+impl<'brand, T> Init<'brand, [T]> {
+    /// Create an initialized slice from the pointer to its first element and its length
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for `len` elements, which must be initialized
+    /// and owned by the brand
+    pub(crate) const unsafe fn from_parts(ptr: NonNull<T>, len: usize) -> Self {
+        Init {
+            raw: Uninit {
+                ptr: NonNull::slice_from_raw_parts(ptr, len),
+                brand: PhantomData,
+            },
         }
     }
 
-    pub(crate) fn is_empty(&self) -> bool {
-        if Self::IS_ZST {
-            self.end_or_len.is_null()
-        } else {
-            self.ptr.as_ptr() == self.end_or_len
+    /// Split the slice into two at `mid`, where each half only drops its own elements
+    ///
+    /// The first will contain the elements `[0, mid)` and the second will contain `[mid, len)`
+    ///
+    /// # Panics
+    ///
+    /// if `mid > len`
+    ///
+    /// # Safety
+    ///
+    /// The pieces keep the brand of the original slice, but don't cover its whole allocation,
+    /// so none of them may be used in place of the original slice, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub unsafe fn split_at(self, mid: usize) -> (Self, Self) {
+        let len = self.raw.len();
+        assert!(mid <= len, "mid > len");
+        let ptr = ManuallyDrop::new(self).raw.ptr.cast::<T>();
+        // SAFETY: mid <= len so the pointer stays in bounds, and the two halves don't overlap.
+        // The caller ensures that neither half is used in place of the original slice
+        unsafe {
+            (
+                Self::from_parts(ptr, mid),
+                Self::from_parts(ptr.add(mid), len - mid),
+            )
         }
     }
 
-    pub(crate) unsafe fn next_unchecked(&mut self) -> Uninit<'brand, T> {
-        if Self::IS_ZST {
-            self.end_or_len = self.end_or_len.wrapping_byte_sub(1);
-            Uninit {
-                ptr: self.ptr,
-                brand: PhantomData,
-            }
-        } else {
-            let ptr = self.ptr;
-            // SAFETY: there is at least one more element in the slice, so
-            // add will stay in bounds
-            self.ptr = unsafe { self.ptr.add(1) };
-            Uninit {
-                ptr,
-                brand: PhantomData,
-            }
+    /// Split off the first element of the slice, or `None` if it is empty
+    ///
+    /// # Safety
+    ///
+    /// The element and the rest keep the brand of the original slice, but don't cover its whole allocation,
+    /// so neither of them may be used in place of the original slice, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub unsafe fn split_first(self) -> Option<(Init<'brand, T>, Self)> {
+        if self.raw.is_empty() {
+            return None;
         }
+        // SAFETY: the caller ensures that the pieces aren't used in place of the original slice
+        let (first, rest) = unsafe { self.split_at(1) };
+        let ptr = ManuallyDrop::new(first).raw.ptr.cast::<T>();
+        // SAFETY: first contains exactly one initialized element
+        Some((
+            unsafe {
+                Uninit {
+                    ptr,
+                    brand: PhantomData,
+                }
+                .assume_init()
+            },
+            rest,
+        ))
     }
 
-    unsafe fn next_back_unchecked(&mut self) -> Uninit<'brand, T> {
-        if Self::IS_ZST {
-            self.end_or_len = self.end_or_len.wrapping_byte_sub(1);
-            Uninit {
-                ptr: self.ptr,
-                brand: PhantomData,
-            }
-        } else {
-            // SAFETY: there is at least one more element in the slice, so
-            // sub will stay in bounds, and since it is in bounds end_or_len
-            // must be non-null
+    /// Split off the last element of the slice, or `None` if it is empty
+    ///
+    /// # Safety
+    ///
+    /// The element and the rest keep the brand of the original slice, but don't cover its whole allocation,
+    /// so neither of them may be used in place of the original slice, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub unsafe fn split_last(self) -> Option<(Init<'brand, T>, Self)> {
+        let len = self.raw.len();
+        let mid = len.checked_sub(1)?;
+        // SAFETY: the caller ensures that the pieces aren't used in place of the original slice
+        let (rest, last) = unsafe { self.split_at(mid) };
+        let ptr = ManuallyDrop::new(last).raw.ptr.cast::<T>();
+        // SAFETY: last contains exactly one initialized element
+        Some((
             unsafe {
-                let end_or_len = self.end_or_len.sub(1);
-                self.end_or_len = end_or_len;
                 Uninit {
-                    ptr: NonNull::new_unchecked(end_or_len),
+                    ptr,
                     brand: PhantomData,
                 }
-            }
-        }
+                .assume_init()
+            },
+            rest,
+        ))
     }
 
-    fn reset(&mut self) {
-        if Self::IS_ZST {
-            self.end_or_len = core::ptr::null_mut();
-        } else {
-            self.end_or_len = self.ptr.as_ptr();
-        }
+    /// Get a reference to the initialized slice
+    pub const fn as_slice(&self) -> &[T] {
+        self.as_ref()
     }
 
-    pub(crate) fn reset_if(&mut self, b: bool) {
-        if !b {
-            return;
-        }
-        if Self::IS_ZST {
-            self.end_or_len = core::ptr::null_mut();
-        } else {
-            self.end_or_len = self.ptr.as_ptr();
-        }
+    /// Get a mutable reference to the initialized slice
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut()
     }
 
-    fn fwd_unchecked(&mut self, n: usize) {
-        if Self::IS_ZST {
-            self.end_or_len = self.end_or_len.wrapping_byte_sub(n);
-        } else {
-            // SAFETY: n < the number of remaining elements in the slice
-            self.ptr = unsafe { self.ptr.add(n) };
-        }
+    /// Get a reference to the elements in `range`, or `None` if it is out of bounds
+    pub fn get(&self, range: impl RangeBounds<usize>) -> Option<&[T]> {
+        let range = slice_range(range, self.raw.len())?;
+        // SAFETY: slice_range ensures that the range is in bounds
+        Some(unsafe { self.get_unchecked(range) })
     }
 
-    fn bck_unchecked(&mut self, n: usize) {
-        if Self::IS_ZST {
-            self.end_or_len = self.end_or_len.wrapping_byte_sub(n);
-        } else {
-            // SAFETY: n < the number of remaining elements in the slice
-            self.end_or_len = unsafe { self.end_or_len.sub(n) };
-        }
+    /// Get a reference to the elements in `range` without checking if it is in bounds
+    ///
+    /// # Safety
+    ///
+    /// `range` must be in bounds of the slice
+    pub unsafe fn get_unchecked(&self, range: Range<usize>) -> &[T] {
+        debug_assert!(range.start <= range.end && range.end <= self.raw.len());
+        // SAFETY: the caller ensures that the range is in bounds, and all elements are initialized
+        unsafe { self.as_ref().get_unchecked(range) }
     }
-}
 
-impl<T> ExactSizeIterator for UninitSliceIter<'_, T> {
-    fn len(&self) -> usize {
-        self.len()
+    /// Get a mutable reference to the elements in `range`, or `None` if it is out of bounds
+    pub fn get_mut(&mut self, range: impl RangeBounds<usize>) -> Option<&mut [T]> {
+        let range = slice_range(range, self.raw.len())?;
+        // SAFETY: slice_range ensures that the range is in bounds
+        Some(unsafe { self.get_unchecked_mut(range) })
     }
-}
-impl<'brand, T> Iterator for UninitSliceIter<'brand, T> {
-    type Item = Uninit<'brand, T>;
 
-    #[allow(unstable_name_collisions)]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.is_empty() {
-            None
-        } else {
-            // SAFETY: !is_empty
-            Some(unsafe { self.next_unchecked() })
-        }
+    /// Get a mutable reference to the elements in `range` without checking if it is in bounds
+    ///
+    /// # Safety
+    ///
+    /// `range` must be in bounds of the slice
+    pub unsafe fn get_unchecked_mut(&mut self, range: Range<usize>) -> &mut [T] {
+        debug_assert!(range.start <= range.end && range.end <= self.raw.len());
+        // SAFETY: the caller ensures that the range is in bounds
+        unsafe { self.as_mut_slice().get_unchecked_mut(range) }
     }
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if n >= self.len() {
-            self.reset();
-            None
-        } else {
-            self.fwd_unchecked(n);
-            // SAFETY: self.len() - n > 0
-            Some(unsafe { self.next_unchecked() })
+    /// Keep the elements in `range`, and drop all other elements
+    ///
+    /// # Panics
+    ///
+    /// if `range` is out of bounds
+    ///
+    /// # Safety
+    ///
+    /// The returned slice keeps the brand of the original slice, but doesn't cover its whole allocation,
+    /// so it may not be used in place of the original slice, for example by returning
+    /// it from [`Initializer::try_init_into`](crate::Initializer::try_init_into)
+    pub unsafe fn into_range(self, range: impl RangeBounds<usize>) -> Self {
+        let Some(range) = slice_range(range, self.raw.len()) else {
+            panic!("range out of bounds")
+        };
+        // SAFETY: the caller ensures that the returned slice isn't used in place of the original slice,
+        // and the other pieces are dropped here
+        unsafe {
+            let (rest, _suffix) = self.split_at(range.end);
+            let (_prefix, value) = rest.split_at(range.start);
+            value
         }
     }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.len();
-        (len, Some(len))
-    }
 }
 
-impl<T> DoubleEndedIterator for UninitSliceIter<'_, T> {
-    #[allow(unstable_name_collisions)]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.is_empty() {
-            None
-        } else {
-            // SAFETY: !is_empty
-            Some(unsafe { self.next_back_unchecked() })
-        }
-    }
-
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        if n >= self.len() {
-            self.reset();
-            None
-        } else {
-            self.bck_unchecked(n);
-            // SAFETY: self.len() - n > 0
-            Some(unsafe { self.next_back_unchecked() })
+impl<'brand> Init<'brand, str> {
+    /// Convert initialized bytes into a string, checking that they are valid UTF-8
+    ///
+    /// If the bytes are not valid UTF-8, they are given back along with the reason
+    pub fn from_utf8(bytes: Init<'brand, [u8]>) -> Result<Self, FromUtf8Error<'brand>> {
+        match core::str::from_utf8(bytes.as_slice()) {
+            Ok(_) => {
+                let ptr = ManuallyDrop::new(bytes).raw.ptr.as_ptr() as *mut str;
+                // SAFETY: the bytes were just checked to be valid UTF-8, and
+                // str has the same layout as [u8]
+                Ok(unsafe { Uninit::from_raw(ptr).assume_init() })
+            }
+            Err(error) => Err(FromUtf8Error { bytes, error }),
         }
     }
 }
 
-impl<T> ExactSizeIterator for InitSliceIter<'_, T> {
-    fn len(&self) -> usize {
-        self.iter.len()
-    }
-}
-impl<'brand, T> Iterator for InitSliceIter<'brand, T> {
-    type Item = Init<'brand, T>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(iter_assume_init)
-    }
+impl<'brand, T> IntoIterator for Uninit<'brand, [T]> {
+    type IntoIter = UninitSliceIter<'brand, T>;
+    type Item = Uninit<'brand, T>;
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+    fn into_iter(self) -> UninitSliceIter<'brand, T> {
+        UninitSliceIter::new(ManuallyDrop::new(self).ptr)
     }
 }
 
-impl<T> DoubleEndedIterator for InitSliceIter<'_, T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back().map(iter_assume_init)
+impl<'brand, T> IntoIterator for Init<'brand, [T]> {
+    type IntoIter = InitSliceIter<'brand, T>;
+    type Item = Init<'brand, T>;
+
+    fn into_iter(self) -> InitSliceIter<'brand, T> {
+        // SAFETY: all elements of an Init<[T]> are initialized
+        unsafe { InitSliceIter::new(ManuallyDrop::new(self).raw.ptr) }
     }
 }
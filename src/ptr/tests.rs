@@ -26,3 +26,179 @@ fn test() {
         assert_eq!(i, unsafe { *x.as_ptr() })
     }
 }
+
+struct DropCounter<'a>(&'a core::cell::Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn drop_counters<'a>(
+    drops: &'a core::cell::Cell<usize>,
+) -> [core::mem::MaybeUninit<DropCounter<'a>>; 5] {
+    core::array::from_fn(|_| core::mem::MaybeUninit::new(DropCounter(drops)))
+}
+
+fn assume_init<T>(buffer: &mut [core::mem::MaybeUninit<T>]) -> super::Init<'_, [T]> {
+    let ptr = core::ptr::slice_from_raw_parts_mut(buffer.as_mut_ptr().cast::<T>(), buffer.len());
+    // SAFETY: the callers only pass initialized buffers, and a MaybeUninit is always valid to pass to Uninit::from_raw
+    unsafe { Uninit::from_raw(ptr).assume_init() }
+}
+
+#[test]
+fn split_init_slice() {
+    let drops = core::cell::Cell::new(0);
+    let mut buffer = drop_counters(&drops);
+    let init = assume_init(&mut buffer);
+
+    // SAFETY: none of the pieces are used in place of the original slice
+    let (left, right) = unsafe { init.split_at(2) };
+    assert_eq!((left.as_ref().len(), right.as_ref().len()), (2, 3));
+    drop(left);
+    assert_eq!(drops.get(), 2);
+
+    // SAFETY: none of the pieces are used in place of the original slice
+    let (first, rest) = unsafe { right.split_first() }.unwrap();
+    drop(first);
+    assert_eq!(drops.get(), 3);
+    // SAFETY: none of the pieces are used in place of the original slice
+    let (last, rest) = unsafe { rest.split_last() }.unwrap();
+    assert_eq!(rest.as_ref().len(), 1);
+    drop(last);
+    assert_eq!(drops.get(), 4);
+    drop(rest);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn init_slice_into_range() {
+    let drops = core::cell::Cell::new(0);
+    let mut buffer = drop_counters(&drops);
+    let init = assume_init(&mut buffer);
+
+    // SAFETY: the range isn't used in place of the original slice
+    let middle = unsafe { init.into_range(1..=2) };
+    assert_eq!(middle.as_ref().len(), 2);
+    assert_eq!(drops.get(), 3);
+    drop(middle);
+    assert_eq!(drops.get(), 5);
+
+    let mut buffer = [core::mem::MaybeUninit::new(0u8); 2];
+    let init = assume_init(&mut buffer);
+    // SAFETY: none of the pieces are used in place of the original slice
+    assert!(unsafe { init.split_at(0).0.split_first() }.is_none());
+}
+
+#[test]
+fn slice_ranges() {
+    let mut buffer = [0u32; 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let mut uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+
+    assert!(uninit.get(3..6).is_none());
+    uninit
+        .get(1..3)
+        .unwrap()
+        .fill(core::mem::MaybeUninit::new(7));
+    uninit.get(..1).unwrap()[0].write(1);
+    for elem in uninit.get(3..).unwrap() {
+        elem.write(2);
+    }
+
+    // SAFETY: every element was initialized above
+    let mut init = unsafe { uninit.assume_init() };
+    assert_eq!(init.get(..), Some(&[1, 7, 7, 2, 2][..]));
+    assert_eq!(init.get(4..=5), None);
+    init.get_mut(1..=2).unwrap().fill(3);
+    assert_eq!(init.get(..3), Some(&[1, 3, 3][..]));
+}
+
+#[test]
+fn slice_accessors() {
+    let mut buffer = [0u8; 3];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let mut uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    for (i, elem) in uninit.as_uninit_slice_mut().iter_mut().enumerate() {
+        elem.write(i as u8);
+    }
+
+    // SAFETY: every element was initialized above
+    let mut init = unsafe { uninit.assume_init() };
+    assert_eq!(init.as_slice(), [0, 1, 2]);
+    init.as_mut_slice().reverse();
+    assert_eq!(init.as_slice(), [2, 1, 0]);
+
+    let mut value = 1u32;
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let mut init = unsafe { Uninit::from_raw(&mut value) }.init(5u32);
+    *init.as_mut() += 1;
+    assert_eq!(*init.as_ref(), 6);
+}
+
+#[test]
+fn init_slice_iter_drops_remainder() {
+    let drops = core::cell::Cell::new(0);
+    let mut buffer = drop_counters(&drops);
+    let mut iter = assume_init(&mut buffer).into_iter();
+
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(drops.get(), 2);
+
+    // SAFETY: the remainder isn't used in place of the original slice
+    let rest = unsafe { iter.into_remainder() };
+    assert_eq!(rest.as_slice().len(), 3);
+    assert_eq!(drops.get(), 2);
+
+    let mut iter = rest.into_iter();
+    drop(iter.next());
+    drop(iter);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn uninit_slice_iter_remainder() {
+    let mut buffer = [0u16; 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    let mut iter = uninit.into_iter();
+
+    iter.next().unwrap().init(1u16).take_ownership();
+    iter.next_back().unwrap().init(5u16).take_ownership();
+    assert_eq!(iter.as_raw_slice().len(), 3);
+
+    // SAFETY: the remainder isn't used in place of the original slice
+    let rest = unsafe { iter.into_remainder() };
+    assert_eq!(rest.len(), 3);
+    rest.init(crate::slice::repeat(3u16)).take_ownership();
+    assert_eq!(buffer, [1, 3, 3, 3, 5]);
+}
+
+#[test]
+fn maybe_uninit_conversions() {
+    let mut value = core::mem::MaybeUninit::<u32>::uninit();
+    // SAFETY: the pointer isn't used in place of any other pointer
+    let init = unsafe { Uninit::from_maybe_uninit(&mut value) }.write(3);
+    assert_eq!(*init.as_ref(), 3);
+
+    let mut buffer = [core::mem::MaybeUninit::<u8>::uninit(); 3];
+    // SAFETY: the pointer isn't used in place of any other pointer
+    let uninit = unsafe { Uninit::from_maybe_uninit_slice(&mut buffer) };
+    assert_eq!(uninit.len(), 3);
+    assert!(!uninit.is_empty());
+    assert_eq!(uninit.write_slice(&[1, 2, 3]).as_slice(), [1, 2, 3]);
+
+    // SAFETY: the pointer isn't used in place of any other pointer
+    let uninit = unsafe { Uninit::from_maybe_uninit_slice(&mut buffer) };
+    assert_eq!(uninit.write_array([4, 5, 6]).as_slice(), [4, 5, 6]);
+}
+
+#[test]
+#[should_panic = "the slices must have the same length"]
+fn write_slice_length_mismatch() {
+    let mut buffer = [core::mem::MaybeUninit::<u8>::uninit(); 3];
+    // SAFETY: the pointer isn't used in place of any other pointer
+    unsafe { Uninit::from_maybe_uninit_slice(&mut buffer) }.write_slice(&[1, 2]);
+}
@@ -0,0 +1,192 @@
+//! initialize data directly inside an [`Rc`] allocation
+//!
+//! [`Rc`] doesn't have a fallible way to allocate, so running out of memory aborts the process
+//! (see [`handle_alloc_error`](alloc::alloc::handle_alloc_error)) instead of returning [`EmplaceError::Alloc`]
+
+use crate::{
+    emplace::Emplace,
+    layout_provider::{DefaultLayoutProvider, LayoutProvider},
+    shared::{self, SharedPtr},
+    slice::{WithLength, WithLengthLayoutProvider},
+    Ctor, EmplaceError,
+};
+
+use alloc::rc::Rc;
+use core::mem::MaybeUninit;
+
+enum RcPtr {}
+
+impl SharedPtr for RcPtr {
+    type Ptr<T: ?Sized> = Rc<T>;
+
+    fn new_uninit_slice<A>(len: usize) -> Rc<[MaybeUninit<A>]> {
+        Rc::new_uninit_slice(len)
+    }
+
+    fn into_raw<T: ?Sized>(ptr: Rc<T>) -> *const T {
+        Rc::into_raw(ptr)
+    }
+
+    unsafe fn from_raw<T: ?Sized>(ptr: *const T) -> Rc<T> {
+        // SAFETY: the caller ensures that the pointer came from into_raw,
+        // and the allocation has the right layout for T
+        unsafe { Rc::from_raw(ptr) }
+    }
+
+    fn is_unique<T: ?Sized>(ptr: &mut Rc<T>) -> bool {
+        Rc::get_mut(ptr).is_some()
+    }
+}
+
+/// initialize a value directly inside an [`Rc`] allocation
+///
+/// # Panics
+///
+/// If the layout provider's layout doesn't match the layout of the initialized value
+///
+/// # Aborts
+///
+/// If the allocation fails, see the [module docs](self)
+pub fn try_rc_with<T, I, L>(init: I) -> Result<Rc<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    shared::try_shared_with::<RcPtr, T, I, L, _>(init, |ptr, init| {
+        ptr.try_init(init)?.take_ownership();
+        Ok(())
+    })
+}
+
+/// initialize a value directly inside an [`Rc`] allocation
+///
+/// # Panics
+///
+/// If the layout provider's layout doesn't match the layout of the initialized value
+pub fn rc_with<T, I, L>(init: I) -> Rc<T>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible>,
+    L: LayoutProvider<T, I>,
+{
+    match try_rc_with::<T, I, L>(init) {
+        Ok(rc) => rc,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a value directly inside an [`Rc`] allocation
+pub fn try_rc<T, I>(init: I) -> Result<Rc<T>, EmplaceError<T::Error>>
+where
+    T: ?Sized + Ctor<I> + DefaultLayoutProvider<I>,
+{
+    try_rc_with::<T, I, T::LayoutProvider>(init)
+}
+
+/// initialize a value directly inside an [`Rc`] allocation
+pub fn rc<T, I>(init: I) -> Rc<T>
+where
+    T: ?Sized + Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+{
+    match try_rc_with::<T, I, T::LayoutProvider>(init) {
+        Ok(rc) => rc,
+        Err(err) => err.handle(),
+    }
+}
+
+/// initialize a slice with `len` elements directly inside an [`Rc`] allocation
+pub fn try_rc_slice<T, I, E>(len: usize, init: I) -> Result<Rc<[T]>, EmplaceError<E>>
+where
+    [T]: Ctor<I, Error = E>,
+{
+    try_rc_with::<[T], WithLength<I>, WithLengthLayoutProvider>(WithLength::from_init(len, init))
+}
+
+/// initialize a slice with `len` elements directly inside an [`Rc`] allocation
+pub fn rc_slice<T, I>(len: usize, init: I) -> Rc<[T]>
+where
+    [T]: Ctor<I, Error = core::convert::Infallible>,
+{
+    match try_rc_slice(len, init) {
+        Ok(rc) => rc,
+        Err(err) => err.handle(),
+    }
+}
+
+impl<T: ?Sized> Emplace for Rc<T> {
+    type Target = T;
+
+    fn try_emplace_with<I, L>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        try_rc_with::<T, I, L>(init)
+    }
+}
+
+/// Extension methods for [`Rc`]
+pub trait RcExt<T: ?Sized>: Sized {
+    /// Drop the value in the [`Rc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    ///
+    /// # Panics
+    ///
+    /// If the layout provider's layout doesn't match the layout of the initialized value
+    fn try_reinit_with<I, L>(self, init: I) -> Result<Rc<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>;
+
+    /// Drop the value in the [`Rc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    ///
+    /// # Panics
+    ///
+    /// If the layout provider's layout doesn't match the layout of the initialized value
+    fn reinit_with<I, L>(self, init: I) -> Rc<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+        L: LayoutProvider<T, I>,
+    {
+        match self.try_reinit_with::<I, L>(init) {
+            Ok(ptr) => ptr,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// Drop the value in the [`Rc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    fn try_reinit<I>(self, init: I) -> Result<Rc<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I> + DefaultLayoutProvider<I>,
+    {
+        self.try_reinit_with::<I, T::LayoutProvider>(init)
+    }
+
+    /// Drop the value in the [`Rc`], and initialize a new value in its place
+    ///
+    /// If there are other pointers to the allocation, or the layout of the new value doesn't
+    /// match the layout of the old value, then the new value is put in a new allocation instead
+    fn reinit<I>(self, init: I) -> Rc<T>
+    where
+        T: Ctor<I, Error = core::convert::Infallible> + DefaultLayoutProvider<I>,
+    {
+        self.reinit_with::<I, T::LayoutProvider>(init)
+    }
+}
+
+impl<T: ?Sized> RcExt<T> for Rc<T> {
+    fn try_reinit_with<I, L>(self, init: I) -> Result<Rc<T>, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+        L: LayoutProvider<T, I>,
+    {
+        shared::try_reinit_with::<RcPtr, T, I, L>(self, init)
+    }
+}
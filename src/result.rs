@@ -0,0 +1,64 @@
+//! initializers for [`Result`]
+
+use crate::{
+    cell::try_init_on_stack,
+    layout_provider::{DefaultLayoutProviderFor, SizedLayoutProvider},
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Initialize a [`Result`] with `Ok`
+///
+/// see [`ok`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct InitOk<I>(I);
+
+/// Initialize a [`Result`] with `Ok`, where the payload is initialized with `init`
+///
+/// This isn't an in-place initializer: the layout of a [`Result`] isn't specified, so there
+/// is no way to set the discriminant of an uninitialized [`Result`] without a payload to move
+/// into it. Instead the payload is initialized on the stack, and then moved into the [`Result`]
+pub const fn ok<I>(init: I) -> InitOk<I> {
+    InitOk(init)
+}
+
+/// Initialize a [`Result`] with `Err`
+///
+/// see [`err`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct InitErr<I>(I);
+
+/// Initialize a [`Result`] with `Err`, where the payload is initialized with `init`
+///
+/// This isn't an in-place initializer: the layout of a [`Result`] isn't specified, so there
+/// is no way to set the discriminant of an uninitialized [`Result`] without a payload to move
+/// into it. Instead the payload is initialized on the stack, and then moved into the [`Result`]
+pub const fn err<I>(init: I) -> InitErr<I> {
+    InitErr(init)
+}
+
+impl<T: Ctor<I>, E, I> Initializer<Result<T, E>> for InitOk<I> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<Result<T, E>>) -> Result<Init<Result<T, E>>, Self::Error> {
+        Ok(ptr.write(Ok(try_init_on_stack(self.0)?)))
+    }
+}
+
+impl<T, E: Ctor<I>, I> Initializer<Result<T, E>> for InitErr<I> {
+    type Error = E::Error;
+
+    fn try_init_into(self, ptr: Uninit<Result<T, E>>) -> Result<Init<Result<T, E>>, Self::Error> {
+        Ok(ptr.write(Err(try_init_on_stack(self.0)?)))
+    }
+}
+
+impl<T, E, I> DefaultLayoutProviderFor<Result<T, E>> for InitOk<I> {
+    type LayoutProvider = SizedLayoutProvider;
+}
+
+impl<T, E, I> DefaultLayoutProviderFor<Result<T, E>> for InitErr<I> {
+    type LayoutProvider = SizedLayoutProvider;
+}
@@ -0,0 +1,12 @@
+#[cfg(feature = "alloc")]
+use super::{err, ok};
+
+#[test]
+#[cfg(feature = "alloc")]
+fn results() {
+    let res = crate::boxed::boxed::<Result<[u32; 16], u8>, _>(ok(()));
+    assert_eq!(*res, Ok([0; 16]));
+
+    let res = crate::boxed::boxed::<Result<[u32; 16], u8>, _>(err(3u8));
+    assert_eq!(*res, Err(3));
+}
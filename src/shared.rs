@@ -0,0 +1,261 @@
+//! the allocation machinery shared between [`Rc`](alloc::rc::Rc) and [`Arc`](alloc::sync::Arc)
+//!
+//! Neither `Rc` nor `Arc` expose a way to allocate an uninitialized `T: ?Sized`.
+//! So instead this allocates an uninitialized slice of a type with the same alignment as `T`
+//! and the same total size as `T`, which has the same layout as `T` would have inside the
+//! reference-counted allocation. Then it is initialized in place, and cast to a `T`.
+
+use core::{
+    alloc::Layout,
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr::NonNull,
+};
+
+use crate::{
+    layout_provider::{LayoutProvider, LayoutProviderError},
+    Ctor, EmplaceError, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A reference-counted pointer which can be emplaced into
+pub(crate) trait SharedPtr {
+    /// The pointer type
+    type Ptr<T: ?Sized>;
+
+    /// Allocate an uninitialized slice, aborting if the allocation fails
+    fn new_uninit_slice<A>(len: usize) -> Self::Ptr<[MaybeUninit<A>]>;
+
+    /// Convert the pointer into a raw pointer, without changing the reference counts
+    fn into_raw<T: ?Sized>(ptr: Self::Ptr<T>) -> *const T;
+
+    /// Convert a raw pointer back into a pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have come from `into_raw`, and point to an allocation
+    /// with the same layout as `Self::Ptr<T>`
+    unsafe fn from_raw<T: ?Sized>(ptr: *const T) -> Self::Ptr<T>;
+
+    /// Returns true if there are no other strong or weak pointers to the allocation
+    fn is_unique<T: ?Sized>(ptr: &mut Self::Ptr<T>) -> bool;
+}
+
+/// An allocation which hasn't been initialized yet
+struct RawShared {
+    ptr: NonNull<u8>,
+    len: usize,
+    free: unsafe fn(NonNull<u8>, usize),
+}
+
+impl Drop for RawShared {
+    fn drop(&mut self) {
+        // SAFETY: this RawShared was created by alloc_aligned with the same
+        // alignment unit as `free`, and hasn't been initialized
+        unsafe { (self.free)(self.ptr, self.len) }
+    }
+}
+
+fn alloc_aligned<P: SharedPtr, A>(len: usize) -> RawShared {
+    let ptr = P::into_raw(P::new_uninit_slice::<A>(len));
+    RawShared {
+        // SAFETY: into_raw never returns a null pointer
+        ptr: unsafe { NonNull::new_unchecked(ptr.cast::<u8>().cast_mut()) },
+        len,
+        free: free_aligned::<P, A>,
+    }
+}
+
+/// # Safety
+///
+/// ptr and len must come from `alloc_aligned::<P, A>`
+unsafe fn free_aligned<P: SharedPtr, A>(ptr: NonNull<u8>, len: usize) {
+    let ptr = core::ptr::slice_from_raw_parts(ptr.cast::<MaybeUninit<A>>().as_ptr(), len);
+    // SAFETY: the caller ensures that this pointer came from `alloc_aligned`,
+    // which created it with `into_raw`. And MaybeUninit doesn't need to be dropped
+    drop(unsafe { P::from_raw(ptr) })
+}
+
+macro_rules! align_units {
+    ($($name:ident = $align:literal,)*) => {
+        $(
+            #[repr(align($align))]
+            #[allow(dead_code)]
+            struct $name(u8);
+        )*
+
+        /// allocate an uninitialized shared allocation which can hold a value with the given layout
+        ///
+        /// returns [`LayoutProviderError::Unsupported`] if the alignment is 2^30 or larger
+        fn alloc_raw<P: SharedPtr>(layout: Layout) -> Result<RawShared, LayoutProviderError> {
+            let len = layout.size() / layout.align();
+            match layout.align() {
+                $($align => Ok(alloc_aligned::<P, $name>(len)),)*
+                _ => Err(LayoutProviderError::Unsupported),
+            }
+        }
+    };
+}
+
+align_units! {
+    Align1 = 1,
+    Align2 = 2,
+    Align4 = 4,
+    Align8 = 8,
+    Align16 = 16,
+    Align32 = 32,
+    Align64 = 64,
+    Align128 = 128,
+    Align256 = 256,
+    Align512 = 512,
+    Align1K = 1024,
+    Align2K = 2048,
+    Align4K = 4096,
+    Align8K = 8192,
+    Align16K = 16384,
+    Align32K = 32768,
+    Align64K = 65536,
+    Align128K = 131072,
+    Align256K = 262144,
+    Align512K = 524288,
+    Align1M = 1048576,
+    Align2M = 2097152,
+    Align4M = 4194304,
+    Align8M = 8388608,
+    Align16M = 16777216,
+    Align32M = 33554432,
+    Align64M = 67108864,
+    Align128M = 134217728,
+    Align256M = 268435456,
+    Align512M = 536870912,
+}
+
+/// panic if the value's layout doesn't match the allocation's layout, after dropping the value
+///
+/// # Safety
+///
+/// ptr must point to an initialized value, which isn't used again if this panics
+pub(crate) unsafe fn check_layout<T: ?Sized>(ptr: NonNull<T>, layout: Layout) {
+    // SAFETY: the caller ensures the value is initialized
+    let value_layout = Layout::for_value(unsafe { ptr.as_ref() });
+    if value_layout != layout {
+        // SAFETY: the value was initialized, and hasn't been dropped yet
+        unsafe { ptr.as_ptr().drop_in_place() };
+
+        #[cold]
+        #[inline(never)]
+        fn handle_layout_mismatch() -> ! {
+            panic!("The layout provider's layout doesn't match the layout of the value");
+        }
+
+        handle_layout_mismatch()
+    }
+}
+
+/// allocate a shared pointer to T, and then initialize it with `init_with`
+///
+/// `init_with` must initialize the pointer if it returns `Ok`
+pub(crate) fn try_shared_with<P, T, I, L, E>(
+    init: I,
+    init_with: impl FnOnce(Uninit<T>, I) -> Result<(), E>,
+) -> Result<P::Ptr<T>, EmplaceError<E>>
+where
+    P: SharedPtr,
+    T: ?Sized,
+    L: LayoutProvider<T, I>,
+{
+    let layout = L::try_layout(&init).map_err(EmplaceError::Layout)?;
+
+    let layout = layout.pad_to_align();
+    let raw = alloc_raw::<P>(layout).map_err(EmplaceError::Layout)?;
+    let ptr = L::cast(raw.ptr.cast(), &init);
+
+    // SAFETY: ptr was just allocated with enough space for T
+    // LayoutProvider L ensures that the layout is correct
+    init_with(unsafe { Uninit::from_raw(ptr.as_ptr()) }, init).map_err(EmplaceError::Init)?;
+
+    // SAFETY: the value was just initialized
+    unsafe { check_layout(ptr, layout) };
+
+    core::mem::forget(raw);
+
+    // SAFETY: The allocation was created by `into_raw`, for a slice with the same size and
+    // alignment as the value, so it has the same layout as an allocation for the value.
+    // And the value has been initialized by `init_with`
+    Ok(unsafe { P::from_raw(ptr.as_ptr()) })
+}
+
+/// A unique allocation whose value has been dropped
+struct DroppedShared<P: SharedPtr, T: ?Sized> {
+    ptr: *const T,
+    ptr_ty: core::marker::PhantomData<P>,
+}
+
+impl<P: SharedPtr, T: ?Sized> Drop for DroppedShared<P, T> {
+    fn drop(&mut self) {
+        // SAFETY: the pointer came from `into_raw`, and ManuallyDrop<T> has the same layout as T,
+        // so this frees the allocation without dropping the value again
+        drop(unsafe { P::from_raw(self.ptr as *const ManuallyDrop<T>) })
+    }
+}
+
+/// drop the value in `ptr`, and initialize a new value in the same allocation if it's unique
+/// and the layouts match. Otherwise initialize the new value in a new allocation
+pub(crate) fn try_reinit_with<P, T, I, L>(
+    mut ptr: P::Ptr<T>,
+    init: I,
+) -> Result<P::Ptr<T>, EmplaceError<T::Error>>
+where
+    P: SharedPtr,
+    T: ?Sized + Ctor<I>,
+    L: LayoutProvider<T, I>,
+{
+    let init_with = |ptr: Uninit<T>, init| {
+        ptr.try_init(init)?.take_ownership();
+        Ok(())
+    };
+
+    if !P::is_unique(&mut ptr) {
+        drop(ptr);
+        return try_shared_with::<P, T, I, L, _>(init, init_with);
+    }
+
+    let raw = P::into_raw(ptr);
+    // SAFETY: the allocation is unique, and holds an initialized value
+    let layout = Layout::for_value(unsafe { &*raw });
+
+    if L::layout(&init).map(|layout| layout.pad_to_align()) != Some(layout) {
+        // SAFETY: raw was just created by into_raw
+        drop(unsafe { P::from_raw(raw) });
+        return try_shared_with::<P, T, I, L, _>(init, init_with);
+    }
+
+    let dropped = DroppedShared::<P, T> {
+        ptr: raw,
+        ptr_ty: core::marker::PhantomData,
+    };
+
+    // SAFETY: the allocation is unique, so no one else can observe the dropped value
+    // and if dropping panics, `dropped` frees the allocation without dropping the value again
+    unsafe { raw.cast_mut().drop_in_place() };
+
+    // SAFETY: into_raw never returns a null pointer
+    let ptr = L::cast(
+        unsafe { NonNull::new_unchecked(raw.cast_mut()) }.cast(),
+        &init,
+    );
+
+    // SAFETY: the allocation is unique and has the same layout as the new value
+    init_with(unsafe { Uninit::from_raw(ptr.as_ptr()) }, init).map_err(EmplaceError::Init)?;
+
+    // SAFETY: the value was just initialized, and if this panics
+    // `dropped` frees the allocation without dropping the value again
+    unsafe { check_layout(ptr, layout) };
+
+    core::mem::forget(dropped);
+
+    // SAFETY: The allocation was created by `into_raw` for a value with the same layout,
+    // and the new value has been initialized
+    Ok(unsafe { P::from_raw(ptr.as_ptr()) })
+}
@@ -0,0 +1,164 @@
+use core::cell::Cell;
+
+use crate::{
+    layout_provider::SizedLayoutProvider,
+    slice::{self, WithLength},
+};
+
+#[repr(align(64))]
+#[derive(Clone, Copy)]
+struct Aligned(u8);
+
+impl crate::Initializer<Aligned> for u8 {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(
+        self,
+        ptr: crate::Uninit<Aligned>,
+    ) -> Result<crate::Init<Aligned>, Self::Error> {
+        Ok(ptr.write(Aligned(self)))
+    }
+}
+
+#[test]
+fn rc() {
+    let rc = crate::rc::rc::<u32, _>(10u32);
+    assert_eq!(*rc, 10);
+
+    let rc = crate::rc::rc::<[u16], _>(WithLength::from_init(4, slice::repeat(3u16)));
+    assert_eq!(*rc, [3; 4]);
+
+    let rc = crate::rc::rc::<[u16], _>(WithLength::from_init(0, slice::repeat(3u16)));
+    assert!(rc.is_empty());
+
+    let rc = crate::rc::rc_with::<Aligned, _, SizedLayoutProvider>(7u8);
+    assert_eq!(rc.0, 7);
+    assert_eq!((&raw const *rc).addr() % 64, 0);
+}
+
+#[test]
+fn arc() {
+    let arc = crate::arc::arc::<u64, _>(10u64);
+    assert_eq!(*arc, 10);
+
+    let arc = crate::arc::try_arc::<[u8], _>(WithLength::init_from_iter(0..5u8));
+    assert_eq!(*arc.ok().unwrap(), [0, 1, 2, 3, 4]);
+
+    let arc = crate::arc::arc_with::<Aligned, _, SizedLayoutProvider>(7u8);
+    assert_eq!(arc.0, 7);
+    assert_eq!((&raw const *arc).addr() % 64, 0);
+}
+
+struct Counted<'a>(&'a Cell<usize>);
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn error_drops_initialized_elements() {
+    let drops = Cell::new(0);
+    let init = WithLength::from_init(
+        4,
+        slice::from_iter((0..3).map(|_| crate::from_fn::with_value(Counted(&drops)))),
+    );
+
+    let result = crate::rc::try_rc_with::<[Counted], _, slice::SliceLayoutProvider>(init);
+    assert!(matches!(
+        result,
+        Err(crate::EmplaceError::Init(
+            slice::InitFromIterError::NotEnoughItems
+        ))
+    ));
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn slices() {
+    let rc = crate::rc::rc_slice::<u32, _>(3, slice::repeat(1u32));
+    assert_eq!(*rc, [1, 1, 1]);
+
+    let arc = crate::arc::try_arc_slice::<u32, _, _>(3, slice::from_iter(1..));
+    assert_eq!(*arc.ok().unwrap(), [1, 2, 3]);
+
+    let data = [1u8, 2, 3];
+    let rc = crate::rc::try_rc::<[u8], _>(slice::copy_from_slice(&data));
+    assert_eq!(*rc.ok().unwrap(), data);
+}
+
+#[test]
+fn unique_arc() {
+    let mut unique =
+        crate::arc::UniqueArc::<[u32]>::new(WithLength::from_init(3, slice::repeat(1u32)));
+    unique[1] = 10;
+    let arc = crate::arc::UniqueArc::freeze(unique);
+    assert_eq!(*arc, [1, 10, 1]);
+}
+
+#[test]
+fn layout_error() {
+    let result = crate::rc::try_rc_slice::<u64, _, _>(usize::MAX, slice::repeat(0u64));
+    assert!(matches!(result, Err(crate::EmplaceError::Layout(_))));
+}
+
+#[test]
+fn unsupported_alignment() {
+    use crate::layout_provider::{LayoutProvider, LayoutProviderError};
+    use core::{alloc::Layout, ptr::NonNull};
+
+    /// A layout provider with an alignment that's too large for shared pointers
+    struct HugeAlign;
+
+    // SAFETY: the layout fits a u32, and the pointer is only cast
+    unsafe impl LayoutProvider<u32, u32> for HugeAlign {
+        fn layout(_: &u32) -> Option<Layout> {
+            Layout::from_size_align(4, 1 << 30).ok()
+        }
+
+        fn cast(ptr: NonNull<()>, _: &u32) -> NonNull<u32> {
+            ptr.cast()
+        }
+
+        fn is_zeroed(_: &u32) -> bool {
+            false
+        }
+    }
+
+    let result = crate::rc::try_rc_with::<u32, u32, HugeAlign>(1);
+    assert_eq!(
+        result.err(),
+        Some(crate::EmplaceError::Layout(
+            LayoutProviderError::Unsupported
+        ))
+    );
+}
+
+#[test]
+fn emplace() {
+    fn make<P: crate::Emplace<Target = [u8]>>() -> P {
+        P::emplace(WithLength::from_init(3, slice::repeat(1u8)))
+    }
+
+    assert_eq!(*make::<alloc::boxed::Box<[u8]>>(), [1, 1, 1]);
+    assert_eq!(*make::<alloc::rc::Rc<[u8]>>(), [1, 1, 1]);
+    assert_eq!(*make::<crate::arc::UniqueArc<[u8]>>(), [1, 1, 1]);
+}
+
+#[test]
+fn reinit() {
+    use crate::rc::RcExt;
+
+    let rc = crate::rc::rc_slice::<u32, _>(3, slice::repeat(1u32));
+    let ptr = alloc::rc::Rc::as_ptr(&rc);
+
+    let rc = rc.reinit(WithLength::from_init(3, slice::repeat(2u32)));
+    assert_eq!(*rc, [2, 2, 2]);
+    assert_eq!(alloc::rc::Rc::as_ptr(&rc), ptr);
+
+    let other = rc.clone();
+    let rc = rc.reinit(WithLength::from_init(3, slice::repeat(3u32)));
+    assert_eq!(*rc, [3, 3, 3]);
+    assert_eq!(*other, [2, 2, 2]);
+}
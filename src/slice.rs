@@ -6,6 +6,9 @@ use crate::{
     Ctor, Initializer,
 };
 
+#[cfg(test)]
+mod tests;
+
 impl<T: Ctor<()>> Initializer<[T]> for () {
     type Error = T::Error;
 
@@ -23,11 +26,11 @@ pub struct CopyFromSlice<'a, T> {
 }
 
 /// Copies the values from the slice directly into the output
-pub const fn copy_from_slice<T: Copy>(slice: &[T]) -> CopyFromSlice<T> {
+pub const fn copy_from_slice<T: Copy>(slice: &[T]) -> CopyFromSlice<'_, T> {
     CopyFromSlice { init: slice }
 }
 
-/// The error type for [`CopyFromSlice`]'s [`Initializer`] impl
+/// The error type for [`CopyFromSlice`] and [`CloneFromSlice`]'s [`Initializer`] impls
 pub struct CopyFromSliceError {
     /// the length of the slice to copy from into
     pub src_len: usize,
@@ -58,6 +61,197 @@ impl<T: Copy> Initializer<[T]> for CopyFromSlice<'_, T> {
     }
 }
 
+/// Clones the values from a slice into the output
+///
+/// see [`clone_from_slice`] for details
+#[derive(Clone, Copy)]
+pub struct CloneFromSlice<'a, T> {
+    pub(crate) init: &'a [T],
+}
+
+/// Clones the values from the slice into the output
+///
+/// If any clone panics, then the already cloned elements are dropped
+pub const fn clone_from_slice<T: Clone>(slice: &[T]) -> CloneFromSlice<'_, T> {
+    CloneFromSlice { init: slice }
+}
+
+impl<T: Clone> Initializer<[T]> for CloneFromSlice<'_, T> {
+    type Error = CopyFromSliceError;
+
+    fn try_init_into(self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        if self.init.len() != ptr.len() {
+            return Err(CopyFromSliceError {
+                src_len: self.init.len(),
+                dest_len: ptr.len(),
+            });
+        }
+
+        let mut writer = SliceWriter::new(ptr);
+
+        for item in self.init {
+            let init = crate::from_fn::with_value(item.clone());
+            // SAFETY: the slices have the same length, so there is an element left for each item
+            let Ok(()) = unsafe { writer.try_init_unchecked(init) };
+        }
+
+        Ok(writer.finish())
+    }
+}
+
+/// Moves the elements of a [`Vec`](alloc::vec::Vec) into the output
+///
+/// see [`move_from_vec`] for details
+#[cfg(feature = "alloc")]
+pub struct MoveFromVec<T> {
+    pub(crate) init: alloc::vec::Vec<T>,
+}
+
+/// Moves the elements of `vec` into the output, and frees the [`Vec`](alloc::vec::Vec)'s buffer
+///
+/// This doesn't clone any elements, so it can be used to move existing data into another allocation
+#[cfg(feature = "alloc")]
+pub const fn move_from_vec<T>(vec: alloc::vec::Vec<T>) -> MoveFromVec<T> {
+    MoveFromVec { init: vec }
+}
+
+/// The error type for [`MoveFromVec`]'s [`Initializer`] impl
+///
+/// The elements are not moved if the lengths don't match, so the [`Vec`](alloc::vec::Vec) is given back
+#[cfg(feature = "alloc")]
+pub struct MoveFromVecError<T> {
+    /// the vec to move from
+    pub vec: alloc::vec::Vec<T>,
+    /// the length of the slice to write into
+    pub dest_len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Initializer<[T]> for MoveFromVec<T> {
+    type Error = MoveFromVecError<T>;
+
+    fn try_init_into(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut vec = self.init;
+        if vec.len() != ptr.len() {
+            return Err(MoveFromVecError {
+                dest_len: ptr.len(),
+                vec,
+            });
+        }
+
+        // SAFETY: the uninit is not aliased so it doesn't overlap with vec
+        // and we just checked that they have the same lengths
+        unsafe {
+            ptr.as_mut_ptr()
+                .cast::<T>()
+                .copy_from_nonoverlapping(vec.as_ptr(), vec.len())
+        };
+        // SAFETY: the elements were moved out of the vec above, so they must not be dropped by it
+        unsafe { vec.set_len(0) };
+        // SAFETY: this was copied by above
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// Moves the elements of a [`Box<[T]>`](alloc::boxed::Box) into the output
+///
+/// see [`move_from_boxed_slice`] for details
+#[cfg(feature = "alloc")]
+pub struct MoveFromBoxedSlice<T> {
+    pub(crate) init: alloc::boxed::Box<[T]>,
+}
+
+/// Moves the elements of `boxed` into the output, and frees the box
+///
+/// This doesn't clone any elements, so it can be used to move existing data into another allocation
+#[cfg(feature = "alloc")]
+pub const fn move_from_boxed_slice<T>(boxed: alloc::boxed::Box<[T]>) -> MoveFromBoxedSlice<T> {
+    MoveFromBoxedSlice { init: boxed }
+}
+
+/// The error type for [`MoveFromBoxedSlice`]'s [`Initializer`] impl
+///
+/// The elements are not moved if the lengths don't match, so the box is given back
+#[cfg(feature = "alloc")]
+pub struct MoveFromBoxedSliceError<T> {
+    /// the box to move from
+    pub boxed: alloc::boxed::Box<[T]>,
+    /// the length of the slice to write into
+    pub dest_len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Initializer<[T]> for MoveFromBoxedSlice<T> {
+    type Error = MoveFromBoxedSliceError<T>;
+
+    fn try_init_into(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let boxed = self.init;
+        if boxed.len() != ptr.len() {
+            return Err(MoveFromBoxedSliceError {
+                dest_len: ptr.len(),
+                boxed,
+            });
+        }
+
+        let len = boxed.len();
+        let boxed = alloc::boxed::Box::into_raw(boxed);
+        // SAFETY: the uninit is not aliased so it doesn't overlap with the box
+        // and we just checked that they have the same lengths
+        unsafe {
+            ptr.as_mut_ptr()
+                .cast::<T>()
+                .copy_from_nonoverlapping(boxed.cast::<T>(), len)
+        };
+        // SAFETY: ManuallyDrop<T> has the same layout as T, so this frees the box
+        // without dropping the elements which were moved out above
+        drop(unsafe { alloc::boxed::Box::from_raw(boxed as *mut [core::mem::ManuallyDrop<T>]) });
+        // SAFETY: this was copied by above
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// Moves the elements of an array into the output
+///
+/// see [`move_from_array`] for details
+pub struct MoveFromArray<T, const N: usize> {
+    pub(crate) init: [T; N],
+}
+
+/// Moves the elements of `array` into the output
+///
+/// This doesn't clone any elements, so it can be used to move existing data into another allocation
+pub const fn move_from_array<T, const N: usize>(array: [T; N]) -> MoveFromArray<T, N> {
+    MoveFromArray { init: array }
+}
+
+/// The error type for [`MoveFromArray`]'s [`Initializer`] impl
+///
+/// The elements are not moved if the lengths don't match, so the array is given back
+pub struct MoveFromArrayError<T, const N: usize> {
+    /// the array to move from
+    pub array: [T; N],
+    /// the length of the slice to write into
+    pub dest_len: usize,
+}
+
+impl<T, const N: usize> Initializer<[T]> for MoveFromArray<T, N> {
+    type Error = MoveFromArrayError<T, N>;
+
+    fn try_init_into(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        if N != ptr.len() {
+            return Err(MoveFromArrayError {
+                array: self.init,
+                dest_len: ptr.len(),
+            });
+        }
+
+        // SAFETY: the slice has exactly N elements, so it has the same layout as [T; N]
+        unsafe { ptr.as_mut_ptr().cast::<[T; N]>().write(self.init) };
+        // SAFETY: this was written by above
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
 /// Repeat an initializer as many times as necessary to initialize the slice
 ///
 /// see [`repeat`] for details
@@ -77,9 +271,67 @@ impl<T: Ctor<I>, I: Clone> Initializer<[T]> for Repeat<I> {
     fn try_init_into(self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
         let mut writer = SliceWriter::new(ptr);
 
-        for _ in 0..writer.remaining_len() {
+        // clone the initializer for all but the last element, so the last element can use the original
+        if let Some(count) = writer.remaining_len().checked_sub(1) {
+            for _ in 0..count {
+                // SAFETY: we repeat this for each element of the slice, except the last
+                unsafe { writer.try_init_unchecked(self.init.clone())? };
+            }
+
+            // SAFETY: the last element is still uninitialized
+            unsafe { writer.try_init_unchecked(self.init)? };
+        }
+
+        Ok(writer.finish())
+    }
+}
+
+/// Set every byte of a byte slice/array to the same value
+///
+/// see [`fill_bytes`] for details
+#[derive(Clone, Copy)]
+pub struct FillBytes {
+    pub(crate) byte: u8,
+}
+
+/// Set every byte of a byte slice/array to `byte`
+///
+/// This behaves like [`repeat`] with `byte`, but always fills the slice with a single `memset`
+pub const fn fill_bytes(byte: u8) -> FillBytes {
+    FillBytes { byte }
+}
+
+impl Initializer<[u8]> for FillBytes {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, ptr: crate::Uninit<[u8]>) -> Result<crate::Init<[u8]>, Self::Error> {
+        Ok(ptr.fill(self.byte))
+    }
+}
+
+/// Initialize each element of the slice/array with the initializer returned by a closure
+///
+/// see [`repeat_with`] for details
+#[derive(Clone, Copy)]
+pub struct RepeatWith<F> {
+    f: F,
+}
+
+/// Initialize each element of the slice/array with the initializer returned by `f`,
+/// which is passed the index of the element being initialized
+pub const fn repeat_with<F>(f: F) -> RepeatWith<F> {
+    RepeatWith { f }
+}
+
+impl<T: Ctor<I>, I, F: FnMut(usize) -> I> Initializer<[T]> for RepeatWith<F> {
+    type Error = T::Error;
+
+    fn try_init_into(mut self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut writer = SliceWriter::new(ptr);
+
+        for i in 0..writer.remaining_len() {
             // SAFETY: we repeat this for each element of the slice
-            unsafe { writer.try_init_unchecked(self.init.clone())? };
+            unsafe { writer.try_init_unchecked((self.f)(i))? };
         }
 
         Ok(writer.finish())
@@ -106,6 +358,8 @@ pub enum InitFromIterError<E> {
     Error(E),
     /// IF the iterator ran out of items before initializing all elements of the slice
     NotEnoughItems,
+    /// If the iterator had items left over after initializing all elements of the slice
+    TooManyItems,
 }
 
 impl<T, I: Iterator> Initializer<[T]> for InitFromIter<I>
@@ -133,6 +387,204 @@ where
     }
 }
 
+/// Initialize the start of the slice with one initializer, and the rest with another
+///
+/// see [`chain`] for details
+#[derive(Clone, Copy)]
+pub struct Chain<A, B> {
+    first: WithLength<A>,
+    rest: B,
+}
+
+/// Initialize the first `first.len` elements of the slice with `first`, and the remaining elements with `rest`
+///
+/// If `rest` fails, then the elements initialized by `first` are dropped
+pub const fn chain<A, B>(first: WithLength<A>, rest: B) -> Chain<A, B> {
+    Chain { first, rest }
+}
+
+/// The error type for [`Chain`], specifies which initializer failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainError<A, B> {
+    /// If the first initializer failed
+    First(A),
+    /// If the second initializer failed
+    Rest(B),
+    /// If the first initializer needs more elements than the slice has
+    FirstTooLong {
+        /// the length of the slice initialized by the first initializer
+        first_len: usize,
+        /// the length of the slice to write into
+        dest_len: usize,
+    },
+}
+
+impl<T, A, B> Initializer<[T]> for Chain<A, B>
+where
+    [T]: Ctor<A> + Ctor<B>,
+{
+    type Error = ChainError<<[T] as Ctor<A>>::Error, <[T] as Ctor<B>>::Error>;
+
+    fn try_init_into(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let first_len = self.first.len;
+        let dest_len = ptr.len();
+        if first_len > dest_len {
+            return Err(ChainError::FirstTooLong {
+                first_len,
+                dest_len,
+            });
+        }
+
+        let data = ptr.as_mut_ptr().cast::<T>();
+        let first = core::ptr::slice_from_raw_parts_mut(data, first_len);
+        // SAFETY: first_len <= dest_len, so the add stays in bounds of the slice
+        let rest = core::ptr::slice_from_raw_parts_mut(
+            unsafe { data.add(first_len) },
+            dest_len - first_len,
+        );
+
+        // SAFETY: This uninit is "re-borrowing" the first part of ptr, so it does not alias.
+        // It inherits all other safety properties from ptr
+        let first = unsafe { crate::Uninit::from_raw(first) }
+            .try_init(self.first.init)
+            .map_err(ChainError::First)?;
+        // SAFETY: This uninit is "re-borrowing" the rest of ptr, so it does not alias
+        // with first. It inherits all other safety properties from ptr
+        //
+        // if this fails, first is dropped, which drops the elements it initialized
+        let rest = unsafe { crate::Uninit::from_raw(rest) }
+            .try_init(self.rest)
+            .map_err(ChainError::Rest)?;
+
+        first.take_ownership();
+        rest.take_ownership();
+        // SAFETY: both parts of ptr were just initialized
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// Copy the elements of a slice iterator into the slice/array
+///
+/// see [`copy_from_slice_iter`] for details
+#[derive(Clone)]
+pub struct CopyFromSliceIter<'a, T> {
+    iter: core::slice::Iter<'a, T>,
+}
+
+/// Copy the elements of a slice iterator into the slice/array
+///
+/// This behaves like [`from_iter`] with `iter.copied()`, but copies all elements at once
+/// instead of writing them one at a time. Any elements left over in the iterator are ignored.
+pub const fn copy_from_slice_iter<T: Copy>(
+    iter: core::slice::Iter<'_, T>,
+) -> CopyFromSliceIter<'_, T> {
+    CopyFromSliceIter { iter }
+}
+
+impl<T: Copy> Initializer<[T]> for CopyFromSliceIter<'_, T> {
+    type Error = InitFromIterError<core::convert::Infallible>;
+
+    fn try_init_into(self, mut ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let src = self.iter.as_slice();
+        let Some(src) = src.get(..ptr.len()) else {
+            return Err(InitFromIterError::NotEnoughItems);
+        };
+
+        // SAFETY: the uninit is not aliased so it doesn't overlap with src
+        // and src has the same length as ptr
+        unsafe {
+            ptr.as_mut_ptr()
+                .cast::<T>()
+                .copy_from_nonoverlapping(src.as_ptr(), src.len())
+        };
+        // SAFETY: this was copied by above
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// Get initializers from the iterator, and initialize the slice/array using them,
+/// checking that the iterator has exactly as many items as the slice
+///
+/// see [`from_exact_iter`] for details
+#[derive(Clone, Copy)]
+pub struct InitFromExactIter<I> {
+    iter: I,
+}
+
+/// Get initializers from the iterator, and initialize the slice/array using them
+///
+/// Unlike [`from_iter`], if the iterator has more items than the slice has elements
+/// then the initialized elements are dropped and [`InitFromIterError::TooManyItems`] is returned
+pub const fn from_exact_iter<I>(iter: I) -> InitFromExactIter<I> {
+    InitFromExactIter { iter }
+}
+
+impl<T, I: Iterator> Initializer<[T]> for InitFromExactIter<I>
+where
+    T: Ctor<I::Item>,
+{
+    type Error = InitFromIterError<T::Error>;
+
+    fn try_init_into(mut self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut writer = SliceWriter::new(ptr);
+
+        for _ in 0..writer.remaining_len() {
+            match self.iter.next() {
+                // SAFETY: we repeat this for each element of the slice
+                Some(init) => unsafe {
+                    writer
+                        .try_init_unchecked(init)
+                        .map_err(InitFromIterError::Error)?
+                },
+                None => return Err(InitFromIterError::NotEnoughItems),
+            }
+        }
+
+        if self.iter.next().is_some() {
+            return Err(InitFromIterError::TooManyItems);
+        }
+
+        Ok(writer.finish())
+    }
+}
+
+/// Get initializers from the iterator, and initialize the rest of the slice/array with a fallback
+///
+/// see [`from_iter_or_default`] for details
+#[derive(Clone, Copy)]
+pub struct InitFromIterOrDefault<I, J> {
+    iter: I,
+    default: J,
+}
+
+/// Get initializers from the iterator, and initialize the slice/array using them.
+/// If the iterator runs out of items, then the remaining elements are initialized with `default`
+pub const fn from_iter_or_default<I, J>(iter: I, default: J) -> InitFromIterOrDefault<I, J> {
+    InitFromIterOrDefault { iter, default }
+}
+
+impl<T, I: Iterator, J: Clone> Initializer<[T]> for InitFromIterOrDefault<I, J>
+where
+    T: Ctor<I::Item> + Ctor<J, Error = <T as Ctor<I::Item>>::Error>,
+{
+    type Error = <T as Ctor<I::Item>>::Error;
+
+    fn try_init_into(mut self, ptr: crate::Uninit<[T]>) -> Result<crate::Init<[T]>, Self::Error> {
+        let mut writer = SliceWriter::new(ptr);
+
+        for _ in 0..writer.remaining_len() {
+            match self.iter.next() {
+                // SAFETY: we repeat this for each element of the slice
+                Some(init) => unsafe { writer.try_init_unchecked(init)? },
+                // SAFETY: we repeat this for each element of the slice
+                None => unsafe { writer.try_init_unchecked(self.default.clone())? },
+            }
+        }
+
+        Ok(writer.finish())
+    }
+}
+
 /// A slice layout provider which can be parameterized on another layout provider
 pub struct SliceLayoutProvider<L = crate::layout_provider::SizedLayoutProvider>(L);
 
@@ -163,7 +615,8 @@ impl<I, T: DefaultLayoutProvider<I>> crate::layout_provider::DefaultLayoutProvid
 // SAFETY:
 // The layout fits [T] with length specified in WithLength,
 // and cast returns a slice with the specified length
-// is_zeroed simply forwards to L
+// is_zeroed and byte_pattern simply forward to L, since the elements of a slice
+// don't have any padding between them
 // L handles the case of cloning I
 unsafe impl<T, I, L: LayoutProvider<T, I>>
     crate::layout_provider::LayoutProvider<[T], WithLength<Repeat<I>>> for SliceLayoutProvider<L>
@@ -179,6 +632,10 @@ unsafe impl<T, I, L: LayoutProvider<T, I>>
     fn is_zeroed(args: &WithLength<Repeat<I>>) -> bool {
         L::is_zeroed(&args.init.init)
     }
+
+    fn byte_pattern(args: &WithLength<Repeat<I>>) -> Option<u8> {
+        L::byte_pattern(&args.init.init)
+    }
 }
 
 impl<I: Iterator, T: DefaultLayoutProvider<I::Item>>
@@ -211,6 +668,145 @@ unsafe impl<T, I: Iterator, L: LayoutProvider<T, I::Item>>
     }
 }
 
+impl<I, F: FnMut(usize) -> I, T: DefaultLayoutProvider<I>>
+    crate::layout_provider::DefaultLayoutProviderFor<[T]> for WithLength<RepeatWith<F>>
+{
+    type LayoutProvider = SliceLayoutProvider<T::LayoutProvider>;
+}
+// SAFETY:
+// The layout fits [T] with length specified in WithLength,
+// and cast returns a slice with the specified length
+// is_zeroed returns false
+unsafe impl<T, I, F: FnMut(usize) -> I, L: LayoutProvider<T, I>>
+    crate::layout_provider::LayoutProvider<[T], WithLength<RepeatWith<F>>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &WithLength<RepeatWith<F>>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<T>(args.len).ok()
+    }
+
+    fn cast(
+        ptr: core::ptr::NonNull<()>,
+        args: &WithLength<RepeatWith<F>>,
+    ) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.len)
+    }
+
+    fn is_zeroed(_args: &WithLength<RepeatWith<F>>) -> bool {
+        false
+    }
+}
+
+impl crate::layout_provider::DefaultLayoutProviderFor<[u8]> for WithLength<FillBytes> {
+    type LayoutProvider = SliceLayoutProvider;
+}
+// SAFETY:
+// The layout fits [u8] with length specified in WithLength,
+// and cast returns a slice with the specified length
+// is_zeroed only returns true if every byte is set to zero
+unsafe impl<L> crate::layout_provider::LayoutProvider<[u8], WithLength<FillBytes>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &WithLength<FillBytes>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<u8>(args.len).ok()
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, args: &WithLength<FillBytes>) -> core::ptr::NonNull<[u8]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.len)
+    }
+
+    fn is_zeroed(args: &WithLength<FillBytes>) -> bool {
+        args.init.byte == 0
+    }
+}
+
+impl<T: Copy> crate::layout_provider::DefaultLayoutProviderFor<[T]>
+    for WithLength<CopyFromSliceIter<'_, T>>
+{
+    type LayoutProvider = SliceLayoutProvider;
+}
+// SAFETY:
+// The layout fits [T] with length specified in WithLength,
+// and cast returns a slice with the specified length
+// is_zeroed returns false
+unsafe impl<T, L> crate::layout_provider::LayoutProvider<[T], WithLength<CopyFromSliceIter<'_, T>>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &WithLength<CopyFromSliceIter<'_, T>>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<T>(args.len).ok()
+    }
+
+    fn cast(
+        ptr: core::ptr::NonNull<()>,
+        args: &WithLength<CopyFromSliceIter<'_, T>>,
+    ) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.len)
+    }
+
+    fn is_zeroed(_args: &WithLength<CopyFromSliceIter<'_, T>>) -> bool {
+        false
+    }
+}
+
+impl<I: Iterator, T: DefaultLayoutProvider<I::Item>>
+    crate::layout_provider::DefaultLayoutProviderFor<[T]> for WithLength<InitFromExactIter<I>>
+{
+    type LayoutProvider = SliceLayoutProvider<T::LayoutProvider>;
+}
+// SAFETY:
+// The layout fits [T] with length specified in WithLength,
+// and cast returns a slice with the specified length
+// is_zeroed returns false
+unsafe impl<T, I: Iterator, L: LayoutProvider<T, I::Item>>
+    crate::layout_provider::LayoutProvider<[T], WithLength<InitFromExactIter<I>>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &WithLength<InitFromExactIter<I>>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<T>(args.len).ok()
+    }
+
+    fn cast(
+        ptr: core::ptr::NonNull<()>,
+        args: &WithLength<InitFromExactIter<I>>,
+    ) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.len)
+    }
+
+    fn is_zeroed(_args: &WithLength<InitFromExactIter<I>>) -> bool {
+        false
+    }
+}
+
+impl<I: Iterator, J, T: DefaultLayoutProvider<I::Item>>
+    crate::layout_provider::DefaultLayoutProviderFor<[T]>
+    for WithLength<InitFromIterOrDefault<I, J>>
+{
+    type LayoutProvider = SliceLayoutProvider<T::LayoutProvider>;
+}
+// SAFETY:
+// The layout fits [T] with length specified in WithLength,
+// and cast returns a slice with the specified length
+// is_zeroed returns false
+unsafe impl<T, I: Iterator, J, L: LayoutProvider<T, I::Item>>
+    crate::layout_provider::LayoutProvider<[T], WithLength<InitFromIterOrDefault<I, J>>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &WithLength<InitFromIterOrDefault<I, J>>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<T>(args.len).ok()
+    }
+
+    fn cast(
+        ptr: core::ptr::NonNull<()>,
+        args: &WithLength<InitFromIterOrDefault<I, J>>,
+    ) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.len)
+    }
+
+    fn is_zeroed(_args: &WithLength<InitFromIterOrDefault<I, J>>) -> bool {
+        false
+    }
+}
+
 impl<T: DefaultLayoutProvider<()>> crate::layout_provider::DefaultLayoutProviderFor<[T]>
     for WithLength
 {
@@ -236,6 +832,9 @@ unsafe impl<T, L: LayoutProvider<T, ()>> crate::layout_provider::LayoutProvider<
     }
 }
 
+impl<T: Copy> crate::layout_provider::DefaultLayoutProviderFor<[T]> for CopyFromSlice<'_, T> {
+    type LayoutProvider = SliceLayoutProvider;
+}
 // SAFETY:
 // arrays are sized, so layout and cast are trivial
 // is_zeroed returns false
@@ -256,6 +855,127 @@ unsafe impl<T, L: LayoutProvider<T, ()>>
     }
 }
 
+impl<T: Clone> crate::layout_provider::DefaultLayoutProviderFor<[T]> for CloneFromSlice<'_, T> {
+    type LayoutProvider = SliceLayoutProvider;
+}
+// SAFETY:
+// The layout fits [T] with the length of the source slice,
+// and cast returns a slice with the same length
+// is_zeroed returns false
+unsafe impl<T, L> crate::layout_provider::LayoutProvider<[T], CloneFromSlice<'_, T>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &CloneFromSlice<T>) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::for_value(args.init))
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, args: &CloneFromSlice<T>) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.init.len())
+    }
+
+    fn is_zeroed(_args: &CloneFromSlice<T>) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::layout_provider::DefaultLayoutProviderFor<[T]> for MoveFromVec<T> {
+    type LayoutProvider = SliceLayoutProvider;
+}
+// SAFETY:
+// The layout fits [T] with the length of the vec,
+// and cast returns a slice with the same length
+// is_zeroed returns false
+#[cfg(feature = "alloc")]
+unsafe impl<T, L> crate::layout_provider::LayoutProvider<[T], MoveFromVec<T>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &MoveFromVec<T>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<T>(args.init.len()).ok()
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, args: &MoveFromVec<T>) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.init.len())
+    }
+
+    fn is_zeroed(_args: &MoveFromVec<T>) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::layout_provider::DefaultLayoutProviderFor<[T]> for MoveFromBoxedSlice<T> {
+    type LayoutProvider = SliceLayoutProvider;
+}
+// SAFETY:
+// The layout fits [T] with the length of the box,
+// and cast returns a slice with the same length
+// is_zeroed returns false
+#[cfg(feature = "alloc")]
+unsafe impl<T, L> crate::layout_provider::LayoutProvider<[T], MoveFromBoxedSlice<T>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(args: &MoveFromBoxedSlice<T>) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::for_value::<[T]>(&args.init))
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, args: &MoveFromBoxedSlice<T>) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.init.len())
+    }
+
+    fn is_zeroed(_args: &MoveFromBoxedSlice<T>) -> bool {
+        false
+    }
+}
+
+impl<T, const N: usize> crate::layout_provider::DefaultLayoutProviderFor<[T]>
+    for MoveFromArray<T, N>
+{
+    type LayoutProvider = SliceLayoutProvider;
+}
+// SAFETY:
+// The layout fits [T] with N elements,
+// and cast returns a slice with N elements
+// is_zeroed returns false
+unsafe impl<T, L, const N: usize> crate::layout_provider::LayoutProvider<[T], MoveFromArray<T, N>>
+    for SliceLayoutProvider<L>
+{
+    fn layout(_args: &MoveFromArray<T, N>) -> Option<core::alloc::Layout> {
+        Some(core::alloc::Layout::new::<[T; N]>())
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, _args: &MoveFromArray<T, N>) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), N)
+    }
+
+    fn is_zeroed(_args: &MoveFromArray<T, N>) -> bool {
+        false
+    }
+}
+
+/// A slice layout provider for [`WithLength`] with any slice initializer
+pub struct WithLengthLayoutProvider;
+
+// SAFETY:
+// The layout fits [T] with length specified in WithLength,
+// and cast returns a slice with the specified length
+// is_zeroed returns false
+unsafe impl<T, I> crate::layout_provider::LayoutProvider<[T], WithLength<I>>
+    for WithLengthLayoutProvider
+{
+    fn layout(args: &WithLength<I>) -> Option<core::alloc::Layout> {
+        core::alloc::Layout::array::<T>(args.len).ok()
+    }
+
+    fn cast(ptr: core::ptr::NonNull<()>, args: &WithLength<I>) -> core::ptr::NonNull<[T]> {
+        core::ptr::NonNull::slice_from_raw_parts(ptr.cast(), args.len)
+    }
+
+    fn is_zeroed(_args: &WithLength<I>) -> bool {
+        false
+    }
+}
+
 impl<T, I> Initializer<[T]> for WithLength<I>
 where
     [T]: Ctor<I>,
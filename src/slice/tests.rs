@@ -0,0 +1,355 @@
+use core::cell::Cell;
+
+use crate::{try_from_fn, Init, Uninit};
+
+use super::{
+    chain, clone_from_slice, copy_from_slice, copy_from_slice_iter, from_exact_iter, from_iter,
+    from_iter_or_default, repeat, repeat_with, ChainError, InitFromIterError, WithLength,
+};
+
+struct DropCounter<'a>(&'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn chain_slices() {
+    let mut buffer = [0u8; 6];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    let init = chain(
+        WithLength::from_init(2, copy_from_slice(&[0xff, 0xfe])),
+        from_iter(1..),
+    );
+    let value = uninit.try_init(init).ok().unwrap();
+    assert_eq!(*value.as_ref(), [0xff, 0xfe, 1, 2, 3, 4]);
+
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    let init = chain(WithLength::from_init(7, repeat(0u8)), repeat(1u8));
+    assert!(matches!(
+        uninit.try_init(init),
+        Err(ChainError::FirstTooLong {
+            first_len: 7,
+            dest_len: 6
+        })
+    ));
+}
+
+#[test]
+fn chain_drops_first_on_error() {
+    let drops = Cell::new(0);
+    let mut buffer = [const { core::mem::MaybeUninit::<DropCounter>::uninit() }; 5];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<DropCounter>(),
+            buffer.len(),
+        ))
+    };
+    let fail = try_from_fn(|_: Uninit<[DropCounter]>| Err::<Init<[DropCounter]>, _>("failed"));
+    let init = chain(
+        WithLength::from_init(
+            3,
+            from_iter(core::iter::repeat_with(|| {
+                crate::from_fn::with_value(DropCounter(&drops))
+            })),
+        ),
+        fail,
+    );
+    assert!(matches!(
+        uninit.try_init(init),
+        Err(ChainError::Rest("failed"))
+    ));
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn repeat_with_index() {
+    let mut buffer = [0u32; 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    let value = uninit.init(repeat_with(|i| i as u32 * 2));
+    assert_eq!(*value.as_ref(), [0, 2, 4, 6, 8]);
+
+    let mut array = [0u32; 4];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut array) };
+    let value = uninit.init(repeat_with(|i| i as u32 + 1));
+    assert_eq!(*value.as_ref(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn from_iter_or_default_fills_remainder() {
+    let mut buffer = [0xaau8; 6];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    let value = uninit.init(from_iter_or_default([1, 2, 3].into_iter(), ()));
+    assert_eq!(*value.as_ref(), [1, 2, 3, 0, 0, 0]);
+
+    let mut array = [0u8; 2];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut array) };
+    let value = uninit.init(from_iter_or_default([1, 2, 3].into_iter(), ()));
+    assert_eq!(*value.as_ref(), [1, 2]);
+}
+
+#[test]
+fn from_exact_iter_checks_length() {
+    let drops = Cell::new(0);
+    let mut buffer = [const { core::mem::MaybeUninit::<DropCounter>::uninit() }; 3];
+    let items = || core::iter::repeat_with(|| crate::from_fn::with_value(DropCounter(&drops)));
+
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<DropCounter>(),
+            buffer.len(),
+        ))
+    };
+    let value = uninit.try_init(from_exact_iter(items().take(4)));
+    assert!(matches!(value, Err(InitFromIterError::TooManyItems)));
+    assert_eq!(drops.get(), 4);
+
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<DropCounter>(),
+            buffer.len(),
+        ))
+    };
+    let value = uninit.try_init(from_exact_iter(items().take(2)));
+    assert!(matches!(value, Err(InitFromIterError::NotEnoughItems)));
+    assert_eq!(drops.get(), 6);
+
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<DropCounter>(),
+            buffer.len(),
+        ))
+    };
+    let value = uninit.try_init(from_exact_iter(items().take(3)));
+    assert_eq!(value.ok().unwrap().as_ref().len(), 3);
+    assert_eq!(drops.get(), 9);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn clone_from_slice_drops_clones_on_panic() {
+    struct PanicOnClone<'a>(&'a Cell<usize>, bool);
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            assert!(!self.1, "clone failed");
+            PanicOnClone(self.0, false)
+        }
+    }
+
+    impl Drop for PanicOnClone<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let src = [
+        PanicOnClone(&drops, false),
+        PanicOnClone(&drops, false),
+        PanicOnClone(&drops, true),
+    ];
+    let mut buffer = [const { core::mem::MaybeUninit::<PanicOnClone>::uninit() }; 3];
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+        let uninit = unsafe {
+            Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+                buffer.as_mut_ptr().cast::<PanicOnClone>(),
+                buffer.len(),
+            ))
+        };
+        let _ = uninit.try_init(clone_from_slice(&src));
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn clone_from_slice_lengths() {
+    let src = [Cell::new(1), Cell::new(2)];
+    let mut buffer = [const { core::mem::MaybeUninit::<Cell<i32>>::uninit() }; 3];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<Cell<i32>>(),
+            2,
+        ))
+    };
+    let value = uninit.try_init(clone_from_slice(&src)).ok().unwrap();
+    assert_eq!(*value.as_ref(), src);
+
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<Cell<i32>>(),
+            3,
+        ))
+    };
+    let err = uninit.try_init(clone_from_slice(&src)).err().unwrap();
+    assert_eq!((err.src_len, err.dest_len), (2, 3));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn move_from_vec() {
+    use alloc::{string::String, vec, vec::Vec};
+
+    let strings: Vec<String> = vec!["a".into(), "b".into()];
+    let bx = crate::boxed::try_boxed::<[String], _>(super::move_from_vec(strings));
+    let bx = bx.ok().unwrap();
+    assert_eq!(*bx, ["a", "b"]);
+
+    let mut buffer = [const { core::mem::MaybeUninit::<String>::uninit() }; 3];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<String>(),
+            buffer.len(),
+        ))
+    };
+    let err = uninit
+        .try_init(super::move_from_vec(vec![String::from("c")]))
+        .err()
+        .unwrap();
+    assert_eq!((&*err.vec, err.dest_len), (&[String::from("c")][..], 3));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn move_from_boxed_slice_and_array() {
+    use alloc::{boxed::Box, string::String};
+
+    let strings: Box<[String]> = Box::new(["a".into(), "b".into()]);
+    let bx = crate::boxed::try_boxed::<[String], _>(super::move_from_boxed_slice(strings));
+    assert_eq!(*bx.ok().unwrap(), ["a", "b"]);
+
+    let strings: [String; 2] = ["c".into(), "d".into()];
+    let bx = crate::boxed::try_boxed::<[String], _>(super::move_from_array(strings));
+    assert_eq!(*bx.ok().unwrap(), ["c", "d"]);
+
+    let strings: [String; 2] = ["e".into(), "f".into()];
+    let bx = crate::boxed::boxed::<[String; 2], _>(super::move_from_array(strings));
+    assert_eq!(*bx, ["e", "f"]);
+
+    let mut buffer = [const { core::mem::MaybeUninit::<String>::uninit() }; 3];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<String>(),
+            buffer.len(),
+        ))
+    };
+    let err = uninit
+        .try_init(super::move_from_array([String::from("g")]))
+        .err()
+        .unwrap();
+    assert_eq!((err.array, err.dest_len), ([String::from("g")], 3));
+}
+
+#[test]
+fn repeat_byte_pattern() {
+    let mut buffer = [0u32; 4];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    assert_eq!(*uninit.init(repeat(u32::MAX)).as_ref(), [u32::MAX; 4]);
+
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    assert_eq!(
+        *uninit.init(repeat(0x0102_0304u32)).as_ref(),
+        [0x0102_0304; 4]
+    );
+
+    let mut bytes = [0i8; 3];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut bytes[..]) };
+    assert_eq!(*uninit.init(repeat(-3i8)).as_ref(), [-3; 3]);
+
+    let mut flags = [false; 3];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut flags) };
+    assert_eq!(*uninit.init(repeat(true)).as_ref(), [true; 3]);
+}
+
+#[test]
+fn copy_from_slice_iter_copies_prefix() {
+    let src = [1u16, 2, 3, 4, 5];
+    let mut iter = src.iter();
+    iter.next();
+
+    let mut buffer = [0u16; 3];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut buffer[..]) };
+    let value = uninit.try_init(copy_from_slice_iter(iter.clone()));
+    assert_eq!(*value.ok().unwrap().as_ref(), [2, 3, 4]);
+
+    let mut array = [0u16; 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(&mut array) };
+    let value = uninit.try_init(copy_from_slice_iter(iter));
+    assert!(matches!(value, Err(InitFromIterError::NotEnoughItems)));
+}
+
+#[test]
+fn repeat_moves_into_last_element() {
+    struct CountClones<'a>(&'a Cell<usize>);
+
+    impl Clone for CountClones<'_> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            CountClones(self.0)
+        }
+    }
+
+    let clones = Cell::new(0);
+    let init = repeat(crate::from_fn::with_value(CountClones(&clones)));
+    let mut buffer = [const { core::mem::MaybeUninit::<CountClones>::uninit() }; 4];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<CountClones>(),
+            buffer.len(),
+        ))
+    };
+    assert_eq!(uninit.init(init).as_ref().len(), 4);
+    assert_eq!(clones.get(), 3);
+}
+
+#[test]
+fn fill_bytes() {
+    let mut buffer = [core::mem::MaybeUninit::<u8>::uninit(); 4];
+    // SAFETY: the pointer isn't used in place of any other pointer
+    let uninit = unsafe { Uninit::from_maybe_uninit_slice(&mut buffer) };
+    assert_eq!(uninit.fill(0xab).as_slice(), [0xab; 4]);
+
+    // SAFETY: the pointer isn't used in place of any other pointer
+    let uninit = unsafe { Uninit::from_maybe_uninit_slice(&mut buffer) };
+    assert_eq!(uninit.init(super::fill_bytes(7)).as_slice(), [7; 4]);
+
+    let mut array = core::mem::MaybeUninit::<[u8; 3]>::uninit();
+    // SAFETY: the pointer isn't used in place of any other pointer
+    let uninit = unsafe { Uninit::from_maybe_uninit(&mut array) };
+    assert_eq!(*uninit.init(super::fill_bytes(1)).as_ref(), [1; 3]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn fill_bytes_boxed() {
+    let bx = crate::boxed::boxed::<[u8], _>(WithLength::from_init(5, super::fill_bytes(0)));
+    assert_eq!(*bx, [0; 5]);
+
+    let bx = crate::boxed::boxed::<[u8; 2], _>(super::fill_bytes(0xff));
+    assert_eq!(*bx, [0xff; 2]);
+}
@@ -1,35 +1,56 @@
-//! A custom writer type which safely initializes slices in place
+//! Custom writer types which safely initialize slices and arrays in place
 
 use core::mem::ManuallyDrop;
 
-use crate::{ptr::UninitSliceIter, Ctor, Init, Uninit};
+use crate::{iter::UninitSliceIter, Ctor, Init, PinCtor, PinnedInit, PinnedUninit, Uninit};
+
+#[cfg(test)]
+mod tests;
 
 /// A type which handles initializing a slice from a arbitrary sequence of initializers
 ///
+/// Elements can be initialized from the front, the back, or both ends toward the middle.
+///
 /// This type will stop after the first initializer to error                    
 pub struct SliceWriter<'brand, T> {
     ptr: Uninit<'brand, [T]>,
     iter: UninitSliceIter<'brand, T>,
     init: usize,
+    init_back: usize,
 }
 
 impl<T> Drop for SliceWriter<'_, T> {
     fn drop(&mut self) {
-        let init =
-            core::ptr::slice_from_raw_parts_mut(self.ptr.as_mut_ptr().cast::<T>(), self.init);
-        // SAFETY: the SliceWriter ensures that self.ptr..self.ptr+self.init is initialized
-        unsafe { init.drop_in_place() };
+        // there's nothing to clean up if T doesn't need to be dropped
+        if !core::mem::needs_drop::<T>() {
+            return;
+        }
+
+        let len = self.ptr.len();
+        let data = self.ptr.as_mut_ptr().cast::<T>();
+        let init = core::ptr::slice_from_raw_parts_mut(data, self.init);
+        let init_back = core::ptr::slice_from_raw_parts_mut(
+            data.wrapping_add(len - self.init_back),
+            self.init_back,
+        );
+        // SAFETY: the SliceWriter ensures that self.ptr..self.ptr+self.init
+        // and self.ptr+len-self.init_back..self.ptr+len are initialized
+        unsafe {
+            init.drop_in_place();
+            init_back.drop_in_place();
+        }
     }
 }
 
 impl<'brand, T> SliceWriter<'brand, T> {
     /// Create an initializer from an uninitialized slice
-    pub fn new(mut uninit: Uninit<'brand, [T]>) -> Self {
+    pub const fn new(mut uninit: Uninit<'brand, [T]>) -> Self {
         Self {
             // SAFETY: ptr is not used while iter is active
             iter: unsafe { uninit.iter_mut().unlink() },
             ptr: uninit,
             init: 0,
+            init_back: 0,
         }
     }
 
@@ -46,9 +67,18 @@ impl<'brand, T> SliceWriter<'brand, T> {
     where
         T: Ctor<Args>,
     {
+        self.try_init_with(args, |u, args| u.try_init(args).map(Init::take_ownership))
+    }
+
+    /// initialize the next element with `init_with`, which must initialize the element if it returns `Ok`
+    fn try_init_with<Args, E>(
+        &mut self,
+        args: Args,
+        init_with: impl FnOnce(Uninit<'brand, T>, Args) -> Result<(), E>,
+    ) -> Result<Result<(), E>, Args> {
         match self.iter.next() {
             Some(u) => {
-                let r = u.try_init(args).map(Init::take_ownership);
+                let r = init_with(u, args);
                 self.init += r.is_ok() as usize;
                 self.iter.reset_if(r.is_err());
                 Ok(r)
@@ -66,18 +96,211 @@ impl<'brand, T> SliceWriter<'brand, T> {
     where
         T: Ctor<Args>,
     {
+        // SAFETY: the caller ensures that there is at least one element left
+        unsafe {
+            self.try_init_unchecked_with(args, |u, args| u.try_init(args).map(Init::take_ownership))
+        }
+    }
+
+    /// initialize the next element with `init_with`, which must initialize the element if it returns `Ok`
+    ///
+    /// # Safety
+    ///
+    /// * `remaining_len` must be non-zero
+    unsafe fn try_init_unchecked_with<Args, E>(
+        &mut self,
+        args: Args,
+        init_with: impl FnOnce(Uninit<'brand, T>, Args) -> Result<(), E>,
+    ) -> Result<(), E> {
         debug_assert!(!self.iter.is_empty());
         // SAFETY: there is at least one element in the iterator
         let u = unsafe { self.iter.next_unchecked() };
-        let r = u.try_init(args).map(Init::take_ownership);
+        let r = init_with(u, args);
         self.init += r.is_ok() as usize;
         self.iter.reset_if(r.is_err());
         r
     }
 
+    /// try to initialize the last uninitialized element with the given arguments
+    ///
+    /// returns Err(args) if there are no more elements to initialize
+    /// returns Ok(_) with the result of the initializer if there was an element to initialize
+    pub fn try_init_back<Args>(&mut self, args: Args) -> Result<Result<(), T::Error>, Args>
+    where
+        T: Ctor<Args>,
+    {
+        self.try_init_back_with(args, |u, args| u.try_init(args).map(Init::take_ownership))
+    }
+
+    /// initialize the last uninitialized element with `init_with`, which must initialize the element if it returns `Ok`
+    fn try_init_back_with<Args, E>(
+        &mut self,
+        args: Args,
+        init_with: impl FnOnce(Uninit<'brand, T>, Args) -> Result<(), E>,
+    ) -> Result<Result<(), E>, Args> {
+        if self.iter.is_empty() {
+            return Err(args);
+        }
+
+        // SAFETY: the writer is not empty
+        Ok(unsafe { self.try_init_back_unchecked_with(args, init_with) })
+    }
+
+    /// initialize the last uninitialized element with the given arguments without checking if there is such an element
+    ///
+    /// # Safety
+    ///
+    /// * `remaining_len` must be non-zero
+    pub unsafe fn try_init_back_unchecked<Args>(&mut self, args: Args) -> Result<(), T::Error>
+    where
+        T: Ctor<Args>,
+    {
+        // SAFETY: the caller ensures that there is at least one element left
+        unsafe {
+            self.try_init_back_unchecked_with(args, |u, args| {
+                u.try_init(args).map(Init::take_ownership)
+            })
+        }
+    }
+
+    /// initialize the last uninitialized element with `init_with`, which must initialize the element if it returns `Ok`
+    ///
+    /// # Safety
+    ///
+    /// * `remaining_len` must be non-zero
+    unsafe fn try_init_back_unchecked_with<Args, E>(
+        &mut self,
+        args: Args,
+        init_with: impl FnOnce(Uninit<'brand, T>, Args) -> Result<(), E>,
+    ) -> Result<(), E> {
+        debug_assert!(!self.iter.is_empty());
+        // SAFETY: there is at least one element in the iterator
+        let u = unsafe { self.iter.next_back_unchecked() };
+        let r = init_with(u, args);
+        self.init_back += r.is_ok() as usize;
+        self.iter.reset_if(r.is_err());
+        r
+    }
+
+    /// a pointer to the next element to initialize from the front
+    ///
+    /// The elements after this pointer may be written to, as long as they are
+    /// within `remaining_len`. See [`assume_init_n`](Self::assume_init_n)
+    pub const fn next_ptr(&mut self) -> *mut T {
+        self.ptr.as_mut_ptr().cast::<T>().wrapping_add(self.init)
+    }
+
+    /// mark the next `n` elements as initialized
+    ///
+    /// # Safety
+    ///
+    /// * the next `n` elements must be initialized
+    /// * `n` must not exceed `remaining_len`
+    unsafe fn advance(&mut self, n: usize) {
+        debug_assert!(n <= self.iter.len());
+        if let Some(last) = n.checked_sub(1) {
+            self.iter.nth(last);
+        }
+        self.init += n;
+    }
+
+    /// mark the next `n` elements as initialized, without writing to them
+    ///
+    /// This is useful when the elements were initialized by other means, like
+    /// a `memcpy` through [`next_ptr`](Self::next_ptr) or a zeroed allocation
+    ///
+    /// # Safety
+    ///
+    /// * `n` must not exceed `remaining_len`
+    /// * the next `n` elements must be initialized
+    pub unsafe fn assume_init_n(&mut self, n: usize) {
+        // SAFETY: the caller ensures that the next n elements are initialized
+        unsafe { self.advance(n) }
+    }
+
+    /// zero out the next `n` elements, and mark them as initialized
+    ///
+    /// If the memory is already known to be zeroed, then [`assume_init_n`](Self::assume_init_n)
+    /// can be used to skip writing the zeros
+    ///
+    /// returns Err(n) without writing anything if there are fewer than `n` elements left
+    pub fn skip_zeroed(&mut self, n: usize) -> Result<(), usize>
+    where
+        T: crate::Zeroable,
+    {
+        if n > self.remaining_len() {
+            return Err(n);
+        }
+
+        // SAFETY: there are at least n elements left, so they are valid for writes
+        unsafe { self.next_ptr().write_bytes(0, n) };
+        // SAFETY: all zero bytes is a valid T
+        unsafe { self.advance(n) };
+        Ok(())
+    }
+
+    /// copy all elements of `slice` into the next elements
+    ///
+    /// returns Err(slice) without writing anything if there isn't enough room for all of `slice`
+    pub fn write_copy_of_slice<'a>(&mut self, slice: &'a [T]) -> Result<(), &'a [T]>
+    where
+        T: Copy,
+    {
+        if slice.len() > self.remaining_len() {
+            return Err(slice);
+        }
+
+        // SAFETY: there are at least slice.len() elements left, so the next elements are
+        // valid for writes, and the writer is unique, so it doesn't overlap with slice
+        unsafe {
+            self.next_ptr()
+                .copy_from_nonoverlapping(slice.as_ptr(), slice.len())
+        };
+        // SAFETY: the elements were just copied into
+        unsafe { self.advance(slice.len()) };
+        Ok(())
+    }
+
+    /// clone all elements of `slice` into the next elements
+    ///
+    /// returns Err(slice) without writing anything if there isn't enough room for all of `slice`
+    pub fn write_clone_of_slice<'a>(&mut self, slice: &'a [T]) -> Result<(), &'a [T]>
+    where
+        T: Clone,
+    {
+        if slice.len() > self.remaining_len() {
+            return Err(slice);
+        }
+
+        for item in slice {
+            let init = crate::from_fn::with_value(item.clone());
+            // SAFETY: there are at least slice.len() elements left
+            let Ok(()) = unsafe { self.try_init_unchecked(init) };
+        }
+
+        Ok(())
+    }
+
+    /// initialize the next elements with the initializers from `iter`
+    ///
+    /// stops once either the iterator is exhausted or all elements are initialized
+    pub fn extend_from_iter<I>(&mut self, iter: I) -> Result<(), T::Error>
+    where
+        I: IntoIterator,
+        T: Ctor<I::Item>,
+    {
+        let mut iter = iter.into_iter();
+        while !self.iter.is_empty() {
+            let Some(init) = iter.next() else { break };
+            // SAFETY: the writer is not empty
+            unsafe { self.try_init_unchecked(init)? };
+        }
+        Ok(())
+    }
+
     /// Check if all elements of the slice are initialized
     pub const fn is_initialized(&self) -> bool {
-        self.init == self.ptr.len()
+        self.init + self.init_back == self.ptr.len()
     }
 
     /// finish the slice writer and extract the initialized slice
@@ -101,3 +324,239 @@ impl<'brand, T> SliceWriter<'brand, T> {
         unsafe { core::ptr::read(&this.ptr).assume_init() }
     }
 }
+
+/// A type which handles initializing a slice of pinned values from a arbitrary sequence of initializers
+///
+/// This type will stop after the first initializer to error
+pub struct PinSliceWriter<'brand, T> {
+    writer: SliceWriter<'brand, T>,
+}
+
+/// initialize the element with a pinned initializer
+fn pin_init_with<T: PinCtor<Args>, Args>(u: Uninit<'_, T>, args: Args) -> Result<(), T::Error> {
+    // SAFETY: the element is part of the slice the PinSliceWriter was created from,
+    // which was already promised to be pinned
+    let init = unsafe { u.into_pinned() }.try_pin_init(args)?;
+    // SAFETY: the writer will either drop the value in place, or
+    // hand ownership of it to a PinnedInit
+    unsafe { init.take_ownership() };
+    Ok(())
+}
+
+impl<'brand, T> PinSliceWriter<'brand, T> {
+    /// Create an initializer from an uninitialized slice
+    pub const fn new(uninit: PinnedUninit<'brand, [T]>) -> Self {
+        Self {
+            writer: SliceWriter::new(uninit.into_inner()),
+        }
+    }
+
+    /// The number of remaining elements to initialize
+    pub fn remaining_len(&self) -> usize {
+        self.writer.remaining_len()
+    }
+
+    /// try to initialize the next element with the given pinned arguments
+    ///
+    /// returns Err(args) if there are no more elements to initialize
+    /// returns Ok(_) with the result of the initializer if there was an element to initialize
+    pub fn try_pin_init<Args>(&mut self, args: Args) -> Result<Result<(), T::Error>, Args>
+    where
+        T: PinCtor<Args>,
+    {
+        self.writer.try_init_with(args, pin_init_with)
+    }
+
+    /// initialize the next element with the given pinned arguments without checking if there is a next element
+    ///
+    /// # Safety
+    ///
+    /// * `remaining_len` must be non-zero
+    pub unsafe fn try_pin_init_unchecked<Args>(&mut self, args: Args) -> Result<(), T::Error>
+    where
+        T: PinCtor<Args>,
+    {
+        // SAFETY: the caller ensures that there is at least one element left
+        unsafe { self.writer.try_init_unchecked_with(args, pin_init_with) }
+    }
+
+    /// try to initialize the last uninitialized element with the given pinned arguments
+    ///
+    /// returns Err(args) if there are no more elements to initialize
+    /// returns Ok(_) with the result of the initializer if there was an element to initialize
+    pub fn try_pin_init_back<Args>(&mut self, args: Args) -> Result<Result<(), T::Error>, Args>
+    where
+        T: PinCtor<Args>,
+    {
+        self.writer.try_init_back_with(args, pin_init_with)
+    }
+
+    /// try to initialize the next element with the given (unpinned) arguments
+    ///
+    /// returns Err(args) if there are no more elements to initialize
+    /// returns Ok(_) with the result of the initializer if there was an element to initialize
+    pub fn try_init<Args>(&mut self, args: Args) -> Result<Result<(), T::Error>, Args>
+    where
+        T: Ctor<Args>,
+    {
+        self.writer.try_init(args)
+    }
+
+    /// initialize the next element with the given (unpinned) arguments without checking if there is a next element
+    ///
+    /// # Safety
+    ///
+    /// * `remaining_len` must be non-zero
+    pub unsafe fn try_init_unchecked<Args>(&mut self, args: Args) -> Result<(), T::Error>
+    where
+        T: Ctor<Args>,
+    {
+        // SAFETY: the caller ensures that there is at least one element left
+        unsafe { self.writer.try_init_unchecked(args) }
+    }
+
+    /// Check if all elements of the slice are initialized
+    pub const fn is_initialized(&self) -> bool {
+        self.writer.is_initialized()
+    }
+
+    /// finish the slice writer and extract the initialized slice
+    pub fn finish(self) -> PinnedInit<'brand, [T]> {
+        // SAFETY: the slice was already promised to be pinned when this writer was created
+        unsafe { self.writer.finish().into_pinned() }
+    }
+
+    /// finish the slice writer and extract the initialized slice
+    /// without checking if the slice is actually finished
+    ///
+    /// # Safety
+    ///
+    /// `is_initialized` must return true
+    pub unsafe fn finish_unchecked(self) -> PinnedInit<'brand, [T]> {
+        // SAFETY: the caller ensures that the slice is initialized, and
+        // the slice was already promised to be pinned when this writer was created
+        unsafe { self.writer.finish_unchecked().into_pinned() }
+    }
+}
+
+/// A type which handles initializing an array from a arbitrary sequence of initializers
+///
+/// Unlike [`SliceWriter`], this produces an [`Init<[T; N]>`] directly
+///
+/// This type will stop after the first initializer to error
+pub struct ArrayWriter<'brand, T, const N: usize> {
+    ptr: Uninit<'brand, [T; N]>,
+    init: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> Drop for ArrayWriter<'_, T, N> {
+    fn drop(&mut self) {
+        // there's nothing to clean up if T doesn't need to be dropped
+        if !core::mem::needs_drop::<T>() {
+            return;
+        }
+
+        let init =
+            core::ptr::slice_from_raw_parts_mut(self.ptr.as_mut_ptr().cast::<T>(), self.init);
+        // SAFETY: the ArrayWriter ensures that self.ptr..self.ptr+self.init is initialized
+        unsafe { init.drop_in_place() };
+    }
+}
+
+impl<'brand, T, const N: usize> ArrayWriter<'brand, T, N> {
+    /// Create an initializer from an uninitialized array
+    pub const fn new(uninit: Uninit<'brand, [T; N]>) -> Self {
+        Self {
+            ptr: uninit,
+            init: 0,
+            end: N,
+        }
+    }
+
+    /// The number of remaining elements to initialize
+    pub const fn remaining_len(&self) -> usize {
+        self.end - self.init
+    }
+
+    /// try to initialize the next element with the given arguments
+    ///
+    /// returns Err(args) if there are no more elements to initialize
+    /// returns Ok(_) with the result of the initializer if there was an element to initialize
+    pub fn try_init<Args>(&mut self, args: Args) -> Result<Result<(), T::Error>, Args>
+    where
+        T: Ctor<Args>,
+    {
+        if self.remaining_len() == 0 {
+            return Err(args);
+        }
+
+        // SAFETY: there is at least one element left
+        Ok(unsafe { self.try_init_unchecked(args) })
+    }
+
+    /// initialize the next element with the given arguments without checking if there is a next element
+    ///
+    /// # Safety
+    ///
+    /// * `remaining_len` must be non-zero
+    pub unsafe fn try_init_unchecked<Args>(&mut self, args: Args) -> Result<(), T::Error>
+    where
+        T: Ctor<Args>,
+    {
+        debug_assert!(self.remaining_len() != 0);
+        // SAFETY: init < end <= N, so the element is in bounds of the array, and it is uninitialized
+        let u = unsafe { Uninit::from_raw(self.ptr.as_mut_ptr().cast::<T>().add(self.init)) };
+        match u.try_init(args) {
+            Ok(init) => {
+                init.take_ownership();
+                self.init += 1;
+                Ok(())
+            }
+            Err(err) => {
+                self.end = self.init;
+                Err(err)
+            }
+        }
+    }
+
+    /// Check if all elements of the array are initialized
+    pub const fn is_initialized(&self) -> bool {
+        self.init == N
+    }
+
+    /// finish the array writer and extract the initialized array
+    pub fn finish(self) -> Init<'brand, [T; N]> {
+        assert!(self.is_initialized());
+        // SAFETY: we checked that the array is initialized
+        unsafe { self.finish_unchecked() }
+    }
+
+    /// initialize all remaining elements with clones of `args`, then extract the initialized array
+    pub fn finish_with_default<Args: Clone>(
+        mut self,
+        args: Args,
+    ) -> Result<Init<'brand, [T; N]>, T::Error>
+    where
+        T: Ctor<Args>,
+    {
+        while self.remaining_len() != 0 {
+            // SAFETY: there is at least one element left
+            unsafe { self.try_init_unchecked(args.clone())? };
+        }
+        Ok(self.finish())
+    }
+
+    /// finish the array writer and extract the initialized array
+    /// without checking if the array is actually finished
+    ///
+    /// # Safety
+    ///
+    /// `is_initialized` must return true
+    pub unsafe fn finish_unchecked(self) -> Init<'brand, [T; N]> {
+        debug_assert!(self.is_initialized());
+        let this = ManuallyDrop::new(self);
+        // SAFETY: the caller ensures that the array is initialized
+        unsafe { core::ptr::read(&this.ptr).assume_init() }
+    }
+}
@@ -0,0 +1,129 @@
+use core::mem::MaybeUninit;
+
+use crate::Uninit;
+
+use super::SliceWriter;
+
+#[test]
+fn bulk_writes() {
+    let mut buffer = [MaybeUninit::<u32>::uninit(); 8];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<u32>(),
+            buffer.len(),
+        ))
+    };
+    let mut writer = SliceWriter::new(uninit);
+
+    assert_eq!(writer.write_copy_of_slice(&[1, 2, 3]), Ok(()));
+    assert_eq!(writer.write_clone_of_slice(&[4, 5]), Ok(()));
+    assert_eq!(writer.write_copy_of_slice(&[0; 4]), Err(&[0; 4][..]));
+    assert_eq!(writer.remaining_len(), 3);
+
+    let Ok(()) = writer.extend_from_iter(6..);
+    assert_eq!(*writer.finish().as_ref(), [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn double_ended() {
+    let mut buffer = [MaybeUninit::<u32>::uninit(); 5];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<u32>(),
+            buffer.len(),
+        ))
+    };
+    let mut writer = SliceWriter::new(uninit);
+
+    assert!(matches!(writer.try_init_back(5), Ok(Ok(()))));
+    assert!(matches!(writer.try_init(1), Ok(Ok(()))));
+    assert!(matches!(writer.try_init_back(4), Ok(Ok(()))));
+    assert!(matches!(writer.try_init(2), Ok(Ok(()))));
+    assert!(!writer.is_initialized());
+    assert!(matches!(writer.try_init_back(3), Ok(Ok(()))));
+    assert!(matches!(writer.try_init_back(0), Err(0)));
+    assert_eq!(*writer.finish().as_ref(), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn double_ended_drops_both_ends() {
+    use core::cell::Cell;
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut buffer = [const { MaybeUninit::<DropCounter>::uninit() }; 5];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<DropCounter>(),
+            buffer.len(),
+        ))
+    };
+    let mut writer = SliceWriter::new(uninit);
+    let init = || crate::from_fn::with_value(DropCounter(&drops));
+
+    assert!(writer.try_init(init()).is_ok());
+    assert!(writer.try_init_back(init()).is_ok());
+    assert!(writer.try_init_back(init()).is_ok());
+    drop(writer);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn externally_initialized() {
+    let mut buffer = [MaybeUninit::<u16>::uninit(); 6];
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe {
+        Uninit::from_raw(core::ptr::slice_from_raw_parts_mut(
+            buffer.as_mut_ptr().cast::<u16>(),
+            buffer.len(),
+        ))
+    };
+    let mut writer = SliceWriter::new(uninit);
+
+    assert!(writer.try_init(7).is_ok());
+    // SAFETY: there are 5 elements left, so the next 2 are valid for writes
+    unsafe {
+        let ptr = writer.next_ptr();
+        ptr.write(8);
+        ptr.add(1).write(9);
+        writer.assume_init_n(2);
+    }
+    assert_eq!(writer.skip_zeroed(4), Err(4));
+    assert_eq!(writer.skip_zeroed(3), Ok(()));
+    assert_eq!(*writer.finish().as_ref(), [7, 8, 9, 0, 0, 0]);
+}
+
+#[test]
+fn array_writer() {
+    use super::ArrayWriter;
+
+    let mut buffer = MaybeUninit::<[u8; 4]>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(buffer.as_mut_ptr()) };
+    let mut writer = ArrayWriter::new(uninit);
+
+    assert!(matches!(writer.try_init(1), Ok(Ok(()))));
+    assert!(matches!(writer.try_init(2), Ok(Ok(()))));
+    assert_eq!(writer.remaining_len(), 2);
+    let Ok(value) = writer.finish_with_default(9);
+    assert_eq!(*value.as_ref(), [1, 2, 9, 9]);
+
+    let mut buffer = MaybeUninit::<[core::num::NonZero<u8>; 3]>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(buffer.as_mut_ptr()) };
+    let mut writer = ArrayWriter::new(uninit);
+    assert!(matches!(writer.try_init(1u8), Ok(Ok(()))));
+    assert!(matches!(writer.try_init(0u8), Ok(Err(crate::ZeroError))));
+    assert_eq!(writer.remaining_len(), 0);
+    assert!(!writer.is_initialized());
+}
@@ -0,0 +1,349 @@
+//! initializers for string slices
+
+use core::{alloc::Layout, fmt, mem::MaybeUninit, ptr::NonNull};
+
+use crate::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider},
+    project::Project,
+    slice::CopyFromSliceError,
+    Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Copies the string directly into the output
+///
+/// see [`copy_from_str`] for details
+#[derive(Clone, Copy)]
+pub struct CopyFromStr<'a> {
+    init: &'a str,
+}
+
+/// Copies the string directly into the output
+pub const fn copy_from_str(s: &str) -> CopyFromStr<'_> {
+    CopyFromStr { init: s }
+}
+
+/// Copies each of the strings one after another into the output
+///
+/// see [`concat`] for details
+#[derive(Clone, Copy)]
+pub struct Concat<'a> {
+    parts: &'a [&'a str],
+}
+
+/// Copies each of the strings one after another into the output
+pub const fn concat<'a>(parts: &'a [&'a str]) -> Concat<'a> {
+    Concat { parts }
+}
+
+/// Copies the string into the output `count` times
+///
+/// see [`repeat`] for details
+#[derive(Clone, Copy)]
+pub struct Repeat<'a> {
+    init: &'a str,
+    count: usize,
+}
+
+/// Copies the string into the output `count` times
+pub const fn repeat(s: &str, count: usize) -> Repeat<'_> {
+    Repeat { init: s, count }
+}
+
+/// Writes the character into the output `count` times
+///
+/// see [`repeat_char`] for details
+#[derive(Clone, Copy)]
+pub struct RepeatChar {
+    init: char,
+    count: usize,
+}
+
+/// Writes the character into the output `count` times
+///
+/// This is useful for building padding or separator strings, see [`repeat`] to repeat a whole string
+pub const fn repeat_char(c: char, count: usize) -> RepeatChar {
+    RepeatChar { init: c, count }
+}
+
+/// Writes formatted text directly into the output
+///
+/// see [`format`] for details
+#[derive(Clone, Copy)]
+pub struct Format<'a> {
+    args: fmt::Arguments<'a>,
+    len: Option<usize>,
+}
+
+/// Writes formatted text directly into the output
+///
+/// The arguments are formatted once up front to measure the length of the output,
+/// so the output can be allocated with exactly the right size. Then they are formatted
+/// again directly into the output.
+///
+/// Initializing fails with [`fmt::Error`] if a formatting trait implementation fails,
+/// or if the output doesn't have the same length both times it is formatted
+pub fn format(args: fmt::Arguments<'_>) -> Format<'_> {
+    let len = match args.as_str() {
+        Some(s) => Some(s.len()),
+        None => {
+            let mut counter = ByteCounter(0);
+            fmt::write(&mut counter, args).ok().map(|()| counter.0)
+        }
+    };
+
+    Format { args, len }
+}
+
+/// Format the arguments into a [`Box<str>`](alloc::boxed::Box) with exactly the right size
+///
+/// see [`format`] and [`format_boxed!`](crate::format_boxed) for details
+///
+/// # Panics
+///
+/// if a formatting trait implementation returns an error
+#[cfg(feature = "alloc")]
+pub fn format_boxed(args: fmt::Arguments<'_>) -> alloc::boxed::Box<str> {
+    match crate::boxed::try_boxed::<str, _>(format(args)) {
+        Ok(bx) => bx,
+        Err(crate::EmplaceError::Init(fmt::Error) | crate::EmplaceError::Layout(_)) => {
+            panic!("a formatting trait implementation returned an error")
+        }
+        Err(crate::EmplaceError::Alloc(layout)) => alloc::alloc::handle_alloc_error(layout),
+    }
+}
+
+/// A [`fmt::Write`] which only counts the number of bytes written
+struct ByteCounter(usize);
+
+impl fmt::Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.checked_add(s.len()).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// A [`fmt::Write`] which writes into uninitialized bytes, and fails if they run out
+struct UninitWriter<'a> {
+    bytes: &'a mut [MaybeUninit<u8>],
+    written: usize,
+}
+
+impl fmt::Write for UninitWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dest = self
+            .bytes
+            .get_mut(self.written..)
+            .and_then(|rest| rest.get_mut(..s.len()))
+            .ok_or(fmt::Error)?;
+
+        // SAFETY: dest has exactly s.len() bytes, and it can't overlap with s
+        // since dest is borrowed mutably
+        unsafe {
+            dest.as_mut_ptr()
+                .cast::<u8>()
+                .copy_from_nonoverlapping(s.as_ptr(), s.len())
+        };
+        self.written += s.len();
+        Ok(())
+    }
+}
+
+impl Initializer<str> for Format<'_> {
+    type Error = fmt::Error;
+
+    fn try_init_into(self, mut ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        let len = ptr.len();
+        if self.len != Some(len) {
+            return Err(fmt::Error);
+        }
+
+        let mut bytes = ptr.project();
+        let mut writer = UninitWriter {
+            bytes: bytes.as_uninit_slice_mut(),
+            written: 0,
+        };
+        fmt::write(&mut writer, self.args)?;
+        if writer.written != len {
+            return Err(fmt::Error);
+        }
+
+        // SAFETY: every byte was written to by formatting, which only writes valid strings
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// The error type for [`Init::from_utf8`]
+///
+/// The bytes are given back, since they were not valid UTF-8
+pub struct FromUtf8Error<'brand> {
+    /// the bytes which failed to convert
+    pub bytes: Init<'brand, [u8]>,
+    /// why the bytes are not valid UTF-8
+    pub error: core::str::Utf8Error,
+}
+
+impl Project for str {
+    type Projection<'a> = Uninit<'a, [u8]>;
+
+    fn project<'a>(uninit: &'a mut Uninit<'_, Self>) -> Self::Projection<'a> {
+        // SAFETY: str has the same layout as [u8], and the new pointer
+        // borrows from uninit so it doesn't alias
+        unsafe { Uninit::from_raw(uninit.as_mut_ptr() as *mut [u8]) }
+    }
+}
+
+/// Copy all `parts` into `ptr`, which must be exactly as long as all of the parts combined
+fn write_parts<'a, 'brand>(
+    mut ptr: Uninit<'brand, str>,
+    len: Option<usize>,
+    parts: impl Iterator<Item = &'a str>,
+) -> Result<Init<'brand, str>, CopyFromSliceError> {
+    let dest_len = ptr.len();
+
+    if len != Some(dest_len) {
+        return Err(CopyFromSliceError {
+            src_len: len.unwrap_or(usize::MAX),
+            dest_len,
+        });
+    }
+
+    let mut dest = ptr.as_mut_ptr().cast::<u8>();
+    for part in parts {
+        // SAFETY: the parts have exactly the same total length as the destination,
+        // and the destination isn't aliased so it can't overlap with the parts
+        unsafe {
+            dest.copy_from_nonoverlapping(part.as_ptr(), part.len());
+            dest = dest.add(part.len());
+        }
+    }
+
+    // SAFETY: the destination was filled with the bytes of valid strings,
+    // which is a valid string
+    Ok(unsafe { ptr.assume_init() })
+}
+
+impl Initializer<str> for CopyFromStr<'_> {
+    type Error = CopyFromSliceError;
+
+    fn try_init_into(self, ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        write_parts(ptr, Some(self.init.len()), core::iter::once(self.init))
+    }
+}
+
+impl Initializer<str> for Concat<'_> {
+    type Error = CopyFromSliceError;
+
+    fn try_init_into(self, ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        write_parts(ptr, self.len(), self.parts.iter().copied())
+    }
+}
+
+impl Initializer<str> for Repeat<'_> {
+    type Error = CopyFromSliceError;
+
+    fn try_init_into(self, ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        let parts = core::iter::repeat_n(self.init, self.count);
+        write_parts(ptr, self.len(), parts)
+    }
+}
+
+impl Initializer<str> for RepeatChar {
+    type Error = CopyFromSliceError;
+
+    fn try_init_into(self, mut ptr: Uninit<str>) -> Result<Init<str>, Self::Error> {
+        let len = self.len();
+        if len != Some(ptr.len()) {
+            return Err(CopyFromSliceError {
+                src_len: len.unwrap_or(usize::MAX),
+                dest_len: ptr.len(),
+            });
+        }
+
+        let mut bytes = ptr.project();
+        if self.init.is_ascii() {
+            bytes.write_bytes(self.init as u8);
+        } else {
+            let mut buf = [0; 4];
+            let pattern = self.init.encode_utf8(&mut buf).as_bytes();
+            let dest = bytes.as_uninit_slice_mut();
+            for chunk in dest.chunks_exact_mut(pattern.len()) {
+                // SAFETY: each chunk has exactly as many bytes as the pattern,
+                // and it can't overlap with the pattern since it is on the stack
+                unsafe {
+                    chunk
+                        .as_mut_ptr()
+                        .cast::<u8>()
+                        .copy_from_nonoverlapping(pattern.as_ptr(), pattern.len())
+                };
+            }
+        }
+
+        // SAFETY: the destination's length is a multiple of the character's length
+        // (checked above), and every chunk was filled with the character's encoding,
+        // which is a valid string
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl RepeatChar {
+    const fn len(&self) -> Option<usize> {
+        self.init.len_utf8().checked_mul(self.count)
+    }
+}
+
+impl Concat<'_> {
+    fn len(&self) -> Option<usize> {
+        self.parts
+            .iter()
+            .try_fold(0usize, |len, part| len.checked_add(part.len()))
+    }
+}
+
+impl Repeat<'_> {
+    const fn len(&self) -> Option<usize> {
+        self.init.len().checked_mul(self.count)
+    }
+}
+
+/// A layout provider for string initializers
+pub struct StrLayoutProvider;
+
+macro_rules! str_layout_provider {
+    ($($init:ty => |$args:ident| $len:expr,)*) => {$(
+        impl DefaultLayoutProviderFor<str> for $init {
+            type LayoutProvider = StrLayoutProvider;
+        }
+
+        // SAFETY:
+        // The layout fits a str with the length of the initializer's output,
+        // and cast returns a str with the same length
+        // is_zeroed returns false
+        unsafe impl LayoutProvider<str, $init> for StrLayoutProvider {
+            fn layout($args: &$init) -> Option<Layout> {
+                Layout::array::<u8>($len?).ok()
+            }
+
+            fn cast(ptr: NonNull<()>, $args: &$init) -> NonNull<str> {
+                let len = $len.unwrap_or(0);
+                let ptr = NonNull::slice_from_raw_parts(ptr.cast::<u8>(), len);
+                // SAFETY: the pointer is non-null
+                unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut str) }
+            }
+
+            fn is_zeroed(_args: &$init) -> bool {
+                false
+            }
+        }
+    )*};
+}
+
+str_layout_provider! {
+    CopyFromStr<'_> => |args| Some(args.init.len()),
+    Concat<'_> => |args| args.len(),
+    Repeat<'_> => |args| args.len(),
+    RepeatChar => |args| args.len(),
+    Format<'_> => |args| args.len,
+}
@@ -0,0 +1,115 @@
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_str() {
+    let bx = crate::boxed::try_boxed::<str, _>(super::copy_from_str("hello"));
+    assert_eq!(&*bx.ok().unwrap(), "hello");
+
+    let rc = crate::rc::try_rc::<str, _>(super::concat(&["hello", " ", "world"]));
+    assert_eq!(&*rc.ok().unwrap(), "hello world");
+
+    let bx = crate::boxed::try_boxed::<str, _>(super::repeat("ab", 3));
+    assert_eq!(&*bx.ok().unwrap(), "ababab");
+
+    let bx = crate::boxed::try_boxed::<str, _>(super::repeat("ab", usize::MAX));
+    assert!(matches!(bx, Err(crate::EmplaceError::Layout(_))));
+}
+
+#[test]
+fn uninit_str_bytes() {
+    use crate::{Init, Uninit};
+
+    let mut buffer = [0u8; 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw,
+    // and the bytes are never read as a str before being initialized
+    let mut uninit = unsafe { Uninit::from_raw(core::str::from_utf8_mut(&mut buffer).unwrap()) };
+    assert_eq!(uninit.len(), 5);
+    assert!(!uninit.is_empty());
+
+    {
+        let bytes = uninit.project().write_slice(b"hello");
+        let init = Init::from_utf8(bytes).ok().unwrap();
+        assert_eq!(init.as_ref(), "hello");
+    }
+
+    let bytes = uninit
+        .into_bytes()
+        .write_slice(&[b'a', 0xff, b'b', b'c', b'd']);
+    let err = Init::<str>::from_utf8(bytes).err().unwrap();
+    assert_eq!(err.error.valid_up_to(), 1);
+    assert_eq!(err.bytes.as_slice(), [b'a', 0xff, b'b', b'c', b'd']);
+
+    let mut buffer = [0u8; 3];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(core::str::from_utf8_mut(&mut buffer).unwrap()) };
+    let init = uninit.try_init(super::copy_from_str("abc")).ok().unwrap();
+    assert_eq!(init.as_ref(), "abc");
+}
+
+#[test]
+fn format() {
+    use core::{cell::Cell, fmt};
+
+    let mut buffer = [0u8; 6];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(core::str::from_utf8_mut(&mut buffer).unwrap()) };
+    let init = uninit
+        .try_init(super::format(format_args!("{}-{:03}", "ab", 7)))
+        .ok()
+        .unwrap();
+    assert_eq!(init.as_ref(), "ab-007");
+
+    let mut buffer = [0u8; 6];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(core::str::from_utf8_mut(&mut buffer).unwrap()) };
+    assert!(uninit
+        .try_init(super::format(format_args!("short")))
+        .is_err());
+
+    // writes a longer string each time it is formatted
+    struct Growing(Cell<usize>);
+
+    impl fmt::Display for Growing {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            (0..self.0.get()).try_for_each(|_| f.write_str("x"))
+        }
+    }
+
+    let growing = Growing(Cell::new(0));
+    let mut buffer = [0u8; 1];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(core::str::from_utf8_mut(&mut buffer).unwrap()) };
+    // measures one byte, but writes two
+    assert!(uninit
+        .try_init(super::format(format_args!("{growing}")))
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn format_boxed() {
+    let bx = crate::format_boxed!("{}, {}!", "hello", "world");
+    assert_eq!(&*bx, "hello, world!");
+
+    let bx = crate::format_boxed!("no arguments");
+    assert_eq!(&*bx, "no arguments");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat_char() {
+    let bx = crate::boxed::try_boxed::<str, _>(super::repeat_char('-', 5));
+    assert_eq!(&*bx.ok().unwrap(), "-----");
+
+    let bx = crate::boxed::try_boxed::<str, _>(super::repeat_char('é', 3));
+    assert_eq!(&*bx.ok().unwrap(), "ééé");
+
+    let bx = crate::boxed::try_boxed::<str, _>(super::repeat_char('🦀', 0));
+    assert_eq!(&*bx.ok().unwrap(), "");
+
+    let mut buffer = [0u8; 5];
+    // SAFETY: a reference is always safe to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(core::str::from_utf8_mut(&mut buffer).unwrap()) };
+    let err = uninit.try_init(super::repeat_char('é', 2)).err().unwrap();
+    assert_eq!((err.src_len, err.dest_len), (4, 5));
+}
@@ -0,0 +1,51 @@
+//! initializers for [`Mutex`] and [`RwLock`]
+
+use std::sync::{Mutex, RwLock};
+
+use crate::{
+    cell::try_init_on_stack,
+    layout_provider::{DefaultLayoutProviderFor, SizedLayoutProvider},
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Initialize the value inside of a lock
+///
+/// see [`in_lock`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct InLock<I>(I);
+
+/// Initialize the value inside of a [`Mutex`] or [`RwLock`] with `init`
+///
+/// This isn't an in-place initializer: the layout of the standard library's locks isn't
+/// specified, so there is no way to get a pointer to the value inside an uninitialized lock.
+/// Instead the value is initialized on the stack, and then moved into the new lock
+pub const fn in_lock<I>(init: I) -> InLock<I> {
+    InLock(init)
+}
+
+impl<T: Ctor<I>, I> Initializer<Mutex<T>> for InLock<I> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<Mutex<T>>) -> Result<Init<Mutex<T>>, Self::Error> {
+        Ok(ptr.write(Mutex::new(try_init_on_stack(self.0)?)))
+    }
+}
+
+impl<T: Ctor<I>, I> Initializer<RwLock<T>> for InLock<I> {
+    type Error = T::Error;
+
+    fn try_init_into(self, ptr: Uninit<RwLock<T>>) -> Result<Init<RwLock<T>>, Self::Error> {
+        Ok(ptr.write(RwLock::new(try_init_on_stack(self.0)?)))
+    }
+}
+
+impl<T, I> DefaultLayoutProviderFor<Mutex<T>> for InLock<I> {
+    type LayoutProvider = SizedLayoutProvider;
+}
+
+impl<T, I> DefaultLayoutProviderFor<RwLock<T>> for InLock<I> {
+    type LayoutProvider = SizedLayoutProvider;
+}
@@ -0,0 +1,13 @@
+use std::sync::{Mutex, RwLock};
+
+use super::in_lock;
+
+#[test]
+fn locks() {
+    let mutex = crate::boxed::boxed::<Mutex<[u32; 4]>, _>(in_lock(()));
+    mutex.lock().unwrap()[1] = 3;
+    assert_eq!(*mutex.lock().unwrap(), [0, 3, 0, 0]);
+
+    let lock = crate::boxed::boxed::<RwLock<u8>, _>(in_lock(7u8));
+    assert_eq!(*lock.read().unwrap(), 7);
+}
@@ -0,0 +1,477 @@
+//! dynamically sized types made of a sized header followed by a dynamically sized tail
+
+use core::{
+    alloc::{Layout, LayoutError},
+    ffi::CStr,
+    ptr::NonNull,
+};
+
+#[cfg(feature = "alloc")]
+use crate::{
+    layout_provider::DefaultLayoutProvider,
+    slice::{InitFromIterError, WithLength},
+    EmplaceError,
+};
+#[cfg(feature = "alloc")]
+use core::convert::Infallible;
+
+use crate::{
+    __private_macros::repr_c_layout,
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider, LayoutProviderError},
+    slice::{CopyFromSliceError, SliceLayoutProvider},
+    str::StrLayoutProvider,
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A header followed by a dynamically sized tail, in a single allocation
+///
+/// The tail may be any type with a [`LayoutProvider`], like a slice, a `str`, or another
+/// `HeaderTail`, which allows building objects with several sections in a single allocation
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct HeaderTail<H, T: ?Sized> {
+    header: H,
+    tail: T,
+}
+
+/// A header followed by a slice, in a single allocation
+pub type SliceTail<H, T> = HeaderTail<H, [T]>;
+
+/// A header followed by a string, in a single allocation
+pub type StrTail<H> = HeaderTail<H, str>;
+
+impl<H, T: ?Sized> HeaderTail<H, T> {
+    /// The header
+    pub const fn header(&self) -> &H {
+        &self.header
+    }
+
+    /// The header
+    pub const fn header_mut(&mut self) -> &mut H {
+        &mut self.header
+    }
+
+    /// The tail after the header
+    pub const fn tail(&self) -> &T {
+        &self.tail
+    }
+
+    /// The tail after the header
+    pub const fn tail_mut(&mut self) -> &mut T {
+        &mut self.tail
+    }
+}
+
+impl<H, T> SliceTail<H, T> {
+    /// The number of elements in the slice after the header
+    pub const fn len(&self) -> usize {
+        self.tail.len()
+    }
+
+    /// Returns true if the slice after the header is empty
+    pub const fn is_empty(&self) -> bool {
+        self.tail.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<H, T> SliceTail<H, T> {
+    /// initialize a header and a slice directly on the heap
+    pub fn try_boxed<HI, TI, HE, TE>(
+        header: HI,
+        tail: TI,
+    ) -> Result<alloc::boxed::Box<Self>, EmplaceError<WithHeaderError<HE, TE>>>
+    where
+        H: Ctor<HI, Error = HE>,
+        [T]: Ctor<TI, Error = TE>,
+        TI: DefaultLayoutProviderFor<[T]>,
+    {
+        crate::boxed::try_boxed(with_header(header, tail))
+    }
+
+    /// initialize a header and a slice directly on the heap
+    pub fn boxed<HI, TI>(header: HI, tail: TI) -> alloc::boxed::Box<Self>
+    where
+        H: Ctor<HI, Error = Infallible>,
+        [T]: Ctor<TI, Error = Infallible>,
+        TI: DefaultLayoutProviderFor<[T]>,
+    {
+        match Self::try_boxed(header, tail) {
+            Ok(bx) => bx,
+            Err(EmplaceError::Init(WithHeaderError::Header(err) | WithHeaderError::Tail(err))) => {
+                match err {}
+            }
+            Err(EmplaceError::Layout(err)) => EmplaceError::<Infallible>::Layout(err).handle(),
+            Err(EmplaceError::Alloc(layout)) => EmplaceError::<Infallible>::Alloc(layout).handle(),
+        }
+    }
+
+    /// initialize a header, and a slice from each of the initializers in `iter`, directly on the heap
+    pub fn try_from_iter<HI, I, HE, TE>(
+        header: HI,
+        iter: I,
+    ) -> Result<alloc::boxed::Box<Self>, EmplaceError<WithHeaderError<HE, InitFromIterError<TE>>>>
+    where
+        H: Ctor<HI, Error = HE>,
+        I: IntoIterator<IntoIter: ExactSizeIterator>,
+        T: Ctor<I::Item, Error = TE> + DefaultLayoutProvider<I::Item>,
+    {
+        Self::try_boxed(header, WithLength::init_from_iter(iter.into_iter()))
+    }
+
+    /// initialize a header, and copy the slice, directly on the heap
+    pub fn copy_from_slice<HI>(header: HI, slice: &[T]) -> alloc::boxed::Box<Self>
+    where
+        H: Ctor<HI, Error = Infallible>,
+        T: Copy,
+    {
+        match Self::try_boxed(header, crate::slice::copy_from_slice(slice)) {
+            Ok(bx) => bx,
+            Err(EmplaceError::Init(WithHeaderError::Header(err))) => match err {},
+            Err(EmplaceError::Init(WithHeaderError::Tail(_))) => {
+                unreachable!("the tail's length is the length of the slice")
+            }
+            Err(EmplaceError::Layout(err)) => EmplaceError::<Infallible>::Layout(err).handle(),
+            Err(EmplaceError::Alloc(layout)) => EmplaceError::<Infallible>::Alloc(layout).handle(),
+        }
+    }
+}
+
+/// A header followed by a NUL-terminated C string, in a single allocation
+#[repr(C)]
+pub struct CStrTail<H> {
+    header: H,
+    /// always a valid C string, with exactly one NUL at the end
+    tail: [u8],
+}
+
+impl<H> CStrTail<H> {
+    /// The header
+    pub const fn header(&self) -> &H {
+        &self.header
+    }
+
+    /// The header
+    pub const fn header_mut(&mut self) -> &mut H {
+        &mut self.header
+    }
+
+    /// The C string after the header
+    pub const fn c_str(&self) -> &CStr {
+        // SAFETY: the tail is always a valid C string
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.tail) }
+    }
+}
+
+/// Copies a C string into a [`CStr`], or the tail of a [`CStrTail`]
+///
+/// see [`copy_from_cstr`] and [`append_nul`] for details
+#[derive(Clone, Copy)]
+pub struct CStrInit<'a> {
+    bytes: &'a [u8],
+    has_nul: bool,
+}
+
+/// Copies the C string, including its NUL terminator
+pub const fn copy_from_cstr(s: &CStr) -> CStrInit<'_> {
+    CStrInit {
+        bytes: s.to_bytes_with_nul(),
+        has_nul: true,
+    }
+}
+
+/// Copies the bytes, and appends a NUL terminator
+///
+/// Initialization fails if the bytes contain a NUL
+pub const fn append_nul(bytes: &[u8]) -> CStrInit<'_> {
+    CStrInit {
+        bytes,
+        has_nul: false,
+    }
+}
+
+impl CStrInit<'_> {
+    /// The length of the C string, including the NUL terminator
+    const fn len(&self) -> Option<usize> {
+        if self.has_nul {
+            Some(self.bytes.len())
+        } else {
+            self.bytes.len().checked_add(1)
+        }
+    }
+
+    /// Check that the bytes don't contain a NUL, unless they came from a C string
+    fn check(&self) -> Result<(), InteriorNulError> {
+        match self.bytes.iter().position(|&b| b == 0) {
+            Some(position) if !self.has_nul => Err(InteriorNulError { position }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Write the C string, including the NUL terminator, into `dest`
+    ///
+    /// # Safety
+    ///
+    /// `dest` must be valid for writes of `self.len()` bytes, and must not overlap with the bytes
+    const unsafe fn write_to(&self, dest: *mut u8) {
+        // SAFETY: the caller ensures that dest has room for the bytes, and a NUL terminator
+        // if they don't have one, and that dest doesn't overlap with the bytes
+        unsafe {
+            dest.copy_from_nonoverlapping(self.bytes.as_ptr(), self.bytes.len());
+            if !self.has_nul {
+                dest.add(self.bytes.len()).write(0);
+            }
+        }
+    }
+}
+
+impl Initializer<CStr> for CStrInit<'_> {
+    type Error = CStrInitError;
+
+    fn try_init_into(self, mut ptr: Uninit<CStr>) -> Result<Init<CStr>, Self::Error> {
+        let ptr = ptr.as_mut_ptr();
+        let dest_len = (ptr as *mut [u8]).len();
+
+        if self.len() != Some(dest_len) {
+            return Err(CStrInitError::Length(CopyFromSliceError {
+                src_len: self.len().unwrap_or(usize::MAX),
+                dest_len,
+            }));
+        }
+
+        self.check().map_err(CStrInitError::InteriorNul)?;
+
+        // SAFETY: the pointer has room for exactly the C string, and
+        // it isn't aliased, so it doesn't overlap with the bytes
+        unsafe { self.write_to(ptr.cast()) };
+
+        // SAFETY: the pointer was initialized with a valid C string
+        Ok(unsafe { Uninit::from_raw(ptr).assume_init() })
+    }
+}
+
+/// The error type for [`CStrInit`]'s [`Initializer<CStr>`] impl
+pub enum CStrInitError {
+    /// If the bytes contained a NUL before the end
+    InteriorNul(InteriorNulError),
+    /// If the C string doesn't have the same length as the output
+    Length(CopyFromSliceError),
+}
+
+/// A layout provider for [`CStr`] initializers
+pub struct CStrLayoutProvider;
+
+impl DefaultLayoutProviderFor<CStr> for CStrInit<'_> {
+    type LayoutProvider = CStrLayoutProvider;
+}
+
+// SAFETY:
+// The layout fits the C string, including its NUL terminator,
+// and cast returns a pointer with the same length as the C string
+// is_zeroed returns false
+unsafe impl LayoutProvider<CStr, CStrInit<'_>> for CStrLayoutProvider {
+    fn layout(args: &CStrInit<'_>) -> Option<Layout> {
+        Layout::array::<u8>(args.len()?).ok()
+    }
+
+    fn cast(ptr: NonNull<()>, args: &CStrInit<'_>) -> NonNull<CStr> {
+        let len = args.len().unwrap_or(0);
+        let ptr = NonNull::slice_from_raw_parts(ptr.cast::<u8>(), len);
+        // SAFETY: the pointer is non-null
+        unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut CStr) }
+    }
+
+    fn is_zeroed(_args: &CStrInit<'_>) -> bool {
+        false
+    }
+}
+
+/// The error type for [`CStrInit`], the bytes contained a NUL before the end
+#[derive(Clone, Copy)]
+pub struct InteriorNulError {
+    /// The position of the NUL in the bytes
+    pub position: usize,
+}
+
+/// Initialize a header and a tail
+///
+/// see [`with_header`] for details
+#[derive(Clone, Copy)]
+pub struct WithHeader<H, T> {
+    header: H,
+    tail: T,
+}
+
+/// Initialize the header with `header`, and the tail with `tail`
+pub const fn with_header<H, T>(header: H, tail: T) -> WithHeader<H, T> {
+    WithHeader { header, tail }
+}
+
+impl<H, T> WithHeader<H, T> {
+    /// The initializer for the header
+    pub const fn header(&self) -> &H {
+        &self.header
+    }
+
+    /// The initializer for the tail
+    pub const fn tail(&self) -> &T {
+        &self.tail
+    }
+
+    /// Split into the initializers for the header and the tail
+    pub fn into_parts(self) -> (H, T) {
+        (self.header, self.tail)
+    }
+}
+
+/// The error type for [`WithHeader`], specifies which part failed to initialize
+#[derive(Clone, Copy)]
+pub enum WithHeaderError<H, T> {
+    /// If the header failed to initialize
+    Header(H),
+    /// If the tail failed to initialize
+    Tail(T),
+}
+
+impl<H, T: ?Sized, HI, TI> Initializer<HeaderTail<H, T>> for WithHeader<HI, TI>
+where
+    H: Ctor<HI>,
+    T: Ctor<TI>,
+{
+    type Error = WithHeaderError<H::Error, T::Error>;
+
+    fn try_init_into(
+        self,
+        mut ptr: Uninit<HeaderTail<H, T>>,
+    ) -> Result<Init<HeaderTail<H, T>>, Self::Error> {
+        let ptr = ptr.as_mut_ptr();
+
+        // SAFETY: the header is in bounds of the allocation, and isn't aliased
+        let header = unsafe { Uninit::from_raw(&raw mut (*ptr).header) };
+        // SAFETY: the tail is in bounds of the allocation, and isn't aliased
+        let tail = unsafe { Uninit::from_raw(&raw mut (*ptr).tail) };
+
+        let header = header
+            .try_init(self.header)
+            .map_err(WithHeaderError::Header)?;
+        let tail = tail.try_init(self.tail).map_err(WithHeaderError::Tail)?;
+
+        header.take_ownership();
+        tail.take_ownership();
+
+        // SAFETY: both the header and the tail were initialized
+        Ok(unsafe { Uninit::from_raw(ptr).assume_init() })
+    }
+}
+
+impl<H, HI> Initializer<CStrTail<H>> for WithHeader<HI, CStrInit<'_>>
+where
+    H: Ctor<HI>,
+{
+    type Error = WithHeaderError<H::Error, CStrInitError>;
+
+    fn try_init_into(self, mut ptr: Uninit<CStrTail<H>>) -> Result<Init<CStrTail<H>>, Self::Error> {
+        let ptr = ptr.as_mut_ptr();
+
+        // SAFETY: the tail is in bounds of the allocation, and isn't aliased
+        let tail = unsafe { Uninit::from_raw(&raw mut (*ptr).tail as *mut CStr) };
+        // checks the length and NULs of the C string before writing anything
+        let tail = tail.try_init(self.tail).map_err(WithHeaderError::Tail)?;
+
+        // SAFETY: the header is in bounds of the allocation, and isn't aliased
+        let header = unsafe { Uninit::from_raw(&raw mut (*ptr).header) };
+        let header = header
+            .try_init(self.header)
+            .map_err(WithHeaderError::Header)?;
+
+        header.take_ownership();
+        tail.take_ownership();
+
+        // SAFETY: both the header and the tail were initialized,
+        // and the tail is a valid C string
+        Ok(unsafe { Uninit::from_raw(ptr).assume_init() })
+    }
+}
+
+/// A layout provider for [`CStrTail`]
+pub struct CStrTailLayoutProvider;
+
+impl<H, HI> DefaultLayoutProviderFor<CStrTail<H>> for WithHeader<HI, CStrInit<'_>> {
+    type LayoutProvider = CStrTailLayoutProvider;
+}
+
+// SAFETY:
+// The layout is the header followed by the C string, padded to the alignment of the header
+// like a `#[repr(C)]` struct, and cast returns a pointer with the same length as the C string
+// is_zeroed returns false
+unsafe impl<H, HI> LayoutProvider<CStrTail<H>, WithHeader<HI, CStrInit<'_>>>
+    for CStrTailLayoutProvider
+{
+    fn layout(args: &WithHeader<HI, CStrInit<'_>>) -> Option<Layout> {
+        header_layout::<H>(Layout::array::<u8>(args.tail.len()?)).ok()
+    }
+
+    fn cast(ptr: NonNull<()>, args: &WithHeader<HI, CStrInit<'_>>) -> NonNull<CStrTail<H>> {
+        let len = args.tail.len().unwrap_or(0);
+        let ptr = NonNull::slice_from_raw_parts(ptr.cast::<u8>(), len);
+        // SAFETY: the pointer is non-null
+        unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut CStrTail<H>) }
+    }
+
+    fn is_zeroed(_args: &WithHeader<HI, CStrInit<'_>>) -> bool {
+        false
+    }
+}
+
+/// A layout provider for [`HeaderTail`], which uses `L` to get the layout of the tail
+pub struct HeaderTailLayoutProvider<L>(L);
+
+/// A layout provider for [`SliceTail`], which uses `L` to get the length of the tail
+pub type SliceTailLayoutProvider<L = SliceLayoutProvider> = HeaderTailLayoutProvider<L>;
+
+/// A layout provider for [`StrTail`], which uses `L` to get the length of the tail
+pub type StrTailLayoutProvider<L = StrLayoutProvider> = HeaderTailLayoutProvider<L>;
+
+impl<H, T: ?Sized, HI, TI> DefaultLayoutProviderFor<HeaderTail<H, T>> for WithHeader<HI, TI>
+where
+    TI: DefaultLayoutProviderFor<T>,
+{
+    type LayoutProvider = HeaderTailLayoutProvider<TI::LayoutProvider>;
+}
+
+/// The layout of a `H` header followed by the tail, padded like a `#[repr(C)]` struct
+fn header_layout<H>(tail: Result<Layout, LayoutError>) -> Result<Layout, LayoutProviderError> {
+    repr_c_layout(&[Layout::new::<H>()], tail, 1)
+}
+
+// SAFETY:
+// The layout is the header followed by the tail, padded to the alignment of the struct
+// like a `#[repr(C)]` struct. cast returns a pointer with the same metadata as the tail,
+// and the pointer metadata of a struct is the pointer metadata of its last field
+// is_zeroed returns false
+unsafe impl<H, T: ?Sized, HI, TI, L> LayoutProvider<HeaderTail<H, T>, WithHeader<HI, TI>>
+    for HeaderTailLayoutProvider<L>
+where
+    L: LayoutProvider<T, TI>,
+{
+    fn layout(args: &WithHeader<HI, TI>) -> Option<Layout> {
+        <Self as LayoutProvider<HeaderTail<H, T>, _>>::try_layout(args).ok()
+    }
+
+    fn try_layout(args: &WithHeader<HI, TI>) -> Result<Layout, LayoutProviderError> {
+        header_layout::<H>(Ok(L::try_layout(&args.tail)?))
+    }
+
+    fn cast(ptr: NonNull<()>, args: &WithHeader<HI, TI>) -> NonNull<HeaderTail<H, T>> {
+        let ptr = L::cast(ptr, &args.tail);
+        // SAFETY: the pointer is non-null
+        unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut HeaderTail<H, T>) }
+    }
+
+    fn is_zeroed(_args: &WithHeader<HI, TI>) -> bool {
+        false
+    }
+}
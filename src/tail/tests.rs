@@ -0,0 +1,186 @@
+#[test]
+#[cfg(feature = "alloc")]
+fn str_tail() {
+    use crate::str;
+
+    let bx = crate::boxed::try_boxed::<super::StrTail<u64>, _>(super::with_header(
+        10u64,
+        str::concat(&["hello", " ", "world"]),
+    ));
+    let mut bx = bx.ok().unwrap();
+    assert_eq!(*bx.header(), 10);
+    assert_eq!(bx.tail(), "hello world");
+    assert_eq!(size_of_val(&*bx), 24);
+
+    bx.tail_mut().make_ascii_uppercase();
+    assert_eq!(bx.tail(), "HELLO WORLD");
+
+    let rc = crate::rc::try_rc::<super::StrTail<u8>, _>(super::with_header(
+        1u8,
+        str::copy_from_str("hi"),
+    ));
+    assert_eq!(rc.ok().unwrap().tail(), "hi");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn c_str_tail() {
+    let bx = crate::boxed::try_boxed::<super::CStrTail<u16>, _>(super::with_header(
+        3u16,
+        super::append_nul(b"name"),
+    ));
+    let bx = bx.ok().unwrap();
+    assert_eq!(*bx.header(), 3);
+    assert_eq!(bx.c_str(), c"name");
+
+    let bx = crate::boxed::try_boxed::<super::CStrTail<u16>, _>(super::with_header(
+        3u16,
+        super::copy_from_cstr(c"id"),
+    ));
+    assert_eq!(bx.ok().unwrap().c_str(), c"id");
+
+    let bx = crate::boxed::try_boxed::<super::CStrTail<u16>, _>(super::with_header(
+        3u16,
+        super::append_nul(b"a\0b"),
+    ));
+    assert!(matches!(
+        bx,
+        Err(crate::EmplaceError::Init(super::WithHeaderError::Tail(
+            super::CStrInitError::InteriorNul(super::InteriorNulError { position: 1 })
+        )))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn c_str_tail_length_mismatch() {
+    use crate::boxed::UninitBox;
+
+    let bx =
+        UninitBox::<super::CStrTail<u64>>::new(&super::with_header(0u64, super::append_nul(b"")));
+
+    let bytes = [b'a'; 4096];
+    let result = bx.try_init(super::with_header(0u64, super::append_nul(&bytes)));
+    assert!(matches!(
+        result,
+        Err((
+            _,
+            super::WithHeaderError::Tail(super::CStrInitError::Length(
+                crate::slice::CopyFromSliceError {
+                    src_len: 4097,
+                    dest_len: 1
+                }
+            ))
+        ))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_c_str() {
+    use core::ffi::CStr;
+
+    let bx = crate::boxed::try_boxed::<CStr, _>(super::append_nul(b"name"));
+    assert_eq!(&*bx.ok().unwrap(), c"name");
+
+    let bx = crate::boxed::try_boxed::<CStr, _>(super::copy_from_cstr(c"id"));
+    assert_eq!(&*bx.ok().unwrap(), c"id");
+
+    let bx = crate::boxed::try_boxed::<CStr, _>(super::append_nul(b""));
+    assert_eq!(&*bx.ok().unwrap(), c"");
+
+    let bx = crate::boxed::try_boxed::<CStr, _>(super::append_nul(b"ab\0"));
+    assert!(matches!(
+        bx,
+        Err(crate::EmplaceError::Init(
+            super::CStrInitError::InteriorNul(super::InteriorNulError { position: 2 })
+        ))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn c_str_length_mismatch() {
+    use core::ffi::CStr;
+
+    use crate::boxed::UninitBox;
+
+    let bx = UninitBox::<CStr>::new(&super::copy_from_cstr(c"id"));
+    let Err((bx, err)) = bx.try_init(super::copy_from_cstr(c"name")) else {
+        panic!("initialized a C string into a shorter allocation")
+    };
+    assert!(matches!(
+        err,
+        super::CStrInitError::Length(crate::slice::CopyFromSliceError {
+            src_len: 5,
+            dest_len: 3
+        })
+    ));
+    let bx = bx.try_init(super::append_nul(b"ab"));
+    assert_eq!(&*bx.ok().unwrap(), c"ab");
+
+    let bx = UninitBox::<super::CStrTail<u8>>::new(&super::with_header(
+        0u8,
+        super::copy_from_cstr(c"name"),
+    ));
+    let result = bx.try_init(super::with_header(0u8, super::copy_from_cstr(c"id")));
+    assert!(matches!(
+        result,
+        Err((
+            _,
+            super::WithHeaderError::Tail(super::CStrInitError::Length(_))
+        ))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn slice_tail() {
+    use crate::slice::{self, WithLength};
+
+    let init = super::with_header(1u8, WithLength::from_init(3, slice::repeat(5u32)));
+    let bx = crate::boxed::try_boxed::<super::SliceTail<u8, u32>, _>(init);
+    let mut bx = bx.ok().unwrap();
+    assert_eq!(*bx.header(), 1);
+    assert_eq!(bx.tail(), [5, 5, 5]);
+    assert_eq!(bx.len(), 3);
+    assert_eq!(size_of_val(&*bx), 16);
+
+    bx.tail_mut()[0] = 4;
+    *bx.header_mut() = 2;
+
+    let init = super::with_header(2u8, WithLength::init_from_iter([4u32, 5, 5].into_iter()));
+    let other = crate::boxed::try_boxed::<super::SliceTail<u8, u32>, _>(init);
+    assert_eq!(bx, other.ok().unwrap());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn slice_tail_constructors() {
+    use super::SliceTail;
+
+    let a = SliceTail::<u8, u32>::copy_from_slice(1u8, &[1, 2, 3]);
+    let b = SliceTail::<u8, u32>::try_from_iter(1u8, [1u32, 2, 3]);
+    assert_eq!(a, b.ok().unwrap());
+
+    let c = SliceTail::<u8, u32>::boxed(
+        1u8,
+        crate::slice::WithLength::from_init(3, crate::slice::repeat(1u32)),
+    );
+    assert_eq!(c.tail(), [1, 1, 1]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn nested_header_tail() {
+    use super::{with_header, HeaderTail, StrTail};
+
+    let init = with_header(1u64, with_header(2u8, crate::str::copy_from_str("name")));
+    let bx = crate::boxed::try_boxed::<HeaderTail<u64, StrTail<u8>>, _>(init);
+    let bx = bx.ok().unwrap();
+    assert_eq!(*bx.header(), 1);
+    assert_eq!(*bx.tail().header(), 2);
+    assert_eq!(bx.tail().tail(), "name");
+    assert_eq!(size_of_val(&*bx), 16);
+    assert_eq!(size_of_val(bx.tail()), 5);
+}
@@ -0,0 +1,134 @@
+//! thin owning pointers, which store their metadata inside the allocation
+//!
+//! These are a single pointer wide, so they are useful for data structures
+//! which hold a lot of pointers to dynamically sized data
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use alloc::alloc::{alloc, dealloc};
+
+use crate::{layout_provider::LayoutBuilder, EmplaceError};
+
+use erasable::{erasable_thin, raw_thin};
+
+mod any;
+#[cfg(target_has_atomic = "ptr")]
+mod arc;
+#[cfg(target_has_atomic = "ptr")]
+mod atomic;
+mod boxed_slice;
+mod dyn_;
+mod erasable;
+mod fn_;
+#[cfg(feature = "serde")]
+mod serde;
+mod tagged;
+mod vec;
+
+#[cfg(test)]
+mod tests;
+
+pub use any::ThinAny;
+#[cfg(target_has_atomic = "ptr")]
+pub use arc::ThinArc;
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::AtomicThin;
+pub use boxed_slice::ThinBoxedSlice;
+pub use dyn_::ThinDyn;
+pub use erasable::{Erasable, ErasablePtr, Erased};
+pub use fn_::{ThinFn, ThinFnMut, ThinFnOnce};
+pub use tagged::TaggedThin;
+pub use vec::ThinVec;
+
+/// Implement the formatting, comparison and hashing traits for a thin pointer by forwarding
+/// them to its [`Deref::Target`](core::ops::Deref::Target)
+macro_rules! forward_traits {
+    (impl[$($generics:tt)*] $type:ty => $target:ty) => {
+        impl<$($generics)*> core::fmt::Debug for $type
+        where
+            $target: core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<$($generics)*> core::fmt::Display for $type
+        where
+            $target: core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<$($generics)*> PartialEq for $type
+        where
+            $target: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<$($generics)*> Eq for $type where $target: Eq {}
+
+        impl<$($generics)*> PartialOrd for $type
+        where
+            $target: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+
+        impl<$($generics)*> Ord for $type
+        where
+            $target: Ord,
+        {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<$($generics)*> core::hash::Hash for $type
+        where
+            $target: core::hash::Hash,
+        {
+            fn hash<S: core::hash::Hasher>(&self, state: &mut S) {
+                (**self).hash(state)
+            }
+        }
+    };
+}
+
+use forward_traits;
+
+/// The layout of a `H` header followed by a `[T]` with `len` elements,
+/// and the offset of the slice from the start of the allocation
+fn slice_layout<H, T>(len: usize) -> Option<(Layout, usize)> {
+    let mut builder = LayoutBuilder::new();
+    builder.field::<H>().ok()?;
+    let offset = builder.slice::<T>(len).ok()?;
+    Some((builder.finish_padded(), offset))
+}
+
+/// allocate memory with the given layout, which must have a non-zero size
+fn allocate<E>(layout: Layout) -> Result<NonNull<u8>, EmplaceError<E>> {
+    debug_assert_ne!(layout.size(), 0);
+    // SAFETY: all layouts passed to allocate have a header, so they have a non-zero size
+    NonNull::new(unsafe { alloc(layout) }).ok_or(EmplaceError::Alloc(layout))
+}
+
+/// An allocation which will be freed if initialization fails
+struct Dealloc {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Drop for Dealloc {
+    fn drop(&mut self) {
+        // SAFETY: the pointer was allocated with `allocate` with this layout
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) }
+    }
+}
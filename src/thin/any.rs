@@ -0,0 +1,169 @@
+use core::{
+    alloc::Layout,
+    any::{Any, TypeId},
+    ptr::NonNull,
+};
+
+use crate::{
+    layout_provider::{LayoutBuilder, LayoutProviderError},
+    Ctor, EmplaceError, Uninit,
+};
+
+use super::Dealloc;
+
+struct AnyHeader {
+    type_id: TypeId,
+    layout: Layout,
+    drop: unsafe fn(*mut u8),
+}
+
+/// # Safety
+///
+/// `ptr` must point to an initialized `T`, which isn't used after this
+unsafe fn drop_value<T>(ptr: *mut u8) {
+    // SAFETY: the caller ensures that ptr points to an initialized `T`
+    unsafe { ptr.cast::<T>().drop_in_place() }
+}
+
+/// The layout of the header followed by a value with the given layout,
+/// and the offset of the value from the start of the allocation
+fn any_layout(value: Layout) -> Option<(Layout, usize)> {
+    let mut builder = LayoutBuilder::new();
+    builder.field::<AnyHeader>().ok()?;
+    let offset = builder.layout(value).ok()?;
+    Some((builder.finish_padded(), offset))
+}
+
+/// A type-erased thin owning pointer, which stores the [`TypeId`] of the value inside the allocation
+///
+/// This is a single pointer wide, unlike `Box<dyn Any>`
+pub struct ThinAny {
+    ptr: NonNull<AnyHeader>,
+}
+
+super::erasable_thin!(impl[] ThinAny => AnyHeader {});
+
+impl ThinAny {
+    /// initialize a `T` directly after the header in a new allocation
+    pub fn try_new<T, I>(init: I) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Any + Ctor<I>,
+    {
+        let Some((layout, offset)) = any_layout(Layout::new::<T>()) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let ptr = super::allocate(layout)?;
+        let dealloc = Dealloc { ptr, layout };
+
+        // SAFETY: the value starts at `offset`, which is in bounds of the allocation
+        let value = unsafe { ptr.as_ptr().add(offset) }.cast::<T>();
+        // SAFETY: the allocation has room for the value after the header
+        let uninit = unsafe { Uninit::from_raw(value) };
+        uninit
+            .try_init(init)
+            .map_err(EmplaceError::Init)?
+            .take_ownership();
+
+        core::mem::forget(dealloc);
+        let ptr = ptr.cast::<AnyHeader>();
+        // SAFETY: the header is at the start of the allocation, which is aligned for AnyHeader
+        unsafe {
+            ptr.as_ptr().write(AnyHeader {
+                type_id: TypeId::of::<T>(),
+                layout: Layout::new::<T>(),
+                drop: drop_value::<T>,
+            })
+        };
+
+        Ok(Self { ptr })
+    }
+
+    /// initialize a `T` directly after the header in a new allocation
+    pub fn new<T, I>(init: I) -> Self
+    where
+        T: Any + Ctor<I, Error = core::convert::Infallible>,
+    {
+        match Self::try_new::<T, I>(init) {
+            Ok(thin) => thin,
+            Err(err) => err.handle(),
+        }
+    }
+
+    const fn header(&self) -> &AnyHeader {
+        // SAFETY: the header is always initialized
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// The [`TypeId`] of the value
+    pub const fn type_id(&self) -> TypeId {
+        self.header().type_id
+    }
+
+    /// Returns true if the value is a `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.type_id() == TypeId::of::<T>()
+    }
+
+    fn value_ptr(&self) -> *mut u8 {
+        let Some((_, offset)) = any_layout(self.header().layout) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        // SAFETY: the value starts at `offset`, which is in bounds of the allocation
+        unsafe { self.ptr.as_ptr().cast::<u8>().add(offset) }
+    }
+
+    /// Returns a reference to the value if it's a `T`
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        // SAFETY: the value is an initialized `T`, and is owned by self
+        self.is::<T>()
+            .then(|| unsafe { &*self.value_ptr().cast::<T>() })
+    }
+
+    /// Returns a mutable reference to the value if it's a `T`
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        // SAFETY: the value is an initialized `T`, and is uniquely owned by self
+        self.is::<T>()
+            .then(|| unsafe { &mut *self.value_ptr().cast::<T>() })
+    }
+
+    /// Move the value out of the allocation if it's a `T`
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        if !self.is::<T>() {
+            return Err(self);
+        }
+
+        let this = core::mem::ManuallyDrop::new(self);
+        let _dealloc = this.dealloc();
+        // SAFETY: the value is an initialized `T`, and self is never used again
+        Ok(unsafe { this.value_ptr().cast::<T>().read() })
+    }
+
+    fn dealloc(&self) -> Dealloc {
+        let Some((layout, _)) = any_layout(self.header().layout) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        Dealloc {
+            ptr: self.ptr.cast(),
+            layout,
+        }
+    }
+}
+
+impl Drop for ThinAny {
+    fn drop(&mut self) {
+        let _dealloc = self.dealloc();
+        // SAFETY: the value is initialized, and the drop fn matches its type
+        unsafe { (self.header().drop)(self.value_ptr()) }
+    }
+}
+
+impl core::fmt::Debug for ThinAny {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ThinAny").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,258 @@
+use core::{
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+    sync::atomic::{self, AtomicUsize, Ordering},
+};
+
+use crate::{
+    from_fn, layout_provider::LayoutProviderError, slice, tail::WithHeaderError, Ctor,
+    EmplaceError, Uninit,
+};
+
+use super::Dealloc;
+
+#[repr(C)]
+struct ArcHeader<H> {
+    count: AtomicUsize,
+    len: usize,
+    header: H,
+}
+
+/// An atomically reference-counted thin pointer to a header and a slice
+///
+/// The reference count, header, length and slice are all stored in a single allocation,
+/// so this is a single pointer wide
+pub struct ThinArc<H, T> {
+    ptr: NonNull<ArcHeader<H>>,
+    _marker: PhantomData<(H, [T])>,
+}
+
+// SAFETY: ThinArc shares its header and slice between threads, like Arc
+unsafe impl<H: Send + Sync, T: Send + Sync> Send for ThinArc<H, T> {}
+// SAFETY: ThinArc shares its header and slice between threads, like Arc
+unsafe impl<H: Send + Sync, T: Send + Sync> Sync for ThinArc<H, T> {}
+
+super::erasable_thin!(impl[H, T] ThinArc<H, T> => ArcHeader<H> { _marker: PhantomData });
+
+impl<H, T> ThinArc<H, T> {
+    /// initialize a header and a slice with `len` elements directly inside a new allocation
+    pub fn try_new<HI, TI, HE, TE>(
+        header: HI,
+        len: usize,
+        tail: TI,
+    ) -> Result<Self, EmplaceError<WithHeaderError<HE, TE>>>
+    where
+        H: Ctor<HI, Error = HE>,
+        [T]: Ctor<TI, Error = TE>,
+    {
+        let Some((layout, offset)) = super::slice_layout::<ArcHeader<H>, T>(len) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let ptr = super::allocate(layout)?;
+        let dealloc = Dealloc { ptr, layout };
+        let arc = ptr.cast::<ArcHeader<H>>().as_ptr();
+
+        // SAFETY: the header is in bounds of the allocation, and isn't aliased
+        let header_ptr = unsafe { Uninit::from_raw(&raw mut (*arc).header) };
+        let header = header_ptr
+            .try_init(header)
+            .map_err(|err| EmplaceError::Init(WithHeaderError::Header(err)))?;
+
+        // SAFETY: the slice starts at `offset`, which is in bounds of the allocation
+        let data = unsafe { ptr.as_ptr().add(offset) }.cast::<T>();
+        let data = core::ptr::slice_from_raw_parts_mut(data, len);
+        // SAFETY: the allocation has room for the slice after the header
+        let tail_ptr = unsafe { Uninit::from_raw(data) };
+        tail_ptr
+            .try_init(tail)
+            .map_err(|err| EmplaceError::Init(WithHeaderError::Tail(err)))?
+            .take_ownership();
+
+        header.take_ownership();
+        core::mem::forget(dealloc);
+
+        // SAFETY: the count and length are in bounds of the allocation, and aren't aliased
+        unsafe {
+            (&raw mut (*arc).count).write(AtomicUsize::new(1));
+            (&raw mut (*arc).len).write(len);
+        }
+
+        Ok(Self {
+            ptr: ptr.cast(),
+            _marker: PhantomData,
+        })
+    }
+
+    const fn inner(&self) -> &ArcHeader<H> {
+        // SAFETY: the allocation is alive as long as there is a ThinArc pointing to it
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// The header
+    pub const fn header(&self) -> &H {
+        &self.inner().header
+    }
+
+    /// The number of elements in the slice
+    pub const fn len(&self) -> usize {
+        self.inner().len
+    }
+
+    /// Returns true if the slice is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of [`ThinArc`]s pointing to this allocation
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().count.load(Ordering::Acquire)
+    }
+
+    /// Returns true if both [`ThinArc`]s point to the same allocation
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+
+    /// Get mutable access to the header and slice, first cloning them into a new allocation
+    /// if there are other [`ThinArc`]s pointing to this allocation
+    pub fn make_mut(this: &mut Self) -> (&mut H, &mut [T])
+    where
+        H: Clone,
+        T: Clone,
+    {
+        if this.inner().count.load(Ordering::Acquire) != 1 {
+            let header = from_fn::with_value(this.header().clone());
+            let tail = slice::from_iter(this.iter().cloned().map(from_fn::with_value));
+
+            *this = match Self::try_new(header, this.len(), tail) {
+                Ok(clone) => clone,
+                Err(EmplaceError::Alloc(layout)) => alloc::alloc::handle_alloc_error(layout),
+                Err(_) => unreachable!("the layout was already checked, and cloning can't fail"),
+            };
+        }
+
+        let slice = this.as_slice_ptr();
+        // SAFETY: this is the only ThinArc pointing to the allocation, and the header
+        // and slice don't overlap
+        unsafe { (&mut (*this.ptr.as_ptr()).header, &mut *slice) }
+    }
+
+    fn as_slice_ptr(&self) -> *mut [T] {
+        let Some((_, offset)) = super::slice_layout::<ArcHeader<H>, T>(self.len()) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        // SAFETY: the slice starts at `offset`, which is in bounds of the allocation
+        let data = unsafe { self.ptr.as_ptr().cast::<u8>().add(offset) }.cast::<T>();
+        core::ptr::slice_from_raw_parts_mut(data, self.len())
+    }
+}
+
+impl<H, T> Clone for ThinArc<H, T> {
+    fn clone(&self) -> Self {
+        let old = self.inner().count.fetch_add(1, Ordering::Relaxed);
+
+        if old > isize::MAX as usize {
+            self.inner().count.fetch_sub(1, Ordering::Relaxed);
+
+            #[cold]
+            #[inline(never)]
+            fn handle_overflow() -> ! {
+                panic!("ThinArc reference count overflowed");
+            }
+
+            handle_overflow()
+        }
+
+        Self {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<H, T> Drop for ThinArc<H, T> {
+    fn drop(&mut self) {
+        if self.inner().count.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+
+        atomic::fence(Ordering::Acquire);
+
+        let Some((layout, _)) = super::slice_layout::<ArcHeader<H>, T>(self.len()) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        let _dealloc = Dealloc {
+            ptr: self.ptr.cast(),
+            layout,
+        };
+
+        let slice = self.as_slice_ptr();
+        // SAFETY: this was the last ThinArc, so the header and slice are initialized
+        // and won't be used after this
+        unsafe {
+            let _header = DropInPlace(&raw mut (*self.ptr.as_ptr()).header);
+            slice.drop_in_place();
+        }
+    }
+}
+
+/// Drops the pointee when dropped, so it's dropped even if something else panics first
+struct DropInPlace<T: ?Sized>(*mut T);
+
+impl<T: ?Sized> Drop for DropInPlace<T> {
+    fn drop(&mut self) {
+        // SAFETY: DropInPlace is only created for initialized values which aren't used again
+        unsafe { self.0.drop_in_place() }
+    }
+}
+
+impl<H, T> Deref for ThinArc<H, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the elements are initialized, and are only accessed through shared references
+        unsafe { &*self.as_slice_ptr() }
+    }
+}
+
+impl<H: core::fmt::Debug, T: core::fmt::Debug> core::fmt::Debug for ThinArc<H, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ThinArc")
+            .field("header", self.header())
+            .field("tail", &&**self)
+            .finish()
+    }
+}
+
+impl<H: PartialEq, T: PartialEq> PartialEq for ThinArc<H, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header() == other.header() && **self == **other
+    }
+}
+
+impl<H: Eq, T: Eq> Eq for ThinArc<H, T> {}
+
+impl<H: PartialOrd, T: PartialOrd> PartialOrd for ThinArc<H, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        (self.header(), &**self).partial_cmp(&(other.header(), &**other))
+    }
+}
+
+impl<H: Ord, T: Ord> Ord for ThinArc<H, T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.header(), &**self).cmp(&(other.header(), &**other))
+    }
+}
+
+impl<H: core::hash::Hash, T: core::hash::Hash> core::hash::Hash for ThinArc<H, T> {
+    fn hash<S: core::hash::Hasher>(&self, state: &mut S) {
+        self.header().hash(state);
+        (**self).hash(state);
+    }
+}
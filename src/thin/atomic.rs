@@ -0,0 +1,97 @@
+use core::{
+    marker::PhantomData,
+    ptr::NonNull,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use super::{ErasablePtr, Erased};
+
+/// An atomically swappable thin pointer, which owns the [`ErasablePtr`] stored in it
+pub struct AtomicThin<P: ErasablePtr> {
+    ptr: AtomicPtr<Erased>,
+    _marker: PhantomData<P>,
+}
+
+// SAFETY: AtomicThin owns a `P`, and only moves it between threads
+unsafe impl<P: ErasablePtr + Send> Send for AtomicThin<P> {}
+// SAFETY: AtomicThin only allows moving a `P` in and out through a shared reference, like a `Mutex<P>`
+unsafe impl<P: ErasablePtr + Send> Sync for AtomicThin<P> {}
+
+impl<P: ErasablePtr> AtomicThin<P> {
+    /// Create a new atomic pointer which owns `ptr`
+    pub fn new(ptr: P) -> Self {
+        Self {
+            ptr: AtomicPtr::new(P::erase(ptr).as_ptr()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Take the pointer out
+    pub fn into_inner(self) -> P {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: the pointer came from `erase`, and self is never used again
+        unsafe { P::unerase(Self::load_ptr(this.ptr.load(Ordering::Relaxed))) }
+    }
+
+    const fn load_ptr(ptr: *mut Erased) -> NonNull<Erased> {
+        // SAFETY: AtomicThin only ever holds pointers which came from `erase`, which are never null
+        unsafe { NonNull::new_unchecked(ptr) }
+    }
+
+    /// Load the current erased pointer, which can be compared against with [`AtomicThin::compare_exchange`]
+    ///
+    /// This doesn't transfer ownership, the pointer may be dropped at any time by another thread
+    pub fn load(&self, order: Ordering) -> NonNull<Erased> {
+        Self::load_ptr(self.ptr.load(order))
+    }
+
+    /// Store a new pointer, and drop the old one
+    pub fn store(&self, ptr: P, order: Ordering) {
+        drop(self.swap(ptr, order));
+    }
+
+    /// Store a new pointer, and return the old one
+    pub fn swap(&self, ptr: P, order: Ordering) -> P {
+        let old = self.ptr.swap(P::erase(ptr).as_ptr(), order);
+        // SAFETY: the old pointer came from `erase`, and was removed from self, so it is now uniquely owned
+        unsafe { P::unerase(Self::load_ptr(old)) }
+    }
+
+    /// Store a new pointer if the current pointer is `current`
+    ///
+    /// On success this returns the old pointer, and on failure this returns `new`
+    pub fn compare_exchange(
+        &self,
+        current: NonNull<Erased>,
+        new: P,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<P, P> {
+        let new = P::erase(new);
+
+        match self
+            .ptr
+            .compare_exchange(current.as_ptr(), new.as_ptr(), success, failure)
+        {
+            // SAFETY: the old pointer came from `erase`, and was removed from self,
+            // so it is now uniquely owned
+            Ok(old) => Ok(unsafe { P::unerase(Self::load_ptr(old)) }),
+            // SAFETY: new was just erased, and was never stored in self
+            Err(_) => Err(unsafe { P::unerase(new) }),
+        }
+    }
+}
+
+impl<P: ErasablePtr> Drop for AtomicThin<P> {
+    fn drop(&mut self) {
+        let ptr = Self::load_ptr(*self.ptr.get_mut());
+        // SAFETY: the pointer came from `erase`, and self is never used again
+        drop(unsafe { P::unerase(ptr) })
+    }
+}
+
+impl<P: ErasablePtr> core::fmt::Debug for AtomicThin<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AtomicThin").field(&self.ptr).finish()
+    }
+}
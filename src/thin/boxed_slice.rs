@@ -0,0 +1,126 @@
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{layout_provider::LayoutProviderError, Ctor, EmplaceError, Uninit};
+
+use super::Dealloc;
+
+/// A thin owning pointer to a `[T]`, which stores the length of the slice inside the allocation
+///
+/// This is a single pointer wide, unlike `Box<[T]>`
+pub struct ThinBoxedSlice<T> {
+    ptr: NonNull<usize>,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: ThinBoxedSlice owns its elements, like Box<[T]>
+unsafe impl<T: Send> Send for ThinBoxedSlice<T> {}
+// SAFETY: ThinBoxedSlice only gives shared access to its elements through a shared reference
+unsafe impl<T: Sync> Sync for ThinBoxedSlice<T> {}
+
+super::erasable_thin!(impl[T] ThinBoxedSlice<T> => usize { _marker: PhantomData });
+super::forward_traits!(impl[T] ThinBoxedSlice<T> => [T]);
+
+impl<T> ThinBoxedSlice<T> {
+    /// initialize a slice with `len` elements directly after the length in a new allocation
+    pub fn try_new<I, E>(len: usize, init: I) -> Result<Self, EmplaceError<E>>
+    where
+        [T]: Ctor<I, Error = E>,
+    {
+        let Some((layout, offset)) = super::slice_layout::<usize, T>(len) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let ptr = super::allocate(layout)?;
+        let dealloc = Dealloc { ptr, layout };
+
+        // SAFETY: the slice starts at `offset`, which is in bounds of the allocation
+        let data = unsafe { ptr.as_ptr().add(offset) }.cast::<T>();
+        let data = core::ptr::slice_from_raw_parts_mut(data, len);
+        // SAFETY: the allocation has room for the slice after the header
+        let uninit = unsafe { Uninit::from_raw(data) };
+        uninit
+            .try_init(init)
+            .map_err(EmplaceError::Init)?
+            .take_ownership();
+
+        core::mem::forget(dealloc);
+        let ptr = ptr.cast::<usize>();
+        // SAFETY: the header is at the start of the allocation, which is aligned for usize
+        unsafe { ptr.as_ptr().write(len) };
+
+        Ok(Self {
+            ptr,
+            _marker: PhantomData,
+        })
+    }
+
+    /// initialize a slice with `len` elements directly after the length in a new allocation
+    pub fn new<I>(len: usize, init: I) -> Self
+    where
+        [T]: Ctor<I, Error = core::convert::Infallible>,
+    {
+        match Self::try_new(len, init) {
+            Ok(slice) => slice,
+            Err(err) => err.handle(),
+        }
+    }
+
+    /// The number of elements in the slice
+    pub const fn len(&self) -> usize {
+        // SAFETY: the header is always initialized
+        unsafe { *self.ptr.as_ptr() }
+    }
+
+    /// Returns true if the slice is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn as_slice_ptr(&self) -> *mut [T] {
+        let Some((_, offset)) = super::slice_layout::<usize, T>(self.len()) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        // SAFETY: the slice starts at `offset`, which is in bounds of the allocation
+        let data = unsafe { self.ptr.as_ptr().cast::<u8>().add(offset) }.cast::<T>();
+        core::ptr::slice_from_raw_parts_mut(data, self.len())
+    }
+}
+
+impl<T> Drop for ThinBoxedSlice<T> {
+    fn drop(&mut self) {
+        let Some((layout, _)) = super::slice_layout::<usize, T>(self.len()) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        let _dealloc = Dealloc {
+            ptr: self.ptr.cast(),
+            layout,
+        };
+
+        // SAFETY: the elements are initialized, and aren't used after this
+        unsafe { self.as_slice_ptr().drop_in_place() }
+    }
+}
+
+impl<T> Deref for ThinBoxedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the elements are initialized and owned by self
+        unsafe { &*self.as_slice_ptr() }
+    }
+}
+
+impl<T> DerefMut for ThinBoxedSlice<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the elements are initialized and uniquely owned by self
+        unsafe { &mut *self.as_slice_ptr() }
+    }
+}
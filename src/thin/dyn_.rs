@@ -0,0 +1,148 @@
+use core::{
+    alloc::Layout,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{
+    layout_provider::{LayoutBuilder, LayoutProviderError},
+    Ctor, EmplaceError, Uninit,
+};
+
+use super::Dealloc;
+
+/// A thin owning pointer to a `D`, usually a trait object, which stores the pointer metadata
+/// (the vtable) inside the allocation
+///
+/// This is a single pointer wide, unlike `Box<dyn Trait>`.
+/// See [`thin_dyn!`](crate::thin_dyn) for a shorthand to construct it
+pub struct ThinDyn<D: ?Sized> {
+    ptr: NonNull<NonNull<D>>,
+    _marker: PhantomData<D>,
+}
+
+// SAFETY: ThinDyn owns its value, like Box<D>
+unsafe impl<D: ?Sized + Send> Send for ThinDyn<D> {}
+// SAFETY: ThinDyn only gives shared access to its value through a shared reference
+unsafe impl<D: ?Sized + Sync> Sync for ThinDyn<D> {}
+
+/// The layout of the header followed by a value with the given layout,
+/// and the offset of the value from the start of the allocation
+fn dyn_layout<D: ?Sized>(value: Layout) -> Option<(Layout, usize)> {
+    let mut builder = LayoutBuilder::new();
+    builder.field::<NonNull<D>>().ok()?;
+    let offset = builder.layout(value).ok()?;
+    Some((builder.finish_padded(), offset))
+}
+
+super::erasable_thin!(impl[D: ?Sized] ThinDyn<D> => NonNull<D> { _marker: PhantomData });
+super::forward_traits!(impl[D: ?Sized] ThinDyn<D> => D);
+
+impl<D: ?Sized> ThinDyn<D> {
+    /// initialize a `T` directly after the header in a new allocation, and then unsize it to a `D` with `unsize`
+    ///
+    /// `unsize` should just be an identity closure (`|value| value`), which captures the unsizing coercion
+    ///
+    /// # Panics
+    ///
+    /// If `unsize` doesn't return a reference to the same value
+    pub fn try_new<T, I>(
+        init: I,
+        unsize: impl FnOnce(&mut T) -> &mut D,
+    ) -> Result<Self, EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+    {
+        let Some((layout, offset)) = dyn_layout::<D>(Layout::new::<T>()) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let ptr = super::allocate(layout)?;
+        let dealloc = Dealloc { ptr, layout };
+
+        // SAFETY: the value starts at `offset`, which is in bounds of the allocation
+        let value = unsafe { ptr.as_ptr().add(offset) }.cast::<T>();
+        // SAFETY: the allocation has room for the value after the header
+        let uninit = unsafe { Uninit::from_raw(value) };
+        let mut init = uninit.try_init(init).map_err(EmplaceError::Init)?;
+
+        // SAFETY: the value was just initialized, and isn't aliased
+        let unsized_value = unsize(unsafe { &mut *init.as_mut_ptr() });
+        assert!(
+            core::ptr::addr_eq(unsized_value, value)
+                && Layout::for_value(unsized_value) == Layout::new::<T>(),
+            "unsize must return a reference to the same value"
+        );
+        let unsized_value = NonNull::from(unsized_value);
+
+        init.take_ownership();
+        core::mem::forget(dealloc);
+        let ptr = ptr.cast::<NonNull<D>>();
+        // SAFETY: the header is at the start of the allocation, which is aligned for NonNull<D>
+        unsafe { ptr.as_ptr().write(unsized_value) };
+
+        Ok(Self {
+            ptr,
+            _marker: PhantomData,
+        })
+    }
+
+    /// initialize a `T` directly after the header in a new allocation, and then unsize it to a `D` with `unsize`
+    ///
+    /// `unsize` should just be an identity closure (`|value| value`), which captures the unsizing coercion
+    ///
+    /// # Panics
+    ///
+    /// If `unsize` doesn't return a reference to the same value
+    pub fn new<T, I>(init: I, unsize: impl FnOnce(&mut T) -> &mut D) -> Self
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+    {
+        match Self::try_new(init, unsize) {
+            Ok(thin) => thin,
+            Err(err) => err.handle(),
+        }
+    }
+
+    const fn value_ptr(&self) -> NonNull<D> {
+        // SAFETY: the header is always initialized
+        unsafe { *self.ptr.as_ptr() }
+    }
+}
+
+impl<D: ?Sized> Drop for ThinDyn<D> {
+    fn drop(&mut self) {
+        let value = self.value_ptr();
+        // SAFETY: the value is initialized
+        let value_layout = Layout::for_value(unsafe { value.as_ref() });
+        let Some((layout, _)) = dyn_layout::<D>(value_layout) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        let _dealloc = Dealloc {
+            ptr: self.ptr.cast(),
+            layout,
+        };
+
+        // SAFETY: the value is initialized, and isn't used after this
+        unsafe { value.as_ptr().drop_in_place() }
+    }
+}
+
+impl<D: ?Sized> Deref for ThinDyn<D> {
+    type Target = D;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the value is initialized and owned by self
+        unsafe { self.value_ptr().as_ref() }
+    }
+}
+
+impl<D: ?Sized> DerefMut for ThinDyn<D> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the value is initialized and uniquely owned by self
+        unsafe { self.value_ptr().as_mut() }
+    }
+}
@@ -0,0 +1,253 @@
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    mem::ManuallyDrop,
+    ops::Deref,
+    pin::Pin,
+    ptr::NonNull,
+};
+
+use alloc::{boxed::Box, rc::Rc};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+/// The target of an erased pointer, which can't be used for anything except restoring the pointer
+///
+/// This is an opaque type, it can't be constructed, and it isn't `Send`, `Sync` or `Unpin`,
+/// so erased pointers can't be dereferenced into anything useful
+pub struct Erased {
+    _data: [u8; 0],
+    _marker: PhantomData<(*mut u8, PhantomPinned)>,
+}
+
+impl Erased {
+    /// Erase a pointer, keeping its address and provenance
+    pub const fn erase<T: ?Sized>(ptr: NonNull<T>) -> NonNull<Self> {
+        ptr.cast()
+    }
+
+    /// Restore an erased pointer to a `T`, keeping its address and provenance
+    ///
+    /// This is always safe, but dereferencing the result is only safe if
+    /// the erased pointer originally pointed to a `T`
+    pub const fn unerase<T>(ptr: NonNull<Self>) -> NonNull<T> {
+        ptr.cast()
+    }
+}
+
+/// A type whose pointers can be erased to thin pointers, because the pointer metadata
+/// can be restored from the value itself
+///
+/// This is implemented for all sized types, and can be derived with `#[derive(Erasable)]`
+/// (with the `derive` feature) for `#[repr(C)]` structs whose leading field is the length
+/// of their slice or `str` tail
+///
+/// # Safety
+///
+/// * `ALIGN` must be at most the alignment of `Self`
+/// * `unerase` must restore any pointer to a value which was passed to `check`,
+///   as long as the value wasn't accessed through a unique reference since then
+pub unsafe trait Erasable {
+    /// The minimum alignment of `Self`
+    const ALIGN: usize;
+
+    /// Check that the pointer metadata can be restored from `this`
+    ///
+    /// # Panics
+    ///
+    /// If the pointer metadata can't be restored from `this`
+    fn check(this: &Self);
+
+    /// Restore the pointer to the value which `ptr` points to
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a value which was passed to [`Erasable::check`],
+    /// and which wasn't accessed through a unique reference since then
+    unsafe fn unerase(ptr: NonNull<Erased>) -> NonNull<Self>;
+}
+
+// SAFETY: sized types don't have any pointer metadata
+unsafe impl<T> Erasable for T {
+    const ALIGN: usize = align_of::<T>();
+
+    fn check(_: &Self) {}
+
+    unsafe fn unerase(ptr: NonNull<Erased>) -> NonNull<Self> {
+        Erased::unerase(ptr)
+    }
+}
+
+/// A pointer which can be erased to a single thin pointer, and then restored
+///
+/// # Safety
+///
+/// * `unerase` must restore the same pointer which was passed to `erase`
+/// * `with` must only give shared access to the pointer, and it must be sound to call
+///   on the same erased pointer any number of times at once (from different threads if
+///   `Self: Sync`). So pointers which are unique, like `&mut T`, can't implement this trait
+pub unsafe trait ErasablePtr: Sized {
+    /// The minimum alignment of the erased pointers, the low bits of erased pointers
+    /// below this alignment are always zero
+    const ALIGN: usize = 1;
+
+    /// Erase the pointer, transferring ownership to the erased pointer
+    fn erase(this: Self) -> NonNull<Erased>;
+
+    /// Restore the pointer from an erased pointer
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned from [`ErasablePtr::erase`] for the same pointer type,
+    /// and must not have been restored since then
+    unsafe fn unerase(ptr: NonNull<Erased>) -> Self;
+
+    /// Borrow the pointer behind an erased pointer, without taking ownership of it
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned from [`ErasablePtr::erase`] for the same pointer type,
+    /// and must not have been restored since then
+    unsafe fn with<R>(ptr: NonNull<Erased>, f: impl FnOnce(&Self) -> R) -> R {
+        // SAFETY: the caller ensures that ptr came from erase, and wasn't restored since then.
+        // The restored pointer is never dropped, and only shared access is given out
+        let this = ManuallyDrop::new(unsafe { Self::unerase(ptr) });
+        f(&this)
+    }
+}
+
+// SAFETY: Box::into_raw and Box::from_raw round-trip, and the value was checked before erasing
+unsafe impl<T: ?Sized + Erasable> ErasablePtr for Box<T> {
+    const ALIGN: usize = T::ALIGN;
+
+    fn erase(this: Self) -> NonNull<Erased> {
+        T::check(&this);
+        // SAFETY: Box is never null
+        Erased::erase(unsafe { NonNull::new_unchecked(Box::into_raw(this)) })
+    }
+
+    unsafe fn unerase(ptr: NonNull<Erased>) -> Self {
+        // SAFETY: the caller ensures that ptr came from erase, and wasn't restored since then,
+        // so the value was checked, and no unique references to it were created
+        unsafe { Box::from_raw(T::unerase(ptr).as_ptr()) }
+    }
+}
+
+// SAFETY: Rc::into_raw and Rc::from_raw round-trip, and the value was checked before erasing
+unsafe impl<T: ?Sized + Erasable> ErasablePtr for Rc<T> {
+    const ALIGN: usize = T::ALIGN;
+
+    fn erase(this: Self) -> NonNull<Erased> {
+        T::check(&this);
+        // SAFETY: Rc is never null
+        Erased::erase(unsafe { NonNull::new_unchecked(Rc::into_raw(this).cast_mut()) })
+    }
+
+    unsafe fn unerase(ptr: NonNull<Erased>) -> Self {
+        // SAFETY: the caller ensures that ptr came from erase, and wasn't restored since then,
+        // so the value was checked, and no unique references to it were created
+        unsafe { Rc::from_raw(T::unerase(ptr).as_ptr()) }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+// SAFETY: Arc::into_raw and Arc::from_raw round-trip, and the value was checked before erasing
+unsafe impl<T: ?Sized + Erasable> ErasablePtr for Arc<T> {
+    const ALIGN: usize = T::ALIGN;
+
+    fn erase(this: Self) -> NonNull<Erased> {
+        T::check(&this);
+        // SAFETY: Arc is never null
+        Erased::erase(unsafe { NonNull::new_unchecked(Arc::into_raw(this).cast_mut()) })
+    }
+
+    unsafe fn unerase(ptr: NonNull<Erased>) -> Self {
+        // SAFETY: the caller ensures that ptr came from erase, and wasn't restored since then,
+        // so the value was checked, and no unique references to it were created
+        unsafe { Arc::from_raw(T::unerase(ptr).as_ptr()) }
+    }
+}
+
+#[cfg(feature = "ptr_metadata")]
+// SAFETY: references to types without metadata (including extern types)
+// are thin pointers, so they can be rebuilt from their address
+unsafe impl<'a, T: core::marker::PointeeSized + core::ptr::Pointee<Metadata = ()> + 'a> ErasablePtr
+    for &'a T
+{
+    fn erase(this: Self) -> NonNull<Erased> {
+        NonNull::from(this).cast()
+    }
+
+    unsafe fn unerase(ptr: NonNull<Erased>) -> Self {
+        // SAFETY: the caller ensures that ptr came from erase, so it's a valid reference for 'a
+        unsafe { NonNull::<T>::from_raw_parts(ptr.cast::<()>(), ()).as_ref() }
+    }
+}
+
+// SAFETY: the pointee is never moved by erasing, so it stays pinned until it's restored
+unsafe impl<P: ErasablePtr + Deref> ErasablePtr for Pin<P> {
+    const ALIGN: usize = P::ALIGN;
+
+    fn erase(this: Self) -> NonNull<Erased> {
+        // SAFETY: the pointer is only restored as a `Pin<P>`, so the pointee is never exposed unpinned
+        P::erase(unsafe { Pin::into_inner_unchecked(this) })
+    }
+
+    unsafe fn unerase(ptr: NonNull<Erased>) -> Self {
+        // SAFETY: the caller ensures that ptr came from erase, so it was pinned
+        unsafe { Pin::new_unchecked(P::unerase(ptr)) }
+    }
+}
+
+/// Implement [`ErasablePtr`] for a thin pointer with a `ptr: NonNull<$header>` field,
+/// the rest of the fields are given as a struct literal
+macro_rules! erasable_thin {
+    (impl[$($generics:tt)*] $type:ty => $header:ty { $($fields:tt)* }) => {
+        // SAFETY: the thin pointer is just its `ptr` field, so it can be rebuilt from it
+        unsafe impl<$($generics)*> $crate::thin::ErasablePtr for $type {
+            const ALIGN: usize = align_of::<$header>();
+
+            fn erase(this: Self) -> core::ptr::NonNull<$crate::thin::Erased> {
+                $crate::thin::Erased::erase(core::mem::ManuallyDrop::new(this).ptr)
+            }
+
+            unsafe fn unerase(ptr: core::ptr::NonNull<$crate::thin::Erased>) -> Self {
+                Self {
+                    ptr: $crate::thin::Erased::unerase(ptr),
+                    $($fields)*
+                }
+            }
+        }
+
+        $crate::thin::raw_thin!(impl[$($generics)*] $type);
+    };
+}
+
+/// Add `into_raw` and `from_raw` to a thin pointer which implements [`ErasablePtr`]
+macro_rules! raw_thin {
+    (impl[$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> $type {
+            /// Convert the thin pointer into an opaque raw pointer, which can be passed through FFI
+            ///
+            /// The value is leaked until the raw pointer is passed to `from_raw`
+            pub fn into_raw(this: Self) -> *mut $crate::thin::Erased {
+                $crate::thin::ErasablePtr::erase(this).as_ptr()
+            }
+
+            /// Convert an opaque raw pointer back into the thin pointer
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must have been returned from `into_raw` for the same thin pointer type,
+            /// and must not have been passed to `from_raw` since then
+            pub unsafe fn from_raw(ptr: *mut $crate::thin::Erased) -> Self {
+                // SAFETY: the caller ensures that ptr came from into_raw, which is never null
+                unsafe {
+                    $crate::thin::ErasablePtr::unerase(core::ptr::NonNull::new_unchecked(ptr))
+                }
+            }
+        }
+    };
+}
+
+pub(super) use {erasable_thin, raw_thin};
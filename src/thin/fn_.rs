@@ -0,0 +1,269 @@
+use core::{alloc::Layout, marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+use crate::{
+    layout_provider::{LayoutBuilder, LayoutProviderError},
+    Ctor, EmplaceError, Uninit,
+};
+
+use super::Dealloc;
+
+struct FnHeader<C> {
+    call: C,
+    drop: unsafe fn(*mut u8),
+    layout: Layout,
+}
+
+/// # Safety
+///
+/// `ptr` must point to an initialized `T`, which isn't used after this
+unsafe fn drop_value<T>(ptr: *mut u8) {
+    // SAFETY: the caller ensures that ptr points to an initialized `T`
+    unsafe { ptr.cast::<T>().drop_in_place() }
+}
+
+/// # Safety
+///
+/// `ptr` must point to an initialized `F`, which isn't mutated for the duration of the call
+unsafe fn call<F: Fn(A) -> R, A, R>(ptr: *mut u8, args: A) -> R {
+    // SAFETY: the caller ensures that ptr points to an initialized `F`
+    unsafe { (*ptr.cast::<F>())(args) }
+}
+
+/// # Safety
+///
+/// `ptr` must point to an initialized `F`, which isn't aliased for the duration of the call
+unsafe fn call_mut<F: FnMut(A) -> R, A, R>(ptr: *mut u8, args: A) -> R {
+    // SAFETY: the caller ensures that ptr points to an initialized `F`
+    unsafe { (*ptr.cast::<F>())(args) }
+}
+
+/// # Safety
+///
+/// `ptr` must point to an initialized `F`, which isn't used after this
+unsafe fn call_once<F: FnOnce(A) -> R, A, R>(ptr: *mut u8, args: A) -> R {
+    // SAFETY: the caller ensures that ptr points to an initialized `F`
+    unsafe { ptr.cast::<F>().read()(args) }
+}
+
+/// The layout of the header followed by a value with the given layout,
+/// and the offset of the value from the start of the allocation
+fn fn_layout<C>(value: Layout) -> Option<(Layout, usize)> {
+    let mut builder = LayoutBuilder::new();
+    builder.field::<FnHeader<C>>().ok()?;
+    let offset = builder.layout(value).ok()?;
+    Some((builder.finish_padded(), offset))
+}
+
+/// A closure with the call and drop functions stored inside the allocation
+struct RawThinFn<C> {
+    ptr: NonNull<FnHeader<C>>,
+}
+
+impl<C> RawThinFn<C> {
+    fn try_new<F, I>(init: I, call: C) -> Result<Self, EmplaceError<F::Error>>
+    where
+        F: Ctor<I>,
+    {
+        let Some((layout, offset)) = fn_layout::<C>(Layout::new::<F>()) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let ptr = super::allocate(layout)?;
+        let dealloc = Dealloc { ptr, layout };
+
+        // SAFETY: the closure starts at `offset`, which is in bounds of the allocation
+        let value = unsafe { ptr.as_ptr().add(offset) }.cast::<F>();
+        // SAFETY: the allocation has room for the closure after the header
+        let uninit = unsafe { Uninit::from_raw(value) };
+        uninit
+            .try_init(init)
+            .map_err(EmplaceError::Init)?
+            .take_ownership();
+
+        core::mem::forget(dealloc);
+        let ptr = ptr.cast::<FnHeader<C>>();
+        // SAFETY: the header is at the start of the allocation, which is aligned for FnHeader
+        unsafe {
+            ptr.as_ptr().write(FnHeader {
+                call,
+                drop: drop_value::<F>,
+                layout: Layout::new::<F>(),
+            })
+        };
+
+        Ok(Self { ptr })
+    }
+
+    const fn header(&self) -> &FnHeader<C> {
+        // SAFETY: the header is always initialized
+        unsafe { self.ptr.as_ref() }
+    }
+
+    fn value_ptr(&self) -> *mut u8 {
+        let Some((_, offset)) = fn_layout::<C>(self.header().layout) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        // SAFETY: the closure starts at `offset`, which is in bounds of the allocation
+        unsafe { self.ptr.as_ptr().cast::<u8>().add(offset) }
+    }
+
+    fn dealloc(&self) -> Dealloc {
+        let Some((layout, _)) = fn_layout::<C>(self.header().layout) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        Dealloc {
+            ptr: self.ptr.cast(),
+            layout,
+        }
+    }
+}
+
+impl<C> Drop for RawThinFn<C> {
+    fn drop(&mut self) {
+        let _dealloc = self.dealloc();
+        // SAFETY: the closure is initialized, and the drop fn matches its type
+        unsafe { (self.header().drop)(self.value_ptr()) }
+    }
+}
+
+macro_rules! thin_fn {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $trait:ident, $call:ident
+    ) => {
+        $(#[$meta])*
+        ///
+        /// The closure takes a single argument, use a tuple to pass multiple arguments.
+        /// The closure may borrow data that lives for `'a`
+        pub struct $name<'a, A, R> {
+            raw: RawThinFn<unsafe fn(*mut u8, A) -> R>,
+            _borrow: PhantomData<&'a ()>,
+        }
+
+        impl<'a, A, R> $name<'a, A, R> {
+            /// initialize a closure directly after the header in a new allocation
+            pub fn try_new<F, I>(init: I) -> Result<Self, EmplaceError<F::Error>>
+            where
+                F: $trait(A) -> R + Ctor<I> + 'a,
+            {
+                let call: unsafe fn(*mut u8, A) -> R = $call::<F, A, R>;
+                Ok(Self {
+                    raw: RawThinFn::try_new::<F, I>(init, call)?,
+                    _borrow: PhantomData,
+                })
+            }
+
+            /// initialize a closure directly after the header in a new allocation
+            pub fn new<F, I>(init: I) -> Self
+            where
+                F: $trait(A) -> R + Ctor<I, Error = core::convert::Infallible> + 'a,
+            {
+                match Self::try_new::<F, I>(init) {
+                    Ok(thin) => thin,
+                    Err(err) => err.handle(),
+                }
+            }
+
+            /// move a closure into a new allocation
+            pub fn from_closure<F>(f: F) -> Self
+            where
+                F: $trait(A) -> R + 'a,
+            {
+                Self::new::<F, _>(crate::from_fn::with_value(f))
+            }
+        }
+
+        // SAFETY: the thin pointer is just the `ptr` field of the raw closure, so it can be rebuilt from it
+        unsafe impl<A, R> super::ErasablePtr for $name<'_, A, R> {
+            const ALIGN: usize = align_of::<FnHeader<unsafe fn(*mut u8, A) -> R>>();
+
+            fn erase(this: Self) -> NonNull<super::Erased> {
+                super::Erased::erase(ManuallyDrop::new(this).raw.ptr)
+            }
+
+            unsafe fn unerase(ptr: NonNull<super::Erased>) -> Self {
+                Self {
+                    raw: RawThinFn { ptr: super::Erased::unerase(ptr) },
+                    _borrow: PhantomData,
+                }
+            }
+        }
+
+        super::raw_thin!(impl['a, A, R] $name<'a, A, R>);
+
+        impl<A, R> core::fmt::Debug for $name<'_, A, R> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+thin_fn! {
+    /// A thin owning pointer to a [`Fn`] closure, which stores the call and drop functions
+    /// inside the allocation
+    ///
+    /// The closure can't outlive the data it borrows
+    ///
+    /// ```compile_fail
+    /// use init::thin::ThinFn;
+    ///
+    /// fn make() -> ThinFn<'static, (), usize> {
+    ///     let s = String::from("hello");
+    ///     ThinFn::from_closure(|_: ()| s.len())
+    /// }
+    /// ```
+    ThinFn, Fn, call
+}
+
+thin_fn! {
+    /// A thin owning pointer to a [`FnMut`] closure, which stores the call and drop functions
+    /// inside the allocation
+    ThinFnMut, FnMut, call_mut
+}
+
+thin_fn! {
+    /// A thin owning pointer to a [`FnOnce`] closure, which stores the call and drop functions
+    /// inside the allocation
+    ThinFnOnce, FnOnce, call_once
+}
+
+impl<A, R> ThinFn<'_, A, R> {
+    /// Call the closure
+    pub fn call(&self, args: A) -> R {
+        // SAFETY: the closure is initialized, and the call fn matches its type
+        unsafe { (self.raw.header().call)(self.raw.value_ptr(), args) }
+    }
+}
+
+impl<A, R> ThinFnMut<'_, A, R> {
+    /// Call the closure
+    pub fn call_mut(&mut self, args: A) -> R {
+        // SAFETY: the closure is initialized and uniquely owned by self, and the call fn matches its type
+        unsafe { (self.raw.header().call)(self.raw.value_ptr(), args) }
+    }
+}
+
+impl<A, R> ThinFnOnce<'_, A, R> {
+    /// Call the closure, consuming it
+    pub fn call_once(self, args: A) -> R {
+        let raw = ManuallyDrop::new(self.raw);
+        let _dealloc = raw.dealloc();
+        // SAFETY: the closure is initialized, and the call fn matches its type. The closure
+        // is moved out by the call fn, and the allocation is freed without dropping it again
+        unsafe { (raw.header().call)(raw.value_ptr(), args) }
+    }
+}
+
+impl<'a, A, R> From<ThinFn<'a, A, R>> for ThinFnMut<'a, A, R> {
+    fn from(thin: ThinFn<'a, A, R>) -> Self {
+        Self {
+            raw: thin.raw,
+            _borrow: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use alloc::vec::Vec;
+
+use crate::{from_fn, slice};
+
+use super::{ThinBoxedSlice, ThinDyn, ThinVec};
+
+impl<T: Serialize> Serialize for ThinBoxedSlice<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<T: Serialize> Serialize for ThinVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<D: ?Sized + Serialize> Serialize for ThinDyn<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<H: Serialize, T: Serialize> Serialize for super::ThinArc<H, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ThinArc", 2)?;
+        state.serialize_field("header", self.header())?;
+        state.serialize_field("tail", &**self)?;
+        state.end()
+    }
+}
+
+struct ThinVecVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ThinVecVisitor<T> {
+    type Value = ThinVec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // the size hint comes from the input, so only trust it up to 1 MiB,
+        // like serde's own collections
+        const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+        let max = MAX_PREALLOC_BYTES / size_of::<T>().max(1);
+        let capacity = seq.size_hint().unwrap_or(0).min(max);
+
+        let mut vec = ThinVec::new();
+        vec.try_reserve(capacity)
+            .map_err(|_| A::Error::custom("could not allocate the vector"))?;
+
+        while let Some(value) = seq.next_element()? {
+            vec.emplace(from_fn::with_value(value));
+        }
+
+        Ok(vec)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ThinVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ThinVecVisitor(PhantomData))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ThinBoxedSlice<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        let len = vec.len();
+        let values = vec.into_iter().map(from_fn::with_value);
+        ThinBoxedSlice::try_new(len, slice::from_iter(values))
+            .map_err(|_| D::Error::custom("could not allocate the slice"))
+    }
+}
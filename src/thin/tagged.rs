@@ -0,0 +1,100 @@
+use core::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+use super::{ErasablePtr, Erased};
+
+/// An owning thin pointer, which stores a `BITS` bit tag in the low bits of the erased pointer
+///
+/// `BITS` must fit in the alignment of the erased pointers, see [`ErasablePtr::ALIGN`]
+pub struct TaggedThin<P: ErasablePtr, const BITS: u32> {
+    ptr: NonNull<Erased>,
+    _marker: PhantomData<P>,
+}
+
+// SAFETY: TaggedThin owns a `P`
+unsafe impl<P: ErasablePtr + Send, const BITS: u32> Send for TaggedThin<P, BITS> {}
+// SAFETY: TaggedThin only gives shared access to the `P` through a shared reference
+unsafe impl<P: ErasablePtr + Sync, const BITS: u32> Sync for TaggedThin<P, BITS> {}
+
+impl<P: ErasablePtr, const BITS: u32> TaggedThin<P, BITS> {
+    const MASK: usize = {
+        assert!(
+            BITS < usize::BITS && 1 << BITS <= P::ALIGN,
+            "the tag doesn't fit in the alignment of the pointer"
+        );
+        (1 << BITS) - 1
+    };
+
+    /// Create a new tagged pointer
+    ///
+    /// # Panics
+    ///
+    /// If `tag` doesn't fit in `BITS` bits
+    pub fn new(ptr: P, tag: usize) -> Self {
+        assert!(tag & !Self::MASK == 0, "the tag doesn't fit in {BITS} bits");
+        let ptr = P::erase(ptr);
+        Self {
+            ptr: ptr.map_addr(|addr| addr | tag),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The tag stored in the pointer
+    pub fn tag(&self) -> usize {
+        self.ptr.addr().get() & Self::MASK
+    }
+
+    /// Replace the tag stored in the pointer, without touching the pointee
+    ///
+    /// # Panics
+    ///
+    /// If `tag` doesn't fit in `BITS` bits
+    pub fn set_tag(&mut self, tag: usize) {
+        assert!(tag & !Self::MASK == 0, "the tag doesn't fit in {BITS} bits");
+        let erased = self.erased();
+        self.ptr = erased.map_addr(|addr| addr | tag);
+    }
+
+    fn erased(&self) -> NonNull<Erased> {
+        // SAFETY: the erased pointer is non-null and aligned to at least `1 << BITS`,
+        // so clearing the tag bits leaves it non-null
+        self.ptr.map_addr(|addr| unsafe {
+            core::num::NonZeroUsize::new_unchecked(addr.get() & !Self::MASK)
+        })
+    }
+
+    /// Access the pointer
+    pub fn with<R>(&self, f: impl FnOnce(&P) -> R) -> R {
+        // SAFETY: the pointer came from `erase`, and is only restored when self is consumed
+        unsafe { P::with(self.erased(), f) }
+    }
+
+    /// Take the pointer and the tag out
+    pub fn into_parts(self) -> (P, usize) {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: the pointer came from `erase`, and self is never used again
+        (unsafe { P::unerase(this.erased()) }, this.tag())
+    }
+
+    /// Take the pointer out, discarding the tag
+    pub fn into_inner(self) -> P {
+        self.into_parts().0
+    }
+}
+
+impl<P: ErasablePtr, const BITS: u32> Drop for TaggedThin<P, BITS> {
+    fn drop(&mut self) {
+        // SAFETY: the pointer came from `erase`, and self is never used again
+        drop(unsafe { P::unerase(self.erased()) })
+    }
+}
+
+impl<P: ErasablePtr + core::fmt::Debug, const BITS: u32> core::fmt::Debug for TaggedThin<P, BITS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.with(|ptr| {
+            f.debug_struct("TaggedThin")
+                .field("ptr", ptr)
+                .field("tag", &self.tag())
+                .finish()
+        })
+    }
+}
@@ -0,0 +1,327 @@
+use core::cell::Cell;
+
+use alloc::{format, string::String};
+
+use crate::{from_fn, slice};
+
+#[test]
+fn boxed_slice() {
+    let mut bx = super::ThinBoxedSlice::new(3, slice::repeat(1u8));
+    assert_eq!(size_of_val(&bx), size_of::<usize>());
+    assert_eq!(*bx, [1, 1, 1]);
+
+    bx[1] = 2;
+    assert_eq!(*bx, [1, 2, 1]);
+
+    let empty = super::ThinBoxedSlice::<u64>::new(0, slice::repeat(0u64));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn boxed_slice_drops() {
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let count = Cell::new(0);
+    let bx = super::ThinBoxedSlice::<Counted>::try_new(
+        4,
+        slice::from_iter((0..4).map(|_| crate::from_fn::with_value(Counted(&count)))),
+    );
+    drop(bx);
+    assert_eq!(count.get(), 4);
+}
+
+#[test]
+fn arc() {
+    let arc = super::ThinArc::<u64, u8>::try_new(5u64, 3, slice::from_iter(1..));
+    let arc = arc.ok().unwrap();
+    assert_eq!(size_of_val(&arc), size_of::<usize>());
+    assert_eq!(*arc.header(), 5);
+    assert_eq!(*arc, [1, 2, 3]);
+
+    let other = arc.clone();
+    assert_eq!(super::ThinArc::strong_count(&arc), 2);
+    assert!(super::ThinArc::ptr_eq(&arc, &other));
+    drop(other);
+    assert_eq!(super::ThinArc::strong_count(&arc), 1);
+}
+
+#[test]
+fn arc_nan_is_not_equal_to_itself() {
+    let arc = super::ThinArc::<f32, f32>::try_new(f32::NAN, 1, slice::repeat(1.0f32));
+    let arc = arc.ok().unwrap();
+    let other = arc.clone();
+    assert!(arc != other);
+    assert_eq!(arc.partial_cmp(&other), None);
+}
+
+#[test]
+fn vec() {
+    let mut vec = super::ThinVec::<u32>::new();
+    assert_eq!(size_of_val(&vec), size_of::<usize>());
+    assert_eq!(vec.capacity(), 0);
+
+    vec.emplace(1);
+    vec.extend_emplace([2, 3, 4, 5]);
+    assert_eq!(*vec, [1, 2, 3, 4, 5]);
+    assert!(vec.capacity() >= 5);
+
+    assert_eq!(vec.pop(), Some(5));
+    vec[0] = 10;
+    assert_eq!(*vec, [10, 2, 3, 4]);
+}
+
+#[test]
+fn thin_dyn() {
+    let mut thin: super::ThinDyn<dyn core::fmt::Write> =
+        crate::thin_dyn!(String, from_fn::with_value(String::new()));
+    assert_eq!(size_of_val(&thin), size_of::<usize>());
+    thin.write_str("hello").unwrap();
+    thin.write_str(" world").unwrap();
+
+    let debug: super::ThinDyn<dyn core::fmt::Debug> = crate::thin_dyn!(u16, 42);
+    assert_eq!(format!("{:?}", &*debug), "42");
+}
+
+#[test]
+fn any() {
+    let mut thin = super::ThinAny::new::<u32, _>(10u32);
+    assert_eq!(size_of_val(&thin), size_of::<usize>());
+    assert!(thin.is::<u32>());
+    assert_eq!(thin.downcast_ref::<u64>(), None);
+
+    *thin.downcast_mut::<u32>().unwrap() += 1;
+    let thin = thin.downcast::<i32>().unwrap_err();
+    assert_eq!(thin.downcast::<u32>().unwrap(), 11);
+
+    let string = super::ThinAny::new::<String, _>(from_fn::with_value(String::from("hello")));
+    assert_eq!(string.downcast_ref::<String>().unwrap(), "hello");
+}
+
+#[test]
+fn thin_fn() {
+    let offset = 10;
+    let add = super::ThinFn::from_closure(move |x: u32| x + offset);
+    assert_eq!(size_of_val(&add), size_of::<usize>());
+    assert_eq!(add.call(1), 11);
+
+    let mut total = 0;
+    let mut sum = super::ThinFnMut::from_closure(|x: u32| total += x);
+    sum.call_mut(1);
+    sum.call_mut(2);
+    drop(sum);
+    assert_eq!(total, 3);
+
+    let string = String::from("hello");
+    let take = super::ThinFnOnce::from_closure(move |()| string);
+    assert_eq!(take.call_once(()), "hello");
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn atomic() {
+    use core::sync::atomic::Ordering;
+
+    let atomic = super::AtomicThin::new(super::ThinBoxedSlice::<u8>::new(2, slice::repeat(1u8)));
+    let stale = atomic.load(Ordering::Acquire);
+    let old = atomic.swap(
+        super::ThinBoxedSlice::new(3, slice::repeat(2u8)),
+        Ordering::AcqRel,
+    );
+    assert_eq!(*old, [1, 1]);
+
+    let current = atomic.load(Ordering::Acquire);
+    let new = super::ThinBoxedSlice::new(1, slice::repeat(3u8));
+    let new = atomic
+        .compare_exchange(stale, new, Ordering::AcqRel, Ordering::Acquire)
+        .err()
+        .unwrap();
+    let replaced = atomic
+        .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
+        .ok()
+        .unwrap();
+    assert_eq!(*replaced, [2, 2, 2]);
+    assert_eq!(*atomic.into_inner(), [3]);
+}
+
+#[test]
+fn raw() {
+    let thin: super::ThinDyn<dyn core::fmt::Debug> = crate::thin_dyn!(u32, 7);
+    let raw = super::ThinDyn::into_raw(thin);
+    // SAFETY: raw came from into_raw of the same type
+    let thin: super::ThinDyn<dyn core::fmt::Debug> = unsafe { super::ThinDyn::from_raw(raw) };
+    assert_eq!(format!("{:?}", &*thin), "7");
+}
+
+#[test]
+fn forward_traits() {
+    let a = super::ThinBoxedSlice::<u8>::new(2, slice::repeat(1u8));
+    let b = super::ThinBoxedSlice::<u8>::new(3, slice::repeat(1u8));
+    assert_eq!(format!("{a:?}"), "[1, 1]");
+    assert!(a < b);
+    assert_ne!(a, b);
+
+    let display: super::ThinDyn<dyn core::fmt::Display> = crate::thin_dyn!(u32, 5);
+    assert_eq!(format!("{display}"), "5");
+
+    let mut vec = super::ThinVec::<u8>::new();
+    vec.extend_emplace([1, 1]);
+    assert_eq!(*vec, *a);
+    assert_eq!(format!("{vec:?}"), "[1, 1]");
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn atomic_pin() {
+    use core::sync::atomic::Ordering;
+
+    use alloc::boxed::Box;
+
+    let atomic = super::AtomicThin::new(Box::pin(1u32));
+    let old = atomic.swap(Box::pin(2), Ordering::AcqRel);
+    assert_eq!(*old, 1);
+    assert_eq!(*atomic.into_inner(), 2);
+}
+
+#[test]
+fn tagged() {
+    let mut tagged =
+        super::TaggedThin::<_, 2>::new(super::ThinBoxedSlice::<u8>::new(2, slice::repeat(4u8)), 3);
+    assert_eq!(size_of_val(&tagged), size_of::<usize>());
+    assert_eq!(tagged.tag(), 3);
+    assert_eq!(tagged.with(|thin| thin[1]), 4);
+
+    tagged.set_tag(1);
+    assert_eq!(tagged.tag(), 1);
+    let (thin, tag) = tagged.into_parts();
+    assert_eq!((&*thin, tag), (&[4u8, 4][..], 1));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde() {
+    let vec: super::ThinVec<u32> = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(*vec, [1, 2, 3]);
+    assert_eq!(serde_json::to_string(&vec).unwrap(), "[1,2,3]");
+
+    let slice: super::ThinBoxedSlice<String> = serde_json::from_str(r#"["a", "b"]"#).unwrap();
+    assert_eq!(*slice, ["a", "b"]);
+    assert_eq!(serde_json::to_string(&slice).unwrap(), r#"["a","b"]"#);
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<super::ThinArc<String, u32>>();
+    assert_send_sync::<super::ThinBoxedSlice<u32>>();
+    assert_send_sync::<super::ThinVec<u32>>();
+    assert_send_sync::<super::ThinDyn<dyn core::fmt::Debug + Send + Sync>>();
+    assert_send_sync::<super::AtomicThin<super::ThinArc<(), u32>>>();
+    assert_send_sync::<super::TaggedThin<super::ThinArc<(), u32>, 2>>();
+}
+
+#[test]
+#[cfg(all(feature = "std", target_has_atomic = "ptr"))]
+fn arc_threads() {
+    let arc = super::ThinArc::<u32, u32>::try_new(1u32, 4, slice::from_iter(0..))
+        .ok()
+        .unwrap();
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            let arc = arc.clone();
+            scope.spawn(move || assert_eq!(arc.iter().sum::<u32>(), 6));
+        }
+    });
+
+    assert_eq!(super::ThinArc::strong_count(&arc), 1);
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn arc_make_mut() {
+    let mut arc = super::ThinArc::<u32, u32>::try_new(1u32, 2, slice::repeat(5u32))
+        .ok()
+        .unwrap();
+
+    let (header, tail) = super::ThinArc::make_mut(&mut arc);
+    *header = 2;
+    tail[0] = 6;
+
+    let other = arc.clone();
+    let (header, tail) = super::ThinArc::make_mut(&mut arc);
+    *header = 3;
+    tail[1] = 7;
+
+    assert!(!super::ThinArc::ptr_eq(&arc, &other));
+    assert_eq!((*arc.header(), &*arc), (3, &[6, 7][..]));
+    assert_eq!((*other.header(), &*other), (2, &[6, 5][..]));
+}
+
+#[test]
+#[cfg(feature = "ptr_metadata")]
+fn extern_type() {
+    use super::ErasablePtr;
+
+    extern "C" {
+        type Opaque;
+    }
+
+    let value = 10u32;
+    // SAFETY: Opaque has no size, so any non-null pointer is a valid reference to it
+    let opaque = unsafe { &*core::ptr::from_raw_parts::<Opaque>(&raw const value, ()) };
+    let erased = ErasablePtr::erase(opaque);
+    // SAFETY: erased came from erasing a &Opaque
+    let restored = unsafe { <&Opaque as ErasablePtr>::unerase(erased) };
+    assert!(core::ptr::eq(restored, opaque));
+}
+
+#[test]
+fn core_types_are_erasable() {
+    use core::{
+        cell::UnsafeCell,
+        mem::MaybeUninit,
+        num::{NonZero, Wrapping},
+        ptr::NonNull,
+        time::Duration,
+    };
+
+    use alloc::boxed::Box;
+
+    use super::{Erasable, TaggedThin};
+
+    // sized types don't need an impl of their own, the blanket impl covers them
+    fn erasable<T: Erasable>() -> usize {
+        T::ALIGN
+    }
+
+    assert_eq!(erasable::<NonNull<u8>>(), align_of::<usize>());
+    assert_eq!(erasable::<NonZero<u32>>(), 4);
+    assert_eq!(erasable::<Option<NonZero<u64>>>(), align_of::<u64>());
+    #[cfg(target_has_atomic = "ptr")]
+    assert_eq!(
+        erasable::<core::sync::atomic::AtomicUsize>(),
+        align_of::<usize>()
+    );
+    assert_eq!(erasable::<Cell<u16>>(), 2);
+    assert_eq!(erasable::<UnsafeCell<u16>>(), 2);
+    assert_eq!(erasable::<MaybeUninit<u32>>(), 4);
+    assert_eq!(erasable::<Wrapping<u32>>(), 4);
+    assert_eq!(erasable::<Duration>(), align_of::<Duration>());
+    assert_eq!(erasable::<fn(u32) -> u32>(), align_of::<usize>());
+
+    let thin = TaggedThin::<Box<Cell<u32>>, 2>::new(Box::new(Cell::new(5)), 3);
+    thin.with(|cell| cell.set(cell.get() + 1));
+    assert_eq!(thin.tag(), 3);
+    assert_eq!(thin.into_inner().get(), 6);
+
+    let thin = TaggedThin::<Box<Duration>, 2>::new(Box::new(Duration::from_secs(1)), 1);
+    assert_eq!(*thin.into_inner(), Duration::from_secs(1));
+}
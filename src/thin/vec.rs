@@ -0,0 +1,269 @@
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use alloc::alloc::realloc;
+
+use crate::{layout_provider::LayoutProviderError, Ctor, EmplaceError, Uninit};
+
+use super::Dealloc;
+
+#[repr(C)]
+struct VecHeader {
+    len: usize,
+    cap: usize,
+}
+
+/// A growable vector behind a single thin pointer, which stores its length and capacity
+/// inside the allocation
+///
+/// Empty vectors don't allocate, and this is a single pointer wide, unlike `Vec<T>`
+pub struct ThinVec<T> {
+    ptr: Option<NonNull<VecHeader>>,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: ThinVec owns its elements, like Vec<T>
+unsafe impl<T: Send> Send for ThinVec<T> {}
+// SAFETY: ThinVec only gives shared access to its elements through a shared reference
+unsafe impl<T: Sync> Sync for ThinVec<T> {}
+
+super::forward_traits!(impl[T] ThinVec<T> => [T]);
+
+impl<T> Default for ThinVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ThinVec<T> {
+    /// Create a new empty vector, this doesn't allocate
+    pub const fn new() -> Self {
+        Self {
+            ptr: None,
+            _marker: PhantomData,
+        }
+    }
+
+    const fn header(&self) -> Option<&VecHeader> {
+        match self.ptr {
+            // SAFETY: the header is always initialized if there is an allocation
+            Some(ptr) => Some(unsafe { ptr.as_ref() }),
+            None => None,
+        }
+    }
+
+    /// The number of elements in the vector
+    pub const fn len(&self) -> usize {
+        match self.header() {
+            Some(header) => header.len,
+            None => 0,
+        }
+    }
+
+    /// Returns true if the vector is empty
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of elements the vector can hold without reallocating
+    pub const fn capacity(&self) -> usize {
+        match self.header() {
+            Some(header) => header.cap,
+            None => 0,
+        }
+    }
+
+    fn resize<E>(&mut self, cap: usize) -> Result<(), EmplaceError<E>> {
+        let Some((layout, _)) = super::slice_layout::<VecHeader, T>(cap) else {
+            return Err(EmplaceError::Layout(LayoutProviderError::Overflow));
+        };
+
+        let len = self.len();
+        let ptr = match self.ptr {
+            None => super::allocate(layout)?,
+            Some(ptr) => {
+                let Some((old_layout, _)) = super::slice_layout::<VecHeader, T>(self.capacity())
+                else {
+                    // SAFETY: this layout was already checked when the allocation was created
+                    unsafe { core::hint::unreachable_unchecked() }
+                };
+
+                // SAFETY: the buffer was allocated with the old layout, and the new size is non-zero
+                let ptr = unsafe { realloc(ptr.as_ptr().cast(), old_layout, layout.size()) };
+                NonNull::new(ptr).ok_or(EmplaceError::Alloc(layout))?
+            }
+        };
+
+        let ptr = ptr.cast::<VecHeader>();
+        // SAFETY: the header is at the start of the allocation, which is aligned for VecHeader
+        unsafe { ptr.as_ptr().write(VecHeader { len, cap }) };
+        self.ptr = Some(ptr);
+        Ok(())
+    }
+
+    /// Try to reserve space for at least `additional` more elements
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), EmplaceError<core::convert::Infallible>> {
+        self.grow(additional)
+    }
+
+    /// Reserve space for at least `additional` more elements
+    ///
+    /// Generic over the error, so the fallible methods can return their initializer's error
+    fn grow<E>(&mut self, additional: usize) -> Result<(), EmplaceError<E>> {
+        let required = self
+            .len()
+            .checked_add(additional)
+            .ok_or(EmplaceError::Layout(LayoutProviderError::Overflow))?;
+
+        if required <= self.capacity() {
+            return Ok(());
+        }
+
+        let cap = self
+            .capacity()
+            .checked_mul(2)
+            .ok_or(EmplaceError::Layout(LayoutProviderError::Overflow))?
+            .max(required)
+            .max(4);
+        self.resize(cap)
+    }
+
+    /// Reserve space for at least `additional` more elements
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve(additional) {
+            err.handle()
+        }
+    }
+
+    /// Try to initialize a new element at the end of the vector
+    pub fn try_emplace<I>(&mut self, init: I) -> Result<(), EmplaceError<T::Error>>
+    where
+        T: Ctor<I>,
+    {
+        self.grow(1)?;
+
+        let len = self.len();
+        // SAFETY: len < cap, so the element is in bounds of the allocation and uninitialized
+        let uninit = unsafe { Uninit::from_raw(self.data().add(len)) };
+        uninit
+            .try_init(init)
+            .map_err(EmplaceError::Init)?
+            .take_ownership();
+
+        // SAFETY: grow allocated a header
+        unsafe { self.set_len(len + 1) };
+        Ok(())
+    }
+
+    /// Initialize a new element at the end of the vector
+    pub fn emplace<I>(&mut self, init: I)
+    where
+        T: Ctor<I, Error = core::convert::Infallible>,
+    {
+        if let Err(err) = self.try_emplace(init) {
+            err.handle()
+        }
+    }
+
+    /// Try to initialize a new element at the end of the vector for each initializer
+    ///
+    /// If any initializer fails, the elements which were already initialized are kept
+    pub fn try_extend_emplace<I>(&mut self, iter: I) -> Result<(), EmplaceError<T::Error>>
+    where
+        I: IntoIterator,
+        T: Ctor<I::Item>,
+    {
+        let mut iter = iter.into_iter();
+        self.grow(iter.size_hint().0)?;
+        iter.try_for_each(|init| self.try_emplace(init))
+    }
+
+    /// Initialize a new element at the end of the vector for each initializer
+    pub fn extend_emplace<I>(&mut self, iter: I)
+    where
+        I: IntoIterator,
+        T: Ctor<I::Item, Error = core::convert::Infallible>,
+    {
+        if let Err(err) = self.try_extend_emplace(iter) {
+            err.handle()
+        }
+    }
+
+    /// Remove the last element of the vector
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len().checked_sub(1)?;
+        // SAFETY: the vector isn't empty, so it has a header
+        unsafe { self.set_len(len) };
+        // SAFETY: the element was initialized, and is no longer part of the vector
+        Some(unsafe { self.data().add(len).read() })
+    }
+
+    /// # Safety
+    ///
+    /// The vector must have a header, and the first `len` elements must be initialized
+    unsafe fn set_len(&mut self, len: usize) {
+        let Some(ptr) = self.ptr else {
+            // SAFETY: the caller ensures that there is a header
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        // SAFETY: the header is always initialized if there is an allocation
+        unsafe { (*ptr.as_ptr()).len = len }
+    }
+
+    fn data(&self) -> *mut T {
+        let Some(ptr) = self.ptr else {
+            return NonNull::dangling().as_ptr();
+        };
+
+        let Some((_, offset)) = super::slice_layout::<VecHeader, T>(self.capacity()) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        // SAFETY: the elements start at `offset`, which is in bounds of the allocation
+        unsafe { ptr.as_ptr().cast::<u8>().add(offset) }.cast::<T>()
+    }
+}
+
+impl<T> Drop for ThinVec<T> {
+    fn drop(&mut self) {
+        let Some(ptr) = self.ptr else { return };
+
+        let Some((layout, _)) = super::slice_layout::<VecHeader, T>(self.capacity()) else {
+            // SAFETY: this layout was already checked when the allocation was created
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+
+        let _dealloc = Dealloc {
+            ptr: ptr.cast(),
+            layout,
+        };
+
+        let elements: *mut [T] = &raw mut **self;
+        // SAFETY: the elements are initialized, and aren't used after this
+        unsafe { elements.drop_in_place() }
+    }
+}
+
+impl<T> Deref for ThinVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the first len elements are initialized and owned by self
+        unsafe { core::slice::from_raw_parts(self.data(), self.len()) }
+    }
+}
+
+impl<T> DerefMut for ThinVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the first len elements are initialized and uniquely owned by self
+        unsafe { core::slice::from_raw_parts_mut(self.data(), self.len()) }
+    }
+}
@@ -0,0 +1,97 @@
+//! initializers for tuples, which initialize each element in place
+
+use core::{alloc::Layout, ptr::NonNull};
+
+use crate::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider},
+    Ctor, Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A [`LayoutProvider`] for tuples, which uses a tuple of layout providers `L`, one for each element
+///
+/// The tuple is only zeroed if every element is zeroed
+pub struct TupleLayoutProvider<L>(L);
+
+/// The error type for tuple initializers, specifies which element failed to initialize
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TupleError<A, B = A, C = A, D = A, E = A, F = A, G = A, H = A> {
+    /// the first element failed to initialize
+    _0(A),
+    /// the second element failed to initialize
+    _1(B),
+    /// the third element failed to initialize
+    _2(C),
+    /// the fourth element failed to initialize
+    _3(D),
+    /// the fifth element failed to initialize
+    _4(E),
+    /// the sixth element failed to initialize
+    _5(F),
+    /// the seventh element failed to initialize
+    _6(G),
+    /// the eighth element failed to initialize
+    _7(H),
+}
+
+macro_rules! tuple {
+    ($($t:ident $i:ident $l:ident $idx:tt $variant:ident),*) => {
+        impl<$($t: Ctor<$i>, $i),*> Initializer<($($t,)*)> for ($($i,)*) {
+            type Error = TupleError<$($t::Error),*>;
+
+            fn try_init_into(
+                self,
+                mut ptr: Uninit<($($t,)*)>,
+            ) -> Result<Init<($($t,)*)>, Self::Error> {
+                let raw = ptr.as_mut_ptr();
+                $(
+                    // SAFETY: each element is in bounds of the tuple, and is only initialized once
+                    let elem = unsafe { Uninit::from_raw(&raw mut (*raw).$idx) };
+                    // the elements which were already initialized are dropped if this fails
+                    #[allow(non_snake_case)]
+                    let $t = elem.try_init(self.$idx).map_err(TupleError::$variant)?;
+                )*
+
+                $($t.take_ownership();)*
+
+                // SAFETY: all elements were initialized
+                Ok(unsafe { ptr.assume_init() })
+            }
+        }
+
+        impl<$($t, $i: DefaultLayoutProviderFor<$t>),*> DefaultLayoutProviderFor<($($t,)*)>
+            for ($($i,)*)
+        {
+            type LayoutProvider = TupleLayoutProvider<($($i::LayoutProvider,)*)>;
+        }
+
+        // SAFETY: tuples are sized, so layout and cast are trivial, and
+        // is_zeroed only returns true if all elements are zeroed
+        unsafe impl<$($t, $i, $l: LayoutProvider<$t, $i>),*> LayoutProvider<($($t,)*), ($($i,)*)>
+            for TupleLayoutProvider<($($l,)*)>
+        {
+            fn layout(_: &($($i,)*)) -> Option<Layout> {
+                Some(Layout::new::<($($t,)*)>())
+            }
+
+            fn cast(ptr: NonNull<()>, _: &($($i,)*)) -> NonNull<($($t,)*)> {
+                ptr.cast()
+            }
+
+            fn is_zeroed(args: &($($i,)*)) -> bool {
+                true $(&& $l::is_zeroed(&args.$idx))*
+            }
+        }
+    };
+}
+
+tuple!(A IA LA 0 _0);
+tuple!(A IA LA 0 _0, B IB LB 1 _1);
+tuple!(A IA LA 0 _0, B IB LB 1 _1, C IC LC 2 _2);
+tuple!(A IA LA 0 _0, B IB LB 1 _1, C IC LC 2 _2, D ID LD 3 _3);
+tuple!(A IA LA 0 _0, B IB LB 1 _1, C IC LC 2 _2, D ID LD 3 _3, E IE LE 4 _4);
+tuple!(A IA LA 0 _0, B IB LB 1 _1, C IC LC 2 _2, D ID LD 3 _3, E IE LE 4 _4, F IF LF 5 _5);
+tuple!(A IA LA 0 _0, B IB LB 1 _1, C IC LC 2 _2, D ID LD 3 _3, E IE LE 4 _4, F IF LF 5 _5, G IG LG 6 _6);
+tuple!(A IA LA 0 _0, B IB LB 1 _1, C IC LC 2 _2, D ID LD 3 _3, E IE LE 4 _4, F IF LF 5 _5, G IG LG 6 _6, H IH LH 7 _7);
@@ -0,0 +1,56 @@
+use core::{cell::Cell, convert::Infallible, mem::MaybeUninit, num::NonZero};
+
+use super::TupleError;
+use crate::{Init, Initializer, Uninit};
+
+struct DropCount<'a>(&'a Cell<usize>);
+
+impl Drop for DropCount<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+impl<'a> Initializer<DropCount<'a>> for &'a Cell<usize> {
+    type Error = Infallible;
+
+    fn try_init_into<'u>(
+        self,
+        ptr: Uninit<'u, DropCount<'a>>,
+    ) -> Result<Init<'u, DropCount<'a>>, Self::Error> {
+        Ok(ptr.write(DropCount(self)))
+    }
+}
+
+#[test]
+fn tuples() {
+    let mut value = MaybeUninit::<(u8, u32, NonZero<u16>)>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+    let init = uninit.try_init((1u8, (), 3u16)).unwrap();
+    assert_eq!(*init.as_ref(), (1, 0, NonZero::new(3).unwrap()));
+}
+
+#[test]
+fn tuple_cleanup() {
+    let drops = Cell::new(0);
+
+    let mut value = MaybeUninit::<(DropCount<'_>, DropCount<'_>, NonZero<u16>)>::uninit();
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { Uninit::from_raw(value.as_mut_ptr()) };
+    let err = uninit.try_init((&drops, &drops, 0u16)).err().unwrap();
+    assert!(matches!(err, TupleError::_2(_)));
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn zeroed_tuples() {
+    use crate::layout_provider::{DefaultLayoutProvider, LayoutProvider};
+
+    fn is_zeroed<T: DefaultLayoutProvider<I>, I>(args: I) -> bool {
+        <T::LayoutProvider as LayoutProvider<T, I>>::is_zeroed(&args)
+    }
+
+    assert!(is_zeroed::<(u32, u64), _>(((), ())));
+    assert!(!is_zeroed::<(u32, u64), _>(((), 1u64)));
+}
@@ -0,0 +1,29 @@
+//! initializers for [`zerocopy`](::zerocopy) types
+//!
+//! [`from_bytes`] copies bytes into any [`zerocopy::FromBytes`](::zerocopy::FromBytes) type,
+//! or slice of them, which allows parsing buffers straight into their final allocation
+
+#[cfg(test)]
+mod tests;
+
+/// Copy bytes into a [`zerocopy::FromBytes`](::zerocopy::FromBytes) value, or a slice of them
+///
+/// see [`from_bytes`] for details
+pub type FromBytes<'a> = crate::from_bytes::FromBytes<'a, Zerocopy>;
+
+pub use crate::from_bytes::{FromBytesLayoutProvider, SizeMismatchError};
+
+/// The backend for [`FromBytes`] which allows any [`zerocopy::FromBytes`](::zerocopy::FromBytes) type
+pub enum Zerocopy {}
+
+// SAFETY: any bytes are a valid zerocopy::FromBytes type
+unsafe impl<T: ::zerocopy::FromBytes> crate::from_bytes::FromBytesBackend<T> for Zerocopy {}
+
+/// Copy the bytes into a [`zerocopy::FromBytes`](::zerocopy::FromBytes) value, or a slice of them
+///
+/// The bytes don't need to be aligned. When initializing a slice, the slice's length
+/// is the number of bytes divided by the size of the element.
+/// Initialization fails if the bytes don't exactly fill the value
+pub const fn from_bytes(bytes: &[u8]) -> FromBytes<'_> {
+    FromBytes::new(bytes)
+}
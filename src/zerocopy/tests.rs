@@ -0,0 +1,22 @@
+#[cfg(feature = "alloc")]
+use super::{from_bytes, SizeMismatchError};
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed() {
+    let bytes = [1u32, 2].map(u32::to_ne_bytes).concat();
+    let bx = crate::boxed::try_boxed::<[u32], _>(from_bytes(&bytes));
+    assert_eq!(*bx.ok().unwrap(), [1, 2]);
+
+    let bx = crate::boxed::try_boxed::<u64, _>(from_bytes(&5u64.to_ne_bytes()));
+    assert_eq!(*bx.ok().unwrap(), 5);
+
+    let bx = crate::boxed::try_boxed::<[u16; 2], _>(from_bytes(&[1, 2, 3]));
+    assert!(matches!(
+        bx,
+        Err(crate::EmplaceError::Init(SizeMismatchError {
+            src_len: 3,
+            dest_len: 4
+        }))
+    ));
+}
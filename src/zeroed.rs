@@ -0,0 +1,149 @@
+//! initializers for types which are valid when all of their bytes are zero
+
+use core::{
+    alloc::Layout,
+    cell::{Cell, UnsafeCell},
+    marker::{PhantomData, PhantomPinned},
+    mem::{ManuallyDrop, MaybeUninit},
+    num::{NonZero, Saturating, Wrapping},
+    ptr::NonNull,
+};
+
+use crate::{
+    layout_provider::{DefaultLayoutProviderFor, LayoutProvider},
+    Init, Initializer, Uninit,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A type which is valid when all of its bytes are zero
+///
+/// This can be derived with `#[derive(Zeroable)]` (with the `derive` feature), which
+/// requires that all fields are `Zeroable`
+///
+/// # Safety
+///
+/// All zero bytes must be a valid value of `Self`
+pub unsafe trait Zeroable {}
+
+/// Initialize a value by zeroing out its memory
+///
+/// see [`zeroed`] for details
+#[derive(Debug, Clone, Copy)]
+pub struct Zeroed;
+
+/// Initialize a [`Zeroable`] value (or a slice of them) by zeroing out its memory
+///
+/// The layout provider for this initializer reports that the value is zeroed,
+/// so allocating with [`boxed`](crate::boxed::boxed) uses zeroed allocations
+/// instead of writing the zeros. To zero a slice on the heap, use
+/// `WithLength::from_init(len, slice::repeat(zeroed()))`
+pub const fn zeroed() -> Zeroed {
+    Zeroed
+}
+
+impl<T: Zeroable> Initializer<T> for Zeroed {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, mut ptr: Uninit<T>) -> Result<Init<T>, Self::Error> {
+        // SAFETY: the pointer is valid for writes of one `T`
+        unsafe { ptr.as_mut_ptr().write_bytes(0, 1) };
+        // SAFETY: all zero bytes is a valid `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+impl<T: Zeroable> Initializer<[T]> for Zeroed {
+    type Error = core::convert::Infallible;
+
+    fn try_init_into(self, mut ptr: Uninit<[T]>) -> Result<Init<[T]>, Self::Error> {
+        let len = ptr.len();
+        // SAFETY: the pointer is valid for writes of `len` `T`s
+        unsafe { ptr.as_mut_ptr().cast::<T>().write_bytes(0, len) };
+        // SAFETY: all zero bytes is a valid `T`
+        Ok(unsafe { ptr.assume_init() })
+    }
+}
+
+/// A [`LayoutProvider`] for [`Zeroed`], which reports that the value is zeroed
+pub struct ZeroedLayoutProvider;
+
+impl<T: Zeroable> DefaultLayoutProviderFor<T> for Zeroed {
+    type LayoutProvider = ZeroedLayoutProvider;
+}
+
+// SAFETY: T is sized, so layout and cast are trivial, and
+// Zeroed only zeroes out the memory, so is_zeroed may return true
+unsafe impl<T: Zeroable> LayoutProvider<T, Zeroed> for ZeroedLayoutProvider {
+    fn layout(_: &Zeroed) -> Option<Layout> {
+        Some(Layout::new::<T>())
+    }
+
+    fn cast(ptr: NonNull<()>, _: &Zeroed) -> NonNull<T> {
+        ptr.cast()
+    }
+
+    fn is_zeroed(_args: &Zeroed) -> bool {
+        true
+    }
+}
+
+macro_rules! zeroable {
+    (@impl [$($binder:tt)*] $t:ty) => {
+        // SAFETY: all zero bytes is a valid value of this type
+        unsafe impl<$($binder)*> Zeroable for $t {}
+    };
+    (=> $binder:tt $($t:ty),* $(,)?) => {$(zeroable!(@impl $binder $t);)*};
+    ($($t:ty),* $(,)?) => {$(zeroable!(@impl [] $t);)*};
+}
+
+zeroable!(u8, u16, u32, u64, u128, usize);
+zeroable!(i8, i16, i32, i64, i128, isize);
+zeroable!(f32, f64, bool, char, (), PhantomPinned);
+zeroable!(=> [T] *const T, *mut T, *const [T], *mut [T], Option<NonNull<T>>);
+zeroable!(*const str, *mut str);
+zeroable!(=> ['a, T: ?Sized] Option<&'a T>, Option<&'a mut T>);
+zeroable!(=> [T: ?Sized] PhantomData<T>);
+zeroable!(=> [T] MaybeUninit<T>);
+zeroable!(=> [T: Zeroable] ManuallyDrop<T>, Cell<T>, UnsafeCell<T>, Wrapping<T>, Saturating<T>);
+zeroable!(=> [T: Zeroable, const N: usize] [T; N]);
+zeroable!(
+    Option<NonZero<u8>>,
+    Option<NonZero<u16>>,
+    Option<NonZero<u32>>
+);
+zeroable!(
+    Option<NonZero<u64>>,
+    Option<NonZero<u128>>,
+    Option<NonZero<usize>>
+);
+zeroable!(
+    Option<NonZero<i8>>,
+    Option<NonZero<i16>>,
+    Option<NonZero<i32>>
+);
+zeroable!(
+    Option<NonZero<i64>>,
+    Option<NonZero<i128>>,
+    Option<NonZero<isize>>
+);
+
+#[cfg(feature = "alloc")]
+zeroable!(=> [T] Option<alloc::boxed::Box<T>>);
+
+macro_rules! zeroable_tuple {
+    ($($t:ident)*) => {
+        // SAFETY: all zero bytes is a valid value of each field
+        unsafe impl<$($t: Zeroable),*> Zeroable for ($($t,)*) {}
+    };
+}
+
+zeroable_tuple!(A);
+zeroable_tuple!(A B);
+zeroable_tuple!(A B C);
+zeroable_tuple!(A B C D);
+zeroable_tuple!(A B C D E);
+zeroable_tuple!(A B C D E F);
+zeroable_tuple!(A B C D E F G);
+zeroable_tuple!(A B C D E F G H);
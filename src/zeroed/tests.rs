@@ -0,0 +1,42 @@
+use core::{cell::Cell, num::NonZero};
+
+use super::{zeroed, Zeroed};
+use crate::layout_provider::{DefaultLayoutProvider, LayoutProvider};
+
+fn is_zeroed<T: DefaultLayoutProvider<Zeroed>>() -> bool {
+    <T::LayoutProvider as LayoutProvider<T, Zeroed>>::is_zeroed(&Zeroed)
+}
+
+#[test]
+fn zeroed_layout_provider() {
+    assert!(is_zeroed::<u32>());
+    assert!(is_zeroed::<[Option<NonZero<u8>>; 4]>());
+    assert!(is_zeroed::<(Cell<f32>, *const str)>());
+}
+
+#[test]
+fn zeroed_stack() {
+    let mut value = core::mem::MaybeUninit::<[u64; 3]>::new([1, 2, 3]);
+    // SAFETY: a MaybeUninit is always valid to pass to Uninit::from_raw
+    let uninit = unsafe { crate::Uninit::from_raw(value.as_mut_ptr()) };
+    let Ok(init) = uninit.try_init(zeroed());
+    assert_eq!(*init.as_ref(), [0; 3]);
+
+    let mut slice = [1u16, 2, 3];
+    // SAFETY: the slice is valid for writes
+    let uninit = unsafe { crate::Uninit::from_raw(&raw mut slice as *mut [u16]) };
+    let Ok(init) = uninit.try_init(zeroed());
+    assert_eq!(*init.as_ref(), [0; 3]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn zeroed_boxed() {
+    use crate::slice::{repeat, WithLength};
+
+    let bx = crate::boxed::boxed::<(u8, Option<&u32>), _>(zeroed());
+    assert_eq!(*bx, (0, None));
+
+    let bx = crate::boxed::boxed::<[i32], _>(WithLength::from_init(5, repeat(zeroed())));
+    assert_eq!(*bx, [0; 5]);
+}